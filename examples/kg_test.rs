@@ -2,6 +2,11 @@ use rand_key::RandKey;
 use std::{env::args, error::Error};
 
 
+#[cfg(feature = "alloc-track")]
+#[global_allocator]
+static GLOBAL: rand_key::alloc_track::TrackingAllocator = rand_key::alloc_track::TrackingAllocator;
+
+
 
  
 fn main() -> Result<(), Box<dyn Error>> {
@@ -14,6 +19,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         r_p = RandKey::new("10", "2", "3")?;
         r_p.join()?;
         println!("{}", r_p);
+        println!("{}", r_p.strength().render_bar(20));
     } else {
         let ltr_cnt = &demands[0];
         let sbl_cnt = &demands[1];
@@ -26,9 +32,13 @@ fn main() -> Result<(), Box<dyn Error>> {
             r_p.set_unit(unit)?;
         }
 
+        #[cfg(feature = "alloc-track")]
+        println!("Peak allocation: {} bytes", r_p.join_with_peak_alloc()?);
+        #[cfg(not(feature = "alloc-track"))]
         r_p.join()?;
 
         println!("{}", r_p);
+        println!("{}", r_p.strength().render_bar(20));
     }
 
     Ok(())
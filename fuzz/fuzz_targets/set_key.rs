@@ -0,0 +1,7 @@
+#![no_main]
+
+
+use libfuzzer_sys::fuzz_target;
+
+
+fuzz_target!(|data: &[u8]| rand_key::fuzz::fuzz_set_key(data));
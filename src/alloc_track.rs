@@ -0,0 +1,67 @@
+use {
+    crate::{RandKey, GenError},
+    std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering::*},
+    },
+};
+
+
+
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+
+/// A global allocator counting the bytes allocated through it
+///
+/// It has to be installed by the binary to make [`RandKey::join_with_peak_alloc`] work:
+/// ```
+/// use rand_key::alloc_track::TrackingAllocator;
+///
+/// #[global_allocator]
+/// static GLOBAL: TrackingAllocator = TrackingAllocator;
+/// # fn main() {}
+/// ```
+pub struct TrackingAllocator;
+
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let now = CURRENT.fetch_add(layout.size(), SeqCst) + layout.size();
+            PEAK.fetch_max(now, SeqCst);
+        }
+        ptr
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), SeqCst);
+    }
+}
+
+
+/// Return the bytes currently allocated through [`TrackingAllocator`]
+#[inline]
+pub fn current_bytes() -> usize { CURRENT.load(SeqCst) }
+
+
+impl RandKey {
+    /// Generate the password and return the peak bytes allocated on top of the ones already in use
+    ///
+    /// It only reports `0` if [`TrackingAllocator`] isn't the global allocator.
+    /// The allocations of other threads during the generation are counted as well.
+    #[inline]
+    pub fn join_with_peak_alloc(&self) -> Result<usize, GenError> {
+        let base = CURRENT.load(SeqCst);
+        PEAK.store(base, SeqCst);
+
+        self.join()?;
+
+        Ok(PEAK.load(SeqCst) - base)
+    }
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{RandKey, utils::_DEFAULT_DATA},
+    proptest::{
+        prelude::*,
+        arbitrary::Arbitrary,
+        sample::subsequence,
+    },
+};
+
+
+
+
+/// Counts up to 64 each, and non-empty subsets of the default data, so every value can `join`.
+/// ```
+/// use proptest::{prelude::*, test_runner::TestRunner};
+/// use rand_key::RandKey;
+///
+/// TestRunner::default().run(&any::<RandKey>(), |r_p| {
+///     r_p.join().unwrap();
+///     let data = r_p.all_data().concat();
+///     prop_assert!(r_p.key().chars().all(|c| data.contains(&c.to_string())));
+///     Ok(())
+/// }).unwrap();
+/// ```
+impl Arbitrary for RandKey {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let data = _DEFAULT_DATA();
+        let pool = |v: &Vec<String>| subsequence(v.clone(), 1..=v.len());
+
+        (0..=64u32, 0..=64u32, 0..=64u32, pool(&data[0]), pool(&data[1]), pool(&data[2]))
+            .prop_map(|(l, s, n, ltr, sbl, num)| {
+                let mut r_p = RandKey::new(l.to_string(), s.to_string(), n.to_string()).unwrap();
+                r_p.replace_data(&[ltr, sbl, num].concat()).unwrap();
+                r_p
+            })
+            .boxed()
+    }
+}
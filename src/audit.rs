@@ -0,0 +1,130 @@
+//! Aggregate statistics of a corpus of existing passwords.
+use {
+    std::{
+        collections::{BTreeMap, HashSet},
+        fmt::{self, Display, Formatter},
+    },
+    crate::StrengthReport,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// Some of the most common passwords, compared case-insensitively
+pub const COMMON_PASSWORDS: &[&str] = &[
+    "123456", "123456789", "12345678", "12345", "1234567", "1234567890", "1234", "111111", "000000", "123123",
+    "654321", "666666", "121212", "112233", "987654321", "password", "password1", "password123", "passw0rd",
+    "qwerty", "qwerty123", "qwertyuiop", "1q2w3e4r", "1qaz2wsx", "asdfgh", "zxcvbnm", "abc123", "iloveyou",
+    "admin", "admin123", "welcome", "letmein", "monkey", "dragon", "football", "baseball", "master", "sunshine",
+    "princess", "shadow", "superman", "trustno1", "starwars", "login", "hello", "freedom", "whatever", "secret",
+    "changeme", "default",
+];
+
+
+/// Statistics of a corpus of passwords, returned by [`audit`]
+///
+/// Print it as text with `Display`, or as JSON with the `serde` feature.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuditReport {
+    /// How many passwords were audited
+    pub total:            usize,
+    /// How many passwords have each length, in characters
+    pub length_histogram: BTreeMap<usize, usize>,
+    /// How many passwords contain letters, symbols and numbers
+    pub class_coverage:   [usize; 3],
+    /// How many passwords contain all the three kinds
+    pub all_classes:      usize,
+    /// How many passwords are copies of a previous one
+    pub duplicates:       usize,
+    /// How many passwords are in [`COMMON_PASSWORDS`]
+    pub common_hits:      usize,
+    /// The lowest estimated entropy in bits, `None` for an empty corpus
+    pub weakest_bits:     Option<f64>,
+}
+
+
+/// Audit `passwords`, the report never contains any of them
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::audit::audit;
+///
+/// let report = audit(&["Password", "q7#Lm2!xZ9@k", "q7#Lm2!xZ9@k", "hunter2"]);
+/// assert_eq!(report.total, 4);
+/// assert_eq!(report.duplicates, 1);
+/// assert_eq!(report.common_hits, 1);
+/// assert_eq!(report.class_coverage, [4, 2, 3]);
+/// assert_eq!(report.all_classes, 2);
+/// assert_eq!(report.length_histogram.get(&12), Some(&2));
+/// println!("{}", report);
+/// ```
+#[inline]
+pub fn audit(passwords: impl IntoIterator<Item = impl AsRef<str>>) -> AuditReport {
+    let mut report = AuditReport::default();
+    let mut seen = HashSet::new();
+
+    for password in passwords {
+        let password = password.as_ref();
+        let present = [
+            password.chars().any(|c| c.is_ascii_alphabetic()),
+            password.chars().any(|c| c.is_ascii_punctuation()),
+            password.chars().any(|c| c.is_ascii_digit()),
+        ];
+
+        report.total += 1;
+        *report.length_histogram.entry(password.chars().count()).or_insert(0) += 1;
+
+        for (coverage, p) in report.class_coverage.iter_mut().zip(present.iter()) {
+            *coverage += *p as usize;
+        }
+        if present.iter().all(|p| *p) {
+            report.all_classes += 1;
+        }
+
+        if !seen.insert(password.to_string()) {
+            report.duplicates += 1;
+        }
+
+        if COMMON_PASSWORDS.iter().any(|c| c.eq_ignore_ascii_case(password)) {
+            report.common_hits += 1;
+        }
+
+        let bits = StrengthReport::of(password).bits;
+        report.weakest_bits = Some(report.weakest_bits.map_or(bits, |w| w.min(bits)));
+    }
+
+    report
+}
+
+
+impl Display for AuditReport {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Passwords:    {}", self.total)?;
+        writeln!(f, "Duplicates:   {}", self.duplicates)?;
+        writeln!(f, "Common:       {}", self.common_hits)?;
+        writeln!(f,
+                 "Letters:      {}\nSymbols:      {}\nNumbers:      {}\nAll kinds:    {}",
+                 self.class_coverage[0],
+                 self.class_coverage[1],
+                 self.class_coverage[2],
+                 self.all_classes)?;
+
+        match self.weakest_bits {
+            Some(bits) => writeln!(f, "Weakest:      {:.1} bits", bits)?,
+            None => writeln!(f, "Weakest:      -")?,
+        }
+
+        writeln!(f, "Lengths:")?;
+        for (len, cnt) in &self.length_histogram {
+            writeln!(f, "  {:>4}  {}", len, cnt)?;
+        }
+
+        Ok(())
+    }
+}
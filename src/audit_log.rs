@@ -0,0 +1,187 @@
+//! An append-only log of the generations, in JSON lines, for the traceability of the issued credentials.
+//!
+//! Once [`enable`]d, every key generated by [`join`](crate::RandKey::join) and the methods built on it,
+//! [`join_ordered`](crate::RandKey::join_ordered), [`resume`](crate::RandKey::resume), [`derive_child`](crate::RandKey::derive_child),
+//! [`SharedRandKey`](crate::SharedRandKey), the pools handing their keys out and, with the `spill` feature,
+//! `join_to_file` writing the key to a file, is recorded with its time,
+//! the fingerprint of the settings, the fingerprint of the key and who requested it.
+//! The keys of [`stream`](crate::RandKey::stream) aren't, they are never whole in this crate.
+//! Neither are the ones [`throughput_probe`](crate::RandKey::throughput_probe) throws away.
+//! The key itself is never written: its fingerprint is an HMAC-SHA256 keyed by the secret of the log,
+//! so the holders of the secret can tell whether a key was issued, and nobody else can crack it by a dictionary,
+//! even a six-digit PIN.
+use {
+    std::{
+        cell::RefCell,
+        fs::{File, OpenOptions},
+        io::{self, BufRead, BufReader, ErrorKind, Write},
+        path::Path,
+        sync::{Arc, RwLock},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    hmac::{Hmac, Mac},
+    parking_lot::Mutex,
+    serde::{Serialize, Deserialize},
+    sha2::Sha256,
+    crate::{GenError, RandKeyConfig, utils::_FINGERPRINT},
+};
+
+
+
+
+static LOG: RwLock<Option<Arc<AuditLog>>> = RwLock::new(None);
+
+
+thread_local! {
+    static REQUESTER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+
+/// A line of the log
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// When the key was generated, in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Fingerprint of the counts, the data and the check digit the key was generated with
+    pub profile:   String,
+    /// HMAC-SHA256 of the key keyed by the secret of the log, in hex
+    pub key:       String,
+    /// Who requested the key, see [`as_requester`], empty if nobody said
+    pub requester: String,
+}
+
+
+/// The file the events are appended to, and the secret of the key fingerprints
+#[derive(Debug)]
+pub struct AuditLog {
+    file:   Mutex<File>,
+    secret: Vec<u8>,
+}
+
+
+impl AuditLog {
+    /// Open `path` to append the events to, creating it if needed, with `secret` keying the fingerprints of the keys
+    #[inline]
+    pub fn open(path: impl AsRef<Path>, secret: &[u8]) -> Result<Self, GenError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(AuditLog { file: Mutex::new(file), secret: secret.to_vec() })
+    }
+
+    /// Return the fingerprint of `key`, the one its events have
+    #[inline]
+    pub fn fingerprint(&self, key: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC takes keys of any length");
+        mac.update(key.as_bytes());
+
+        mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Append the event of `key` generated with `config`, in a single write so the lines of threads don't mix
+    #[inline]
+    fn record(&self, config: &RandKeyConfig, key: &str) -> Result<(), GenError> {
+        let event = AuditEvent {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            profile:   _PROFILE(config),
+            key:       self.fingerprint(key),
+            requester: REQUESTER.with(|r| r.borrow().clone()),
+        };
+
+        let mut line = serde_json::to_string(&event).expect("the events are plain strings and numbers");
+        line.push('\n');
+
+        let mut file = self.file.lock();
+        file.write_all(line.as_bytes())?;
+        file.flush()?;
+
+        Ok(())
+    }
+}
+
+
+/// Return the fingerprint of the settings `config`
+#[inline]
+pub(crate) fn _PROFILE(config: &RandKeyConfig) -> String {
+    let mut parts = vec![vec![config.ltr_cnt.clone(), config.sbl_cnt.clone(), config.num_cnt.clone(), format!("{:?}", config.checksum)]];
+    parts.extend(config.data.iter().cloned());
+
+    _FINGERPRINT(&parts)
+}
+
+
+/// Record every generation in `log` from now on, instead of the log enabled before
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, audit_log::{self, AuditLog}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let path = std::env::temp_dir().join(format!("rand_key_audit_{}.jsonl", std::process::id()));
+/// audit_log::enable(AuditLog::open(&path, b"log secret")?);
+///
+/// let r_p = RandKey::new("10", "2", "3")?;
+/// let key = audit_log::as_requester("alice", || r_p.generate())?;
+/// audit_log::disable();
+/// r_p.join()?;
+///
+/// let events = audit_log::read_events(&path)?;
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events[0].requester, "alice");
+/// assert!(!std::fs::read_to_string(&path)?.contains(&key));
+///
+/// // The holders of the secret can tell the key was issued
+/// assert_eq!(events[0].key, AuditLog::open(&path, b"log secret")?.fingerprint(&key));
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn enable(log: AuditLog) { *LOG.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(log)); }
+
+
+/// Stop recording the generations
+#[inline]
+pub fn disable() { *LOG.write().unwrap_or_else(|e| e.into_inner()) = None; }
+
+
+/// Check whether the generations are recorded
+#[inline]
+pub fn is_enabled() -> bool { LOG.read().unwrap_or_else(|e| e.into_inner()).is_some() }
+
+
+/// Run `f` with the keys it generates on this thread recorded as requested by `requester`
+#[inline]
+pub fn as_requester<T>(requester: &str, f: impl FnOnce() -> T) -> T {
+    let previous = REQUESTER.with(|r| r.replace(requester.into()));
+    let result = f();
+    REQUESTER.with(|r| *r.borrow_mut() = previous);
+
+    result
+}
+
+
+/// Return the events of the log at `path`
+///
+/// Return `GenError::Io` of the kind `InvalidData` if a line isn't an event.
+#[inline]
+pub fn read_events(path: impl AsRef<Path>) -> Result<Vec<AuditEvent>, GenError> {
+    BufReader::new(File::open(path)?).lines()
+                                     .filter(|line| !line.as_ref().is_ok_and(|l| l.trim().is_empty()))
+                                     .map(|line| Ok(serde_json::from_str(&line?).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?))
+                                     .collect()
+}
+
+
+/// Record the generation of `key` with the settings `config` returns in the enabled log, if any
+///
+/// The key isn't issued if it can't be recorded, the error of the log is returned instead.
+#[inline]
+pub(crate) fn _RECORD(config: impl FnOnce() -> RandKeyConfig, key: &str) -> Result<(), GenError> {
+    let log = LOG.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+    match log {
+        Some(log) => log.record(&config(), key),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,150 @@
+use {
+    std::collections::HashSet,
+    crate::{RandKey, GenError, shuffle::_MAX_RETRIES},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// A generated key with the label of where it goes, like a username or a system name
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabeledKey {
+    pub label: String,
+    pub key:   String,
+}
+
+
+impl RandKey {
+    /// Generate `n` keys, the last one is kept in the `key` field.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// let keys = r_p.generate_many(3)?;
+    /// assert_eq!(keys.len(), 3);
+    /// assert!(keys.iter().all(|k| k.len() == 15));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_many(&self, n: usize) -> Result<Vec<String>, GenError> {
+        (0..n).map(|_| self.join().map(|_| self.key())).collect()
+    }
+
+    /// Generate a key for each label
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// let keys = r_p.generate_labeled(&["alice", "bob"])?;
+    /// assert_eq!(keys[0].label, "alice");
+    /// assert_eq!(keys[1].label, "bob");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_labeled(&self, labels: &[impl AsRef<str>]) -> Result<Vec<LabeledKey>, GenError> {
+        labels.iter()
+              .map(|label| {
+                  self.join()?;
+                  Ok(LabeledKey { label: label.as_ref().into(), key: self.key() })
+              })
+              .collect()
+    }
+
+    /// Generate `n` distinct keys none of which is in `existing`, like invite codes which mustn't collide
+    /// with the ones already issued.
+    ///
+    /// Return `GenError::AttemptsExhausted` if they aren't found within `n` × 1000 keys.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::collections::HashSet;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("0", "0", "2")?;
+    /// let issued: HashSet<String> = (0..90).map(|i| format!("{:02}", i)).collect();
+    ///
+    /// let mut keys = r_p.generate_many_excluding(10, &issued)?;
+    /// keys.sort();
+    /// assert_eq!(keys, (90..100).map(|i| i.to_string()).collect::<Vec<_>>());
+    ///
+    /// assert!(r_p.generate_many_excluding(11, &issued).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_many_excluding(&self, n: usize, existing: &HashSet<String>) -> Result<Vec<String>, GenError> {
+        self.generate_many_excluding_with(n, |key| existing.contains(key))
+    }
+
+    /// Generate `n` distinct keys for which `is_taken` is false, like [`generate_many_excluding`](RandKey::generate_many_excluding).
+    ///
+    /// `is_taken` may be a Bloom filter of a set too big for the memory, see the example,
+    /// its false positives only cost another key.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // A Bloom filter of 2^20 bits with 3 hashes
+    /// let mut bits = vec![false; 1 << 20];
+    /// let hashes = |key: &str| -> Vec<usize> {
+    ///     (0..3u8).map(|i| {
+    ///                 let mut h = DefaultHasher::new();
+    ///                 (i, key).hash(&mut h);
+    ///                 h.finish() as usize % (1 << 20)
+    ///             })
+    ///             .collect()
+    /// };
+    /// for issued in ["AB12CD", "EF34GH"] {
+    ///     hashes(issued).into_iter().for_each(|i| bits[i] = true);
+    /// }
+    ///
+    /// let r_p = RandKey::new("4", "0", "2")?;
+    /// let keys = r_p.generate_many_excluding_with(100, |key| hashes(key).into_iter().all(|i| bits[i]))?;
+    /// assert_eq!(keys.len(), 100);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_many_excluding_with(&self, n: usize, mut is_taken: impl FnMut(&str) -> bool) -> Result<Vec<String>, GenError> {
+        let mut keys = Vec::with_capacity(n);
+        let mut seen = HashSet::with_capacity(n);
+
+        for _ in 0..n.saturating_mul(_MAX_RETRIES) {
+            if keys.len() == n {
+                break;
+            }
+
+            let key = self.generate()?;
+            if !is_taken(&key) && seen.insert(key.clone()) {
+                keys.push(key);
+            }
+        }
+
+        if keys.len() < n {
+            return Err(GenError::AttemptsExhausted);
+        }
+
+        Ok(keys)
+    }
+}
@@ -0,0 +1,23 @@
+//! `randkey-grpcd [ADDR] [RATE] [BURST]`, serve the `KeyGenerator` gRPC service on ADDR (default 127.0.0.1:50051),
+//! with the same limits as `randkeyd`.
+use {
+    rand_key::{RandKey, KeyService, grpc},
+    std::{env::args, error::Error},
+};
+
+
+
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = args().skip(1).collect();
+
+    let addr = args.first().map_or("127.0.0.1:50051", String::as_str).parse()?;
+    let rate = args.get(1).map_or(Ok(10000.0), |r| r.parse())?;
+    let burst = args.get(2).map_or(Ok(100000.0), |b| b.parse())?;
+
+    eprintln!("randkey-grpcd listening on {}", addr);
+    grpc::serve(addr, KeyService::new(RandKey::default().into(), rate, burst)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! `randkey analyze`, score passwords in bulk
+use {
+    crate::{CliResult, value_of},
+    rand_key::{
+        quality::{analyze, Policy, QualityReport},
+        scan::{scan, Finding},
+    },
+    std::{
+        fs::File,
+        io::{self, BufRead, BufReader, Write},
+    },
+};
+
+
+
+
+/// The tab-separated line of a report, which never contains the password
+fn line(i: usize, report: &QualityReport, findings: &[Finding]) -> String {
+    let patterns: Vec<String> = report.patterns.iter().map(ToString::to_string).collect();
+    let findings: Vec<String> = findings.iter().map(ToString::to_string).collect();
+
+    format!("{}\t{}\t{}\t{}\t{}\t{:.1}\t{:?}\t{}\t{}\t{}",
+            i + 1,
+            report.counts[0],
+            report.counts[1],
+            report.counts[2],
+            report.others,
+            report.entropy.bits,
+            report.entropy.strength(),
+            if report.passed() { "pass" } else { "fail" },
+            patterns.join(", "),
+            findings.join(", "))
+}
+
+
+fn analyze_all(input: impl BufRead, mut output: impl Write, policy: &Policy) -> CliResult<()> {
+    writeln!(output, "line\tletters\tsymbols\tnumbers\tothers\tbits\tstrength\tpolicy\tpatterns\tfindings")?;
+
+    for (i, password) in input.lines().enumerate() {
+        let password = password?;
+        writeln!(output, "{}", line(i, &analyze(&password, policy), &scan(&password)))?;
+    }
+
+    Ok(())
+}
+
+
+pub(crate) fn run(mut args: impl Iterator<Item = String>) -> CliResult<()> {
+    let mut source = None;
+    let mut policy = Policy::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--min-strength" => policy.min_strength = value_of(&arg, &mut args)?.parse()?,
+            "--min-classes" => policy.min_classes = value_of(&arg, &mut args)?.parse()?,
+            "--min-length" => policy.min_length = value_of(&arg, &mut args)?.parse()?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option `{}`", arg).into()),
+            _ => source = Some(arg),
+        }
+    }
+
+    let stdout = io::stdout();
+
+    match source.as_deref() {
+        Some("-") => analyze_all(io::stdin().lock(), stdout.lock(), &policy),
+        Some(path) => analyze_all(BufReader::new(File::open(path)?), stdout.lock(), &policy),
+        None => Err("Give the file of passwords to analyze, or `-` for the standard input".into()),
+    }
+}
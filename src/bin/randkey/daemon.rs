@@ -0,0 +1,117 @@
+//! `randkey daemon [--socket PATH] [--rate RATE] [--burst BURST]`, serve keys on a Unix socket
+//! (default `/run/randkey.sock`), to the local processes which would pay the startup of `randkey` for each key.
+//!
+//! Each line of a connection is a request in JSON like the ones of `randkeyd`, every field optional:
+//! `{"preset": "pin", "batch": 2}`, answered by a line `{"keys": [{"label": "", "key": "…"}, …]}` or `{"error": "…"}`.
+//! The connections share BURST characters at once (default 100000), refilled by RATE per second (default 10000).
+use {
+    crate::{CliResult, value_of},
+    serde_json::json,
+    rand_key::{RandKey, KeyService, service::BatchRequest},
+    std::{
+        fs,
+        sync::Arc,
+        thread,
+        io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+        os::unix::{fs::FileTypeExt, net::{UnixListener, UnixStream}},
+    },
+};
+
+
+
+
+const DEFAULT_SOCKET: &str = "/run/randkey.sock";
+const MAX_LINE: u64 = 1 << 16;
+
+
+/// The requests of a socket come from its own host, the rate limit is shared by all of them
+const TENANT: &str = "local";
+
+
+/// Return the response line of a request line
+fn answer(service: &KeyService, line: &str) -> serde_json::Value {
+    let keys = serde_json::from_str::<BatchRequest>(line).map_err(|e| e.to_string())
+                                                         .and_then(|req| service.generate_batch(TENANT, &req).map_err(|e| e.to_string()));
+
+    match keys {
+        Ok(keys) => json!({ "keys": keys }),
+        Err(error) => json!({ "error": error }),
+    }
+}
+
+
+fn handle(stream: UnixStream, service: &KeyService) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    loop {
+        let mut line = String::new();
+        // The line is cut at MAX_LINE, so a client can't fill the memory
+        if reader.by_ref().take(MAX_LINE).read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        if !line.ends_with('\n') && line.len() as u64 == MAX_LINE {
+            writeln!(writer, "{}", json!({ "error": "Request line too long" }))?;
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        writeln!(writer, "{}", answer(service, &line))?;
+        writer.flush()?;
+    }
+}
+
+
+/// Bind `path`, replacing the socket a previous daemon left, but no other file
+fn bind(path: &str) -> CliResult<UnixListener> {
+    match fs::symlink_metadata(path) {
+        Ok(meta) if meta.file_type().is_socket() => fs::remove_file(path)?,
+        Ok(_) => return Err(format!("`{}` exists and isn't a socket", path).into()),
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(UnixListener::bind(path)?)
+}
+
+
+pub(crate) fn run(mut args: impl Iterator<Item = String>) -> CliResult<()> {
+    let mut socket = DEFAULT_SOCKET.to_string();
+    let mut rate = 10000.0;
+    let mut burst = 100000.0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--socket" => socket = value_of(&arg, &mut args)?,
+            "--rate" => rate = value_of(&arg, &mut args)?.parse()?,
+            "--burst" => burst = value_of(&arg, &mut args)?.parse()?,
+            _ => return Err(format!("Unknown option `{}`", arg).into()),
+        }
+    }
+
+    let service = Arc::new(KeyService::new(RandKey::default().into(), rate, burst));
+    let listener = bind(&socket)?;
+    eprintln!("randkey daemon listening on {}", socket);
+
+    for stream in listener.incoming() {
+        // A failed connection is the client's, the daemon goes on serving the others
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("randkey daemon: {}", e);
+                continue;
+            }
+        };
+        let service = service.clone();
+
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, &service) {
+                eprintln!("randkey daemon: {}", e);
+            }
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! Printing the keys to the terminal
+use std::{
+    thread,
+    time::Duration,
+    io::{self, IsTerminal, Write},
+};
+
+
+
+
+/// Print `key`, then erase it after `duration`
+///
+/// The key is printed without a newline, so erasing the line removes it from the screen.
+/// The scrollback is erased too, on the terminals supporting it.
+pub(crate) fn show_for(key: &str, duration: Duration) -> io::Result<()> {
+    let mut out = io::stdout();
+
+    if !out.is_terminal() {
+        return Err(io::Error::other("`--show-for` needs a terminal"));
+    }
+
+    write!(out, "{}", key)?;
+    out.flush()?;
+
+    thread::sleep(duration);
+
+    // Back to the start of the line, erase it and the scrollback
+    write!(out, "\r\x1b[2K\x1b[3J")?;
+    out.flush()
+}
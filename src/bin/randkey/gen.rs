@@ -0,0 +1,142 @@
+//! `randkey gen`
+use {
+    crate::{CliResult, display, spec, parse_duration, value_of},
+    rand_key::{RandKey, ASCIIExcludeCtrl::Punctuation, presets::{self, Preset}},
+    std::{
+        thread,
+        time::Duration,
+        io::{self, ErrorKind, Write},
+    },
+};
+
+
+
+
+#[derive(Debug, Default)]
+struct GenArgs {
+    counts:   Vec<String>,
+    preset:   Option<Preset>,
+    show_for: Option<Duration>,
+    spec:     Option<String>,
+    exclude:  Option<String>,
+    symbols:  Option<String>,
+    count:    Option<usize>,
+    every:    Option<Duration>,
+    wpa_conf: Option<String>,
+    ssid:     Option<String>,
+}
+
+
+/// The interval of `--follow` without `--every`
+const FOLLOW_EVERY: Duration = Duration::from_secs(1);
+
+
+/// Split `chars` into the items of the data
+fn items(chars: &str) -> Vec<String> { chars.chars().map(String::from).collect() }
+
+
+impl GenArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> CliResult<Self> {
+        let mut parsed = GenArgs::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--preset" => parsed.preset = Some(value_of(&arg, &mut args)?.parse()?),
+                "--show-for" => parsed.show_for = Some(parse_duration(&value_of(&arg, &mut args)?)?),
+                "--spec" => parsed.spec = Some(value_of(&arg, &mut args)?),
+                "--exclude-chars" => parsed.exclude = Some(value_of(&arg, &mut args)?),
+                "--only-symbols" => parsed.symbols = Some(value_of(&arg, &mut args)?),
+                "--count" => parsed.count = Some(value_of(&arg, &mut args)?.parse()?),
+                "--every" => parsed.every = Some(parse_duration(&value_of(&arg, &mut args)?)?),
+                "--follow" => parsed.every = parsed.every.or(Some(FOLLOW_EVERY)),
+                "--wpa-conf" => parsed.wpa_conf = Some(value_of(&arg, &mut args)?),
+                "--ssid" => parsed.ssid = Some(value_of(&arg, &mut args)?),
+                _ if arg.starts_with("--") => return Err(format!("Unknown option `{}`", arg).into()),
+                _ => parsed.counts.push(arg),
+            }
+        }
+
+        if parsed.show_for.is_some() && (parsed.count.is_some() || parsed.every.is_some()) {
+            return Err("`--show-for` shows a single key, without `--count`, `--every` or `--follow`".into());
+        }
+
+        match parsed.counts.len() {
+            0 | 3 => Ok(parsed),
+            _ => Err("Give the counts of letters, symbols and numbers, or none of them".into()),
+        }
+    }
+
+    fn to_randkey(&self) -> CliResult<RandKey> {
+        let mut r_p = match (&self.counts[..], self.preset) {
+            ([l, s, n], _) => RandKey::new(l, s, n)?,
+            (_, Some(preset)) => preset.to_randkey(),
+            _ => RandKey::default(),
+        };
+
+        if let Some(symbols) = &self.symbols {
+            if !symbols.chars().all(|c| c.is_ascii_punctuation()) {
+                return Err(format!("`--only-symbols` takes symbols only, not `{}`", symbols).into());
+            }
+            r_p.clear(Punctuation);
+            r_p.add_item(&items(symbols))?;
+        }
+
+        if let Some(exclude) = &self.exclude {
+            r_p.del_item(&items(exclude))?;
+        }
+
+        Ok(r_p)
+    }
+}
+
+
+pub(crate) fn run(args: impl Iterator<Item = String>) -> CliResult<()> {
+    let args = GenArgs::parse(args)?;
+
+    if let Some(path) = &args.spec {
+        return spec::run(path);
+    }
+
+    let r_p = args.to_randkey()?;
+
+    if let Some(conf) = &args.wpa_conf {
+        let ssid = args.ssid.as_deref().ok_or("`--wpa-conf` needs `--ssid`")?;
+        let key = r_p.generate()?;
+
+        print!("{}", match conf.as_str() {
+            "supplicant" => presets::wpa_supplicant_conf(ssid, &key)?,
+            "hostapd" => presets::hostapd_conf(ssid, &key)?,
+            _ => return Err(format!("`--wpa-conf` is `supplicant` or `hostapd`, not `{}`", conf).into()),
+        });
+        return Ok(());
+    }
+
+    if let Some(duration) = args.show_for {
+        return Ok(display::show_for(&r_p.generate()?, duration)?);
+    }
+
+    match emit(&r_p, args.count.unwrap_or(1), args.every) {
+        // The reader has gone, like `head`
+        Err(e) if e.downcast_ref::<io::Error>().is_some_and(|e| e.kind() == ErrorKind::BrokenPipe) => Ok(()),
+        result => result,
+    }
+}
+
+
+/// Print `count` keys, and again `every` interval until interrupted
+fn emit(r_p: &RandKey, count: usize, every: Option<Duration>) -> CliResult<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    loop {
+        for _ in 0..count {
+            writeln!(out, "{}", r_p.generate()?)?;
+        }
+        out.flush()?;
+
+        match every {
+            Some(every) => thread::sleep(every),
+            None => return Ok(()),
+        }
+    }
+}
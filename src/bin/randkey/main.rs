@@ -0,0 +1,87 @@
+//! `randkey <COMMAND> [OPTIONS]`, generate keys from the command line.
+//!
+//! * `randkey gen [LTR SBL NUM] [--preset NAME] [--show-for SECS] [--only-symbols CHARS] [--exclude-chars CHARS]`,
+//!   print a key, of 12 letters, 2 symbols and 3 numbers unless the counts or a preset are given.
+//!   `--only-symbols` replaces the symbols of the data, and `--exclude-chars` deletes characters from it.
+//! * `randkey gen [--count N] [--every INTERVAL | --follow]`, print N keys, again every INTERVAL
+//!   (each second with `--follow`) until interrupted.
+//!   With `--show-for`, the key is cleared from the terminal after SECS seconds.
+//! * `randkey gen --preset wpa --wpa-conf <supplicant | hostapd> --ssid SSID`, print the config joining SSID.
+//! * `randkey gen --spec FILE`, print the CSV of a labeled key for each row of the CSV FILE, see `spec.rs`.
+//! * `randkey tui`, tune the key interactively, with the `tui` feature.
+//! * `randkey daemon [--socket PATH]`, serve keys to the requests in JSON on a Unix socket, with the `daemon` feature, see `daemon.rs`.
+//! * `randkey analyze <FILE | -> [--min-strength NAME] [--min-classes N] [--min-length N]`, score the passwords and scan them for human patterns
+//!   on each line of FILE or the standard input, against a policy of `reasonable` and 3 kinds of characters by default.
+use std::{
+    env::args,
+    error::Error,
+    process::exit,
+    time::Duration,
+};
+
+
+mod analyze;
+#[cfg(all(feature = "daemon", unix))]
+mod daemon;
+mod display;
+mod gen;
+mod spec;
+#[cfg(feature = "tui")]
+mod tui;
+
+
+
+
+pub(crate) type CliResult<T> = Result<T, Box<dyn Error>>;
+
+
+/// Parse a duration like `30`, `30s`, `500ms`, `5m` or `1h`, a bare number is in seconds
+pub(crate) fn parse_duration(s: &str) -> CliResult<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+    let (value, suffix) = s.split_at(split);
+    let value: f64 = value.parse().map_err(|_| format!("Invalid duration `{}`", s))?;
+
+    let secs = match suffix {
+        "" | "s" => value,
+        "ms" => value / 1000.0,
+        "m" => value * 60.0,
+        "h" => value * 3600.0,
+        _ => return Err(format!("Invalid duration `{}`", s).into()),
+    };
+
+    Ok(Duration::from_secs_f64(secs))
+}
+
+
+/// Return the value following the option `name`
+pub(crate) fn value_of(name: &str, args: &mut impl Iterator<Item = String>) -> CliResult<String> {
+    args.next().ok_or_else(|| format!("`{}` needs a value", name).into())
+}
+
+
+fn run() -> CliResult<()> {
+    let mut args = args().skip(1);
+
+    match args.next().as_deref() {
+        Some("gen") => gen::run(args),
+        Some("analyze") => analyze::run(args),
+        #[cfg(feature = "tui")]
+        Some("tui") => tui::run(),
+        #[cfg(not(feature = "tui"))]
+        Some("tui") => Err("`randkey tui` needs the `tui` feature".into()),
+        #[cfg(all(feature = "daemon", unix))]
+        Some("daemon") => daemon::run(args),
+        #[cfg(not(all(feature = "daemon", unix)))]
+        Some("daemon") => Err("`randkey daemon` needs the `daemon` feature and a Unix".into()),
+        Some(cmd) => Err(format!("Unknown command `{}`, see `randkey gen`", cmd).into()),
+        None => Err("Usage: randkey gen [LTR SBL NUM] [--preset NAME] [--show-for SECS] [--spec FILE]".into()),
+    }
+}
+
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("randkey: {}", e);
+        exit(1);
+    }
+}
@@ -0,0 +1,96 @@
+//! `randkey gen --spec FILE`, a key for each row of a CSV file
+//!
+//! The header names the columns, in any order and all optional: `label`, `preset`, `ltr_cnt`, `sbl_cnt` and `num_cnt`.
+//! The counts override the ones of the preset, which is `RandKey::default()` if not given.
+use {
+    crate::CliResult,
+    rand_key::{RandKey, LabeledKey, ASCIIExcludeCtrl::*, export, presets::Preset},
+    std::{
+        fs::File,
+        io::{self, BufRead, BufReader},
+    },
+};
+
+
+
+
+/// Split a line of CSV, the fields may be quoted with `"`, and `""` is a quote in them
+fn split_csv(line: &str) -> CliResult<Vec<String>> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let field = fields.last_mut().unwrap();
+
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(String::new()),
+            _ => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(format!("Unclosed quote in `{}`", line).into());
+    }
+
+    Ok(fields)
+}
+
+
+/// Generate the key of a row, `get` returns the non-empty value of a column
+fn generate(get: impl Fn(&str) -> Option<String>) -> CliResult<LabeledKey> {
+    let mut r_p = match get("preset") {
+        Some(preset) => preset.parse::<Preset>()?.to_randkey(),
+        None => RandKey::default(),
+    };
+
+    for (column, kind) in [("ltr_cnt", Alphabetic), ("sbl_cnt", Punctuation), ("num_cnt", Digit)].iter() {
+        if let Some(cnt) = get(column) {
+            if !cnt.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format!("Invalid `{}`: `{}`", column, cnt).into());
+            }
+            r_p.set_cnt(*kind, cnt);
+        }
+    }
+
+    Ok(LabeledKey { label: get("label").unwrap_or_default(), key: r_p.generate()? })
+}
+
+
+pub(crate) fn run(path: &str) -> CliResult<()> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header = match lines.next() {
+        Some(line) => split_csv(&line?)?,
+        None => return Err(format!("`{}` is empty", path).into()),
+    };
+
+    let mut keys = Vec::new();
+
+    for (i, line) in lines.enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let row = split_csv(&line)?;
+        let get = |column: &str| {
+            header.iter()
+                  .position(|h| h.trim() == column)
+                  .and_then(|j| row.get(j))
+                  .map(|v| v.trim().to_string())
+                  .filter(|v| !v.is_empty())
+        };
+
+        // The header is the first line
+        keys.push(generate(get).map_err(|e| format!("Row {}: {}", i + 2, e))?);
+    }
+
+    export::to_csv(&keys, io::stdout().lock())?;
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! `randkey tui`, tune the counts and the kinds of characters and watch the strength change
+use {
+    crate::CliResult,
+    rand_key::{RandKey, ASCIIExcludeCtrl::{self, *}},
+    std::io::{self, Write},
+    crossterm::{
+        cursor::{Hide, MoveTo, Show},
+        event::{self, Event, KeyCode, KeyEventKind},
+        terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+        ExecutableCommand, QueueableCommand,
+    },
+};
+
+
+
+
+const MAX_CNT: usize = 64;
+const SLIDER_WIDTH: usize = 32;
+const HELP: &str = "Up/Down select, Left/Right adjust, Space toggle, R regenerate, Q quit";
+
+
+/// Restore the terminal when dropped, even on errors
+struct Screen;
+
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        io::stdout().execute(EnterAlternateScreen)?.execute(Hide)?;
+
+        Ok(Screen)
+    }
+}
+
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = io::stdout().execute(Show).and_then(|out| out.execute(LeaveAlternateScreen));
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+
+struct State {
+    cnts:     [usize; 3],
+    enabled:  [bool; 3],
+    selected: usize,
+    key:      String,
+    r_p:      RandKey,
+}
+
+
+impl State {
+    const KINDS: [(ASCIIExcludeCtrl, &'static str); 3] = [(Alphabetic, "Letters"), (Punctuation, "Symbols"), (Digit, "Numbers")];
+
+    fn new() -> CliResult<Self> {
+        let mut state = State {
+            cnts:     [12, 2, 3],
+            enabled:  [true; 3],
+            selected: 0,
+            key:      String::new(),
+            r_p:      RandKey::default(),
+        };
+        state.regenerate()?;
+
+        Ok(state)
+    }
+
+    fn regenerate(&mut self) -> CliResult<()> {
+        for (i, (kind, _)) in Self::KINDS.iter().enumerate() {
+            let cnt = if self.enabled[i] { self.cnts[i] } else { 0 };
+            self.r_p.set_cnt(*kind, cnt.to_string());
+        }
+        self.key = self.r_p.generate()?;
+
+        Ok(())
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        out.queue(Clear(ClearType::All))?.queue(MoveTo(0, 0))?;
+        write!(out, "randkey tui: {}\r\n\r\n", HELP)?;
+
+        for (i, (_, name)) in Self::KINDS.iter().enumerate() {
+            let filled = self.cnts[i] * SLIDER_WIDTH / MAX_CNT;
+
+            write!(out,
+                   "{} [{}] {}  [{}{}] {:>2}\r\n",
+                   if i == self.selected { '>' } else { ' ' },
+                   if self.enabled[i] { 'x' } else { ' ' },
+                   name,
+                   "#".repeat(filled),
+                   "-".repeat(SLIDER_WIDTH - filled),
+                   self.cnts[i])?;
+        }
+
+        let report = self.r_p.strength();
+        write!(out, "\r\nKey:      {}\r\n", self.key)?;
+        write!(out, "Strength: {} ({:?})\r\n", report.render_bar(SLIDER_WIDTH), report.strength())?;
+
+        out.flush()
+    }
+}
+
+
+pub(crate) fn run() -> CliResult<()> {
+    let _screen = Screen::enter()?;
+    let mut out = io::stdout();
+    let mut state = State::new()?;
+
+    loop {
+        state.draw(&mut out)?;
+
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+
+        let i = state.selected;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Up => state.selected = (i + 2) % 3,
+            KeyCode::Down => state.selected = (i + 1) % 3,
+            KeyCode::Left => state.cnts[i] = state.cnts[i].saturating_sub(1),
+            KeyCode::Right => state.cnts[i] = (state.cnts[i] + 1).min(MAX_CNT),
+            KeyCode::Char(' ') => state.enabled[i] = !state.enabled[i],
+            KeyCode::Char('r') | KeyCode::Enter => {}
+            _ => continue,
+        }
+
+        state.regenerate()?;
+    }
+}
@@ -0,0 +1,174 @@
+//! `randkeyd [ADDR] [RATE] [BURST]`, serve `POST /generate` on ADDR (default 127.0.0.1:8080),
+//! each client may generate BURST characters at once (default 100000), refilled by RATE per second (default 10000).
+//!
+//! The request body is JSON, every field is optional:
+//! `{"preset": "standard", "ltr_cnt": "10", "sbl_cnt": "2", "num_cnt": "3", "batch": 2, "labels": ["a", "b"]}`.
+//! The counts override the ones of the preset, and `labels` sets the batch size.
+//! The response is `{"keys": [{"label": "a", "key": "…"}, …]}` or `{"error": "…"}`, see `BatchRequest`.
+use {
+    serde_json::json,
+    rand_key::{RandKey, GenError, KeyService, service::BatchRequest},
+    std::{
+        env::args,
+        error::Error,
+        sync::Arc,
+        thread,
+        time::Duration,
+        io::{self, BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+    },
+};
+
+
+
+
+const MAX_BODY: usize = 1 << 16;
+const MAX_LINE: u64 = 1 << 13;
+const MAX_HEADERS: usize = 100;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+
+fn generate(service: &KeyService, tenant: &str, body: &[u8]) -> Result<serde_json::Value, (u16, String)> {
+    let req: BatchRequest = serde_json::from_slice(body).map_err(|e| (400, e.to_string()))?;
+
+    match service.generate_batch(tenant, &req) {
+        Ok(keys) => Ok(json!({ "keys": keys })),
+        Err(e @ GenError::RateLimited) => Err((429, e.to_string())),
+        Err(e @ GenError::AllocationFailed) => Err((413, e.to_string())),
+        Err(e) => Err((400, e.to_string())),
+    }
+}
+
+
+fn respond(stream: &mut TcpStream, status: u16, body: serde_json::Value) -> io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        _ => "Error",
+    };
+    let body = body.to_string();
+
+    write!(stream,
+           "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+           status,
+           reason,
+           body.len(),
+           body)
+}
+
+
+/// Read a line cut at MAX_LINE, so a client can't fill the memory, return `None` if it's too long
+fn read_line(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    reader.take(MAX_LINE).read_line(&mut line)?;
+
+    if !line.ends_with('\n') && line.len() as u64 == MAX_LINE {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+
+fn handle(mut stream: TcpStream, service: &KeyService) -> io::Result<()> {
+    let tenant = stream.peer_addr()?.ip().to_string();
+    // A client that stops sending doesn't hold its thread forever
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    match read_head(&mut reader) {
+        Ok(Some((request_line, content_length))) => serve(stream, reader, service, &tenant, &request_line, content_length),
+        Ok(None) => respond(&mut stream, 431, json!({ "error": "Request header fields too large" })),
+        Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => {
+            respond(&mut stream, 408, json!({ "error": "Request timed out" }))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+
+/// Return the request line and the content length, `None` if a line or the number of headers is too large
+fn read_head(reader: &mut impl BufRead) -> io::Result<Option<(String, usize)>> {
+    let request_line = match read_line(reader)? {
+        Some(line) => line,
+        None => return Ok(None),
+    };
+
+    let mut content_length = 0;
+    let mut headers = 0;
+    loop {
+        let line = match read_line(reader)? {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+        if line.is_empty() || line.trim().is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Ok(None);
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    Ok(Some((request_line, content_length)))
+}
+
+
+fn serve(mut stream: TcpStream, mut reader: impl BufRead, service: &KeyService, tenant: &str, request_line: &str, content_length: usize) -> io::Result<()> {
+    if !request_line.starts_with("POST /generate ") {
+        return respond(&mut stream, 404, json!({ "error": "Only POST /generate is served" }));
+    }
+    if content_length > MAX_BODY {
+        return respond(&mut stream, 413, json!({ "error": "Request body too large" }));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    match generate(service, tenant, &body) {
+        Ok(keys) => respond(&mut stream, 200, keys),
+        Err((status, error)) => respond(&mut stream, status, json!({ "error": error })),
+    }
+}
+
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = args().skip(1).collect();
+
+    let addr = args.first().map_or("127.0.0.1:8080", String::as_str);
+    let rate = args.get(1).map_or(Ok(10000.0), |r| r.parse())?;
+    let burst = args.get(2).map_or(Ok(100000.0), |b| b.parse())?;
+
+    let service = Arc::new(KeyService::new(RandKey::default().into(), rate, burst));
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("randkeyd listening on {}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        // A failed connection is the client's, the daemon goes on serving the others
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("randkeyd: {}", e);
+                continue;
+            }
+        };
+        let service = service.clone();
+
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, &service) {
+                eprintln!("randkeyd: {}", e);
+            }
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use {
+    std::cmp::Ordering,
+    crate::{RandKey, GenError, passphrase::Passphrase, utils::{BigUint, Zero, ToPrimitive}},
+};
+
+
+
+
+/// A secret sharing an [`EntropyBudget`] with the others
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum Factor {
+    /// The keys of a profile, resized in the proportions of its counts
+    Key(RandKey),
+    /// The passphrases of a generator, resized in words
+    Passphrase(Passphrase),
+}
+
+
+impl Factor {
+    /// Return the entropy of the secret in bits
+    #[inline]
+    pub fn bits(&self) -> f64 {
+        match self {
+            Factor::Key(r_p) => r_p.strength().bits,
+            Factor::Passphrase(gen) => gen.entropy_bits(),
+        }
+    }
+
+    /// Return the length of the secret, in characters for a key and in words for a passphrase
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Factor::Key(r_p) => _TOTAL(r_p).to_usize().unwrap_or(usize::MAX),
+            Factor::Passphrase(gen) => gen.word_count(),
+        }
+    }
+
+    /// Check whether the secret is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Return the secret resized to `len`
+    #[inline]
+    fn resized(&self, len: usize) -> Self {
+        match self {
+            Factor::Key(r_p) => Factor::Key(_RESIZE(r_p, len)),
+            Factor::Passphrase(gen) => {
+                let mut gen = gen.clone();
+                gen.count = len;
+                Factor::Passphrase(gen)
+            }
+        }
+    }
+
+    /// Return the shortest secret, no shorter than this one, of at least `bits` of entropy
+    ///
+    /// Return `GenError::PoolTooSmall` if it gains no entropy as it grows.
+    #[inline]
+    fn grown_to(&self, bits: f64, index: usize) -> Result<Self, GenError> {
+        if self.bits() >= bits {
+            return Ok(self.clone());
+        }
+
+        let stuck = || GenError::PoolTooSmall(format!("the secret {} gains no entropy as it grows", index));
+
+        // Double the length until it's enough, then bisect
+        let (mut lo, mut hi) = (self.len(), self.len().max(1));
+        let mut last = self.bits();
+        loop {
+            hi = hi.checked_mul(2).ok_or_else(stuck)?;
+            let grown = self.resized(hi).bits();
+            if grown >= bits {
+                break;
+            }
+            if grown <= last {
+                return Err(stuck());
+            }
+            lo = hi;
+            last = grown;
+        }
+
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            match self.resized(mid).bits().partial_cmp(&bits) {
+                Some(Ordering::Less) => lo = mid,
+                _ => hi = mid,
+            }
+        }
+
+        Ok(self.resized(hi))
+    }
+}
+
+
+impl From<RandKey> for Factor {
+    #[inline]
+    fn from(r_p: RandKey) -> Self { Factor::Key(r_p) }
+}
+
+
+impl From<Passphrase> for Factor {
+    #[inline]
+    fn from(gen: Passphrase) -> Self { Factor::Passphrase(gen) }
+}
+
+
+/// Return how many characters the keys of `r_p` have
+#[inline]
+fn _TOTAL(r_p: &RandKey) -> BigUint { &r_p.ltr_cnt + &r_p.sbl_cnt + &r_p.num_cnt }
+
+
+/// Return `r_p` with `len` characters, `len` no less than its own,
+/// shared among the kinds in the proportions of the counts by the largest remainders
+#[inline]
+fn _RESIZE(r_p: &RandKey, len: usize) -> RandKey {
+    let total = _TOTAL(r_p);
+    let len = BigUint::from(len);
+
+    let counts = [&r_p.ltr_cnt, &r_p.sbl_cnt, &r_p.num_cnt];
+    let mut shares: Vec<(BigUint, BigUint)> = counts.iter().map(|c| (*c * &len / &total, *c * &len % &total)).collect();
+
+    let mut left = &len - shares.iter().map(|(q, _)| q).sum::<BigUint>();
+    let mut order: Vec<usize> = (0..3).collect();
+    order.sort_by(|a, b| shares[*b].1.cmp(&shares[*a].1));
+    for i in order {
+        if left.is_zero() {
+            break;
+        }
+        shares[i].0 += 1u8;
+        left -= 1u8;
+    }
+
+    let mut resized = r_p.clone();
+    resized.ltr_cnt = shares[0].0.clone();
+    resized.sbl_cnt = shares[1].0.clone();
+    resized.num_cnt = shares[2].0.clone();
+    resized
+}
+
+
+/// A total of entropy which several secrets meet together, like a PIN and a passphrase
+/// each one too weak on its own
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, EntropyBudget, Factor, passphrase::Passphrase};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pin = RandKey::new("0", "0", "4")?;
+/// let phrase = Passphrase::new(3);
+///
+/// let factors = EntropyBudget::new(80.0).split(&[pin.into(), phrase.into()])?;
+/// assert!(factors.iter().map(Factor::bits).sum::<f64>() >= 80.0);
+/// assert_eq!(factors.iter().map(Factor::len).collect::<Vec<_>>(), vec![13, 5]);
+///
+/// if let Factor::Key(pin) = &factors[0] {
+///     assert_eq!(pin.generate()?.len(), 13);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EntropyBudget {
+    bits: f64,
+}
+
+
+impl EntropyBudget {
+    /// Return a budget of `bits` of entropy
+    #[inline]
+    pub fn new(bits: f64) -> Self { EntropyBudget { bits } }
+
+    /// Return the entropy in bits the secrets meet together
+    #[inline]
+    pub fn bits(&self) -> f64 { self.bits }
+
+    /// Return the secrets lengthened so that their entropy sums up to the budget
+    ///
+    /// The budget is shared evenly, and the part a secret has already beyond its share goes to the others.
+    /// No secret gets shorter.
+    ///
+    /// Return `GenError::InconsistentField` if there is no secret or a key has no characters,
+    /// and `GenError::PoolTooSmall` if a secret gains no entropy as it grows, like a kind of a single character.
+    #[inline]
+    pub fn split(&self, factors: &[Factor]) -> Result<Vec<Factor>, GenError> {
+        if factors.is_empty() || factors.iter().any(|f| matches!(f, Factor::Key(r_p) if _TOTAL(r_p).is_zero())) {
+            return Err(GenError::InconsistentField);
+        }
+
+        // The secrets already beyond their shares keep their lengths, the others share what's left
+        let mut fixed = vec![false; factors.len()];
+        let mut left = self.bits;
+        let share = loop {
+            let active = fixed.iter().filter(|f| !**f).count();
+            let share = if active == 0 { 0.0 } else { left / active as f64 };
+
+            let mut changed = false;
+            for (i, f) in factors.iter().enumerate() {
+                if !fixed[i] && f.bits() >= share {
+                    fixed[i] = true;
+                    left -= f.bits();
+                    changed = true;
+                }
+            }
+            if !changed {
+                break share;
+            }
+        };
+
+        factors.iter()
+               .enumerate()
+               .map(|(i, f)| if fixed[i] { Ok(f.clone()) } else { f.grown_to(share, i) })
+               .collect()
+    }
+}
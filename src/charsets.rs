@@ -0,0 +1,96 @@
+use {
+    serde::{Serialize, Deserialize},
+    crate::{RandKey, GenError, utils::_CHECK_ASCII},
+};
+
+
+
+
+/// The version of the JSON schema written by [`RandKey::export_charsets`]
+pub(crate) const _CHARSETS_VERSION: u32 = 1;
+
+
+/// The JSON schema of the data
+#[derive(Serialize, Deserialize)]
+struct Charsets {
+    version: u32,
+    letters: Vec<String>,
+    symbols: Vec<String>,
+    numbers: Vec<String>,
+}
+
+
+impl RandKey {
+    /// Return the data as JSON, which [`RandKey::import_charsets`] loads back
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut curated = RandKey::default();
+    /// curated.del_item(&["0", "O", "1", "l", "I"])?;
+    /// let json = curated.export_charsets();
+    ///
+    /// let mut r_p = RandKey::default();
+    /// r_p.import_charsets(&json)?;
+    /// assert_eq!(r_p.all_data(), curated.all_data());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn export_charsets(&self) -> String {
+        let charsets = Charsets {
+            version: _CHARSETS_VERSION,
+            letters: self.DATA[0].clone(),
+            symbols: self.DATA[1].clone(),
+            numbers: self.DATA[2].clone(),
+        };
+
+        serde_json::to_string_pretty(&charsets).unwrap()
+    }
+
+    /// Replace the data by the JSON [`RandKey::export_charsets`] returns
+    ///
+    /// Return `GenError::InvalidCharsets` if it isn't valid JSON of a known version,
+    /// `GenError::InvalidChar` if a character isn't in its kind,
+    /// and `GenError::MissChar` if a kind of characters in use is empty.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// let mut r_p = RandKey::default();
+    /// assert!(r_p.import_charsets("{}").is_err());
+    /// assert!(r_p.import_charsets(r#"{"version":1,"letters":["1"],"symbols":["-"],"numbers":["2"]}"#).is_err());
+    /// assert!(r_p.import_charsets(r#"{"version":1,"letters":["a"],"symbols":["-"],"numbers":["2"]}"#).is_ok());
+    /// ```
+    #[inline]
+    pub fn import_charsets(&mut self, json: &str) -> Result<(), GenError> {
+        let charsets: Charsets = serde_json::from_str(json).map_err(|e| GenError::InvalidCharsets(e.to_string()))?;
+
+        if charsets.version != _CHARSETS_VERSION {
+            return Err(GenError::InvalidCharsets(format!("Unknown version {}", charsets.version)));
+        }
+
+        let data = vec![charsets.letters, charsets.symbols, charsets.numbers];
+        let kinds: [fn(&char) -> bool; 3] = [char::is_ascii_alphabetic, char::is_ascii_punctuation, char::is_ascii_digit];
+
+        for (v, is_kind) in data.iter().zip(kinds.iter()) {
+            if !_CHECK_ASCII(v) || !v.iter().all(|s| s.chars().all(|c| is_kind(&c))) {
+                return Err(GenError::InvalidChar);
+            }
+        }
+
+        // Keep the old data if the new one can't generate the key
+        let old = std::mem::replace(&mut self.DATA, data);
+        if let Err(e) = self.check_data() {
+            self.DATA = old;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
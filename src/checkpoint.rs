@@ -0,0 +1,196 @@
+use {
+    std::{
+        fs::{self, OpenOptions},
+        io::Write,
+        path::{Path, PathBuf},
+    },
+    crate::{RandKey, GenError, Strategy, parallel::{parallel_available, _THREADS}, rules::_PLACE, utils::*},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+
+
+
+/// Progress of [`RandKey::join_resumable`], from which [`RandKey::resume`] goes on
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Checkpoint {
+    /// The file the characters picked so far are written to
+    pub path:   PathBuf,
+    /// How many letters, symbols and numbers the key has, the check digit aside
+    pub counts: [usize; 3],
+    /// How many of them are picked
+    pub done:   [usize; 3],
+    /// How many bytes of the file they take
+    pub bytes:  u64,
+}
+
+
+impl Checkpoint {
+    /// Check whether all the characters are picked
+    #[inline]
+    pub fn is_complete(&self) -> bool { self.done == self.counts }
+}
+
+
+impl RandKey {
+    /// Return the counts of the characters to pick, the check digit aside
+    ///
+    /// Return `GenError::InconsistentField` with `Strategy::Unique` or `Strategy::Coverage`,
+    /// the batches in the file can't be kept apart nor cover the data.
+    #[inline]
+    fn resumable_counts(&self) -> Result<[usize; 3], GenError> {
+        self.check_entropy()?;
+        self.check_pools()?;
+
+        if matches!(self.STRATEGY, Strategy::Unique | Strategy::Coverage) {
+            return Err(GenError::InconsistentField);
+        }
+
+        _COUNTS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM)
+    }
+
+    /// Arrange the characters picked into the file, one kind after another, like the ones of `join`
+    #[inline]
+    fn arrange_picked<T: Copy + Default>(&self, mut buf: Vec<T>, counts: [usize; 3]) -> Result<Vec<T>, GenError> {
+        if self.RULES.is_empty() {
+            _SHUFFLE(&mut buf, self.SEED);
+            Ok(buf)
+        } else {
+            _PLACE(buf, counts, &self.RULES, self.CHECKSUM.is_some(), self.SEED)
+        }
+    }
+
+    /// Generate the password like [`join`](RandKey::join), picking the characters into the file at `path`.
+    ///
+    /// After each batch of units, picked by all the threads at once, `on_checkpoint` is called with the progress,
+    /// which [`resume`](RandKey::resume) goes on from if the generation is stopped.
+    /// An error of `on_checkpoint` stops the generation and is returned.
+    /// Once all the characters are picked, they are arranged into the `key` field like [rules](RandKey::add_rule) say,
+    /// and the file is removed.
+    ///
+    /// The characters are written to the file in plaintext until then, so it's a part of the secret:
+    /// put it where only the owner can read it, and remove it if the generation is given up.
+    /// It's created readable by its owner only on Unix.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, Checkpoint};
+    /// use std::io;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let path = std::env::temp_dir().join("rand_key_resumable_doc");
+    /// let r_p = RandKey::new("100000", "20", "30")?;
+    /// r_p.set_unit("1000")?;
+    ///
+    /// // Stop at the first checkpoint, as if the process got killed
+    /// let mut saved: Option<Checkpoint> = None;
+    /// let stopped = r_p.join_resumable(&path, |c| {
+    ///     saved = Some(c.clone());
+    ///     Err(io::Error::from(io::ErrorKind::Interrupted).into())
+    /// });
+    /// assert!(stopped.is_err());
+    /// assert!(r_p.is_empty());
+    ///
+    /// r_p.resume(saved.unwrap(), |_| Ok(()))?;
+    /// assert_eq!(r_p.len(), "100050");
+    /// assert!(!path.exists());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_resumable(&self,
+                          path:          impl AsRef<Path>,
+                          on_checkpoint: impl FnMut(&Checkpoint) -> Result<(), GenError>) -> Result<(), GenError> {
+        let counts = self.resumable_counts()?;
+
+        let mut file = OpenOptions::new();
+        file.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        file.mode(0o600);
+        file.open(path.as_ref())?;
+
+        self.resume(Checkpoint { path: path.as_ref().into(), counts, done: [0; 3], bytes: 0 }, on_checkpoint)
+    }
+
+    /// Go on with the generation of [`join_resumable`](RandKey::join_resumable) from `checkpoint`
+    ///
+    /// The characters written to the file after the checkpoint are picked again.
+    /// Return `GenError::InconsistentField` if the counts of `self` aren't the ones of the checkpoint,
+    /// or with `Strategy::Unique` or `Strategy::Coverage`, the characters picked batch by batch can't be kept apart nor cover the data.
+    #[inline]
+    pub fn resume(&self,
+                  mut checkpoint:    Checkpoint,
+                  mut on_checkpoint: impl FnMut(&Checkpoint) -> Result<(), GenError>) -> Result<(), GenError> {
+        if checkpoint.counts != self.resumable_counts()?
+           || checkpoint.done.iter().zip(checkpoint.counts.iter()).any(|(done, cnt)| done > cnt) {
+            return Err(GenError::InconsistentField);
+        }
+
+        let mut file = OpenOptions::new().append(true).open(&checkpoint.path)?;
+        file.set_len(checkpoint.bytes)?;
+
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let unit = self.UNIT.borrow().to_usize().unwrap_or(usize::MAX).max(1);
+        let batch = unit.saturating_mul(_THREADS());
+        let seeding = self.SEEDING;
+
+        for (kind, pool) in self.DATA.iter().enumerate() {
+            while checkpoint.done[kind] < checkpoint.counts[kind] {
+                let n = (checkpoint.counts[kind] - checkpoint.done[kind]).min(batch);
+
+                // The units are numbered from the start of the kind, like the ones of `join`
+                let first = checkpoint.done[kind] / unit;
+                let pick = |i: usize| {
+                    let mut rng = seeding.unit_rng(kind, first + i);
+                    (0..unit.min(n - i * unit)).map(|_| pool[rng.gen_range(0, pool.len())].as_str())
+                                               .collect::<String>()
+                };
+                let picked = if parallel_available() {
+                    (0..n.div_ceil(unit)).into_par_iter().map(pick).collect::<Vec<_>>().concat()
+                } else {
+                    (0..n.div_ceil(unit)).map(pick).collect::<Vec<_>>().concat()
+                };
+
+                file.write_all(picked.as_bytes())?;
+                file.sync_data()?;
+
+                checkpoint.done[kind] += n;
+                checkpoint.bytes += picked.len() as u64;
+                on_checkpoint(&checkpoint)?;
+            }
+        }
+        drop(file);
+
+        let picked = fs::read_to_string(&checkpoint.path)?;
+        let mut key = if picked.is_ascii() {
+            String::from_utf8(self.arrange_picked(picked.into_bytes(), checkpoint.counts)?).expect("ASCII characters are valid UTF-8")
+        } else {
+            self.arrange_picked(picked.chars().collect(), checkpoint.counts)?.into_iter().collect()
+        };
+
+        if let Some(checksum) = self.CHECKSUM {
+            key.push(checksum.digit(&key)?);
+        }
+
+        #[cfg(feature = "metrics")]
+        crate::telemetry::_RECORD(Ok(&key), start.elapsed());
+
+        #[cfg(feature = "audit-log")]
+        crate::audit_log::_RECORD(|| self.config(), &key)?;
+
+        *self.key.borrow_mut() = key.into();
+        self.record_metadata();
+        fs::remove_file(&checkpoint.path)?;
+
+        Ok(())
+    }
+}
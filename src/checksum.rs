@@ -0,0 +1,166 @@
+//! Check digits appended to the generated keys, so typos are caught before they reach a server.
+use crate::GenError;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// Algorithms of the check digit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Checksum {
+    /// The Luhn (mod 10) algorithm, letters count as two digits: `a` or `A` is 10, … `z` or `Z` is 35
+    Luhn,
+    /// The Verhoeff algorithm, catching all the transpositions of adjacent digits
+    Verhoeff,
+    /// The Damm algorithm, catching all the transpositions of adjacent digits without a permutation table
+    Damm,
+}
+
+
+impl Checksum {
+    /// Return the check digit of `s`, which is made of letters and digits only.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::checksum::Checksum::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Luhn.digit("7992739871")?, '3');
+    /// assert_eq!(Verhoeff.digit("236")?, '3');
+    /// assert_eq!(Damm.digit("572")?, '4');
+    /// assert!(Luhn.digit("1-2").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn digit(self, s: &str) -> Result<char, GenError> {
+        let digits = _DIGITS(s)?;
+
+        let d = match self {
+            Checksum::Luhn => _LUHN(&digits),
+            Checksum::Verhoeff => _VERHOEFF(&digits),
+            Checksum::Damm => _DAMM(&digits),
+        };
+
+        Ok((b'0' + d) as char)
+    }
+
+    /// Check whether the last character of `s` is the check digit of the others.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::checksum::Checksum::Luhn;
+    ///
+    /// assert!(Luhn.verify("79927398713"));
+    /// assert!(!Luhn.verify("79927398731"));
+    /// ```
+    #[inline]
+    pub fn verify(self, s: &str) -> bool {
+        match s.char_indices().last() {
+            Some((i, last)) => self.digit(&s[..i]).is_ok_and(|d| d == last),
+            None => false,
+        }
+    }
+}
+
+
+/// Spell the letters and digits of `s` in digits
+#[inline]
+fn _DIGITS(s: &str) -> Result<Vec<u8>, GenError> {
+    let mut digits = Vec::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c.to_digit(36) {
+            Some(d) if c.is_ascii() => {
+                if d >= 10 {
+                    digits.push((d / 10) as u8);
+                }
+                digits.push((d % 10) as u8);
+            }
+            _ => return Err(GenError::InvalidChar),
+        }
+    }
+
+    Ok(digits)
+}
+
+
+#[inline]
+fn _LUHN(digits: &[u8]) -> u8 {
+    let sum: u32 = digits.iter()
+                         .rev()
+                         .enumerate()
+                         .map(|(i, d)| {
+                             let d = *d as u32;
+                             if i % 2 == 0 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d }
+                         })
+                         .sum();
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+
+#[rustfmt::skip]
+const _VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+
+#[rustfmt::skip]
+const _VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+
+const _VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+
+#[inline]
+fn _VERHOEFF(digits: &[u8]) -> u8 {
+    let c = digits.iter()
+                  .rev()
+                  .enumerate()
+                  .fold(0, |c, (i, d)| _VERHOEFF_D[c as usize][_VERHOEFF_P[(i + 1) % 8][*d as usize] as usize]);
+
+    _VERHOEFF_INV[c as usize]
+}
+
+
+#[rustfmt::skip]
+const _DAMM_TABLE: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+
+#[inline]
+fn _DAMM(digits: &[u8]) -> u8 { digits.iter().fold(0, |i, d| _DAMM_TABLE[i as usize][*d as usize]) }
@@ -0,0 +1,156 @@
+use {
+    std::{fmt::Debug, sync::Arc},
+    crate::{RandKey, GenError, ASCIIExcludeCtrl::{self, *}, utils::{BigUint, Zero, _GROUP_WITH, _CNT_WITH}},
+};
+
+
+
+
+/// What decides whether a character is a letter, a symbol or a number
+///
+/// The data, the counts of the keys set by [`set_key`](RandKey::set_key) and [`to_randkey_with`](crate::ToRandKey::to_randkey_with)
+/// and the items added by [`add_item`](RandKey::add_item) and [`replace_data`](RandKey::replace_data) are all classified by it.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, Classifier, DefaultClassifier, ToRandKey, ASCIIExcludeCtrl::{self, *}};
+///
+/// // `_` is a letter, like in identifiers
+/// #[derive(Debug)]
+/// struct Identifier;
+///
+/// impl Classifier for Identifier {
+///     fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl> {
+///         if c == '_' { Some(Alphabetic) } else { DefaultClassifier.classify(c) }
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let r_p = "snake_case1".to_randkey_with(Identifier)?;
+/// assert_eq!(r_p.get_cnt(Alphabetic), "10");
+/// assert_eq!(r_p.get_cnt(Punctuation), "0");
+/// assert!(r_p.data(Alphabetic).contains(&"_".to_string()));
+/// # Ok(())
+/// # }
+/// ```
+pub trait Classifier: Debug + Send + Sync {
+    /// Return the kind of `c`, `None` if it can't be in a key
+    fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl>;
+}
+
+
+/// The classes of ASCII, the other characters are rejected
+///
+/// See [`UnicodeClassifier`](crate::unicode::UnicodeClassifier) for the other characters, with the `unicode` feature.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultClassifier;
+
+
+impl Classifier for DefaultClassifier {
+    #[inline]
+    fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl> {
+        match c {
+            c if c.is_ascii_alphabetic() => Some(Alphabetic),
+            c if c.is_ascii_punctuation() => Some(Punctuation),
+            c if c.is_ascii_digit() => Some(Digit),
+            _ => None,
+        }
+    }
+}
+
+
+/// Counts of the letters, symbols and numbers of a string fed in chunks,
+/// for the data arriving over a network or read from files too big for the memory
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::ClassCounter;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut counter = ClassCounter::new();
+/// counter.update("ab12")?;
+/// counter.update("3_c53")?;
+///
+/// let (l, s, n) = counter.counts();
+/// assert_eq!((l.to_string(), s.to_string(), n.to_string()), ("3".into(), "1".into(), "5".into()));
+///
+/// // A chunk with a character out of the classes isn't counted
+/// assert!(counter.update("x\n").is_err());
+/// assert_eq!(counter.len(), 9u32.into());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClassCounter {
+    counts:     [BigUint; 3],
+    classifier: Arc<dyn Classifier>,
+}
+
+
+impl ClassCounter {
+    /// Return a counter with nothing counted, the characters classified by [`DefaultClassifier`]
+    #[inline]
+    pub fn new() -> Self { Self::with_classifier(DefaultClassifier) }
+
+    /// Return a counter with nothing counted, the characters classified by `classifier`
+    #[inline]
+    pub fn with_classifier(classifier: impl Classifier + 'static) -> Self {
+        ClassCounter { counts: Default::default(), classifier: Arc::new(classifier) }
+    }
+
+    /// Count the characters of `chunk` too
+    ///
+    /// Return `GenError::InvalidChar`, and count nothing of `chunk`, if the classifier rejects one of its characters.
+    #[inline]
+    pub fn update(&mut self, chunk: &str) -> Result<(), GenError> {
+        let (l, s, n) = _CNT_WITH(chunk, &*self.classifier)?;
+
+        self.counts[0] += l;
+        self.counts[1] += s;
+        self.counts[2] += n;
+
+        Ok(())
+    }
+
+    /// Return the counts of the letters, the symbols and the numbers so far
+    #[inline]
+    pub fn counts(&self) -> (BigUint, BigUint, BigUint) {
+        let [l, s, n] = self.counts.clone();
+        (l, s, n)
+    }
+
+    /// Return how many characters are counted so far
+    #[inline]
+    pub fn len(&self) -> BigUint { self.counts.iter().sum() }
+
+    /// Check whether nothing is counted so far
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len().is_zero() }
+}
+
+
+impl Default for ClassCounter {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+
+impl RandKey {
+    /// Return a counter with nothing counted, the characters classified like by this `RandKey`
+    #[inline]
+    pub fn class_counter(&self) -> ClassCounter { ClassCounter { counts: Default::default(), classifier: self.CLASSIFIER.clone() } }
+
+    /// Return what classifies the characters
+    #[inline]
+    pub fn classifier(&self) -> &dyn Classifier { &*self.CLASSIFIER }
+
+    /// Classify the characters by `classifier`, the data is classified again by it,
+    /// without the characters it rejects
+    #[inline]
+    pub fn set_classifier(&mut self, classifier: impl Classifier + 'static) {
+        self.CLASSIFIER = Arc::new(classifier);
+        self.DATA = _GROUP_WITH(&self.DATA.concat(), &*self.CLASSIFIER);
+    }
+}
@@ -0,0 +1,119 @@
+//! The API of `rand_pwd`, the old name of this crate, over `RandKey`, to upgrade without rewriting it all at once.
+//!
+//! `RandPwd` takes the counts in integers and its `join` can't fail.
+//! Each of its methods says what to call on `RandKey` instead, and [`RandPwd::into_randkey`] hands over to it.
+#![allow(deprecated)]
+use {
+    std::fmt::{self, Display, Formatter},
+    crate::{RandKey, ASCIIExcludeCtrl},
+};
+
+
+
+
+/// A `RandKey` with the API of `rand_pwd`
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// #![allow(deprecated)]
+/// use rand_key::{RandKey, compat::RandPwd, ASCIIExcludeCtrl::*};
+///
+/// let mut r_p = RandPwd::new(10, 2, 3);
+/// r_p.join();
+/// assert_eq!(r_p.show().len(), 15);
+/// assert_eq!(r_p.get_cnt(Digit), Some(3));
+///
+/// // And on to the new API
+/// let r_k: RandKey = r_p.into_randkey();
+/// assert_eq!(r_k.get_cnt(Digit), "3");
+/// ```
+#[deprecated(since = "1.3.0", note = "use `RandKey`, which takes the counts in strings and returns the errors")]
+#[derive(Clone, Debug)]
+pub struct RandPwd {
+    inner: RandKey,
+}
+
+
+impl RandPwd {
+    /// Return a `RandPwd` of `ltr` letters, `sbl` symbols and `num` numbers, like `RandKey::new`
+    #[deprecated(since = "1.3.0", note = "use `RandKey::new`")]
+    #[inline]
+    pub fn new(ltr: usize, sbl: usize, num: usize) -> Self {
+        let inner = RandKey::new(ltr.to_string(), sbl.to_string(), num.to_string()).expect("integers are valid counts");
+
+        RandPwd { inner }
+    }
+
+    /// Generate the password, like `RandKey::join`
+    ///
+    /// # Panics
+    ///
+    /// If `RandKey::join` returns an error, like when a kind to generate has no data left.
+    #[deprecated(since = "1.3.0", note = "use `RandKey::join`, which returns the errors")]
+    #[inline]
+    pub fn join(&mut self) {
+        if let Err(e) = self.inner.join() {
+            panic!("RandPwd::join failed: {}", e);
+        }
+    }
+
+    /// Return the password, like `RandKey::key`
+    #[deprecated(since = "1.3.0", note = "use `RandKey::key`")]
+    #[inline]
+    pub fn show(&self) -> String { self.inner.key() }
+
+    /// Return all the characters to pick from, the letters, then the symbols and the numbers
+    #[deprecated(since = "1.3.0", note = "use `RandKey::data`, which returns the data of a kind")]
+    #[inline]
+    pub fn data(&self) -> Vec<String> { self.inner.DATA.concat() }
+
+    /// Return the count of `kind`, `None` if it doesn't fit in `usize`, like `RandKey::get_cnt`
+    #[deprecated(since = "1.3.0", note = "use `RandKey::get_cnt`, which returns a string")]
+    #[inline]
+    pub fn get_cnt(&self, kind: ASCIIExcludeCtrl) -> Option<usize> { self.inner.get_cnt(kind).parse().ok() }
+
+    /// Set the count of `kind`, like `RandKey::set_cnt`
+    #[deprecated(since = "1.3.0", note = "use `RandKey::set_cnt`, which takes a string")]
+    #[inline]
+    pub fn set_cnt(&mut self, kind: ASCIIExcludeCtrl, val: usize) { self.inner.set_cnt(kind, val.to_string()); }
+
+    /// Set the unit, like `RandKey::set_unit`, a unit of 0 is taken as 1 so it never fails
+    #[deprecated(since = "1.3.0", note = "use `RandKey::set_unit`, which takes a string")]
+    #[inline]
+    pub fn set_unit(&mut self, val: usize) {
+        self.inner.set_unit(val.max(1).to_string()).expect("positive integers are valid units");
+    }
+
+    /// Return the `RandKey` underneath
+    #[inline]
+    pub fn as_randkey(&self) -> &RandKey { &self.inner }
+
+    /// Return the `RandKey` underneath, with the settings and the password of this `RandPwd`
+    #[inline]
+    pub fn into_randkey(self) -> RandKey { self.inner }
+}
+
+
+impl Default for RandPwd {
+    #[inline]
+    fn default() -> Self { RandPwd { inner: RandKey::default() } }
+}
+
+
+impl Display for RandPwd {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Display::fmt(&self.inner, f) }
+}
+
+
+impl From<RandKey> for RandPwd {
+    #[inline]
+    fn from(inner: RandKey) -> Self { RandPwd { inner } }
+}
+
+
+impl From<RandPwd> for RandKey {
+    #[inline]
+    fn from(r_p: RandPwd) -> Self { r_p.inner }
+}
@@ -0,0 +1,124 @@
+use {
+    std::sync::RwLock,
+    crate::{
+        RandKey, GenError,
+        checksum::Checksum,
+        prelude::AsBiguint,
+        utils::_GROUP_WITH,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+static GLOBAL_DEFAULTS: RwLock<Option<RandKeyConfig>> = RwLock::new(None);
+
+
+/// The settings of a `RandKey`, without its key
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RandKeyConfig {
+    pub ltr_cnt:  String,
+    pub sbl_cnt:  String,
+    pub num_cnt:  String,
+    pub unit:     String,
+    /// Letters, symbols and numbers to pick from
+    pub data:     Vec<Vec<String>>,
+    pub checksum: Option<Checksum>,
+}
+
+
+impl RandKey {
+    /// Return the settings of `RandKey`
+    #[inline]
+    pub fn config(&self) -> RandKeyConfig {
+        RandKeyConfig {
+            ltr_cnt:  self.ltr_cnt.to_string(),
+            sbl_cnt:  self.sbl_cnt.to_string(),
+            num_cnt:  self.num_cnt.to_string(),
+            unit:     self.UNIT.borrow().to_string(),
+            data:     self.DATA.clone(),
+            checksum: self.CHECKSUM,
+        }
+    }
+
+    /// Return an empty `RandKey` with the given settings
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.set_unit("100")?;
+    /// r_p.del_item(&["a", "b"])?;
+    ///
+    /// let copy = RandKey::from_config(&r_p.config())?;
+    /// assert_eq!(copy.config(), r_p.config());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_config(config: &RandKeyConfig) -> Result<Self, GenError> {
+        let mut r_p = RandKey::new(&config.ltr_cnt, &config.sbl_cnt, &config.num_cnt)?;
+        r_p.set_unit(&config.unit)?;
+        r_p.replace_data(&config.data.concat())?;
+        r_p.set_checksum(config.checksum);
+
+        Ok(r_p)
+    }
+
+    /// Apply the settings that were validated by `from_config`
+    #[inline]
+    pub(crate) fn apply_config(&mut self, config: &RandKeyConfig) {
+        self.ltr_cnt = config.ltr_cnt.as_biguint().unwrap_or_default();
+        self.sbl_cnt = config.sbl_cnt.as_biguint().unwrap_or_default();
+        self.num_cnt = config.num_cnt.as_biguint().unwrap_or_default();
+        *self.UNIT.borrow_mut() = config.unit.as_biguint().unwrap_or_else(|_| self.UNIT.borrow().clone());
+        self.DATA = _GROUP_WITH(&config.data.concat(), &*self.CLASSIFIER);
+        self.CHECKSUM = config.checksum;
+    }
+}
+
+
+/// Set the settings of every `RandKey` made by `Default` or `to_randkey` afterwards,
+/// `RandKey::new` takes all of them but the counts.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, set_global_defaults};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // Always exclude the ambiguous characters
+/// let mut base = RandKey::default();
+/// base.del_item(&["0", "O", "o", "1", "l", "I"])?;
+/// set_global_defaults(base.config())?;
+///
+/// let r_p = RandKey::new("20", "0", "10")?;
+/// r_p.join()?;
+/// assert!(!r_p.key().contains(&['0', 'O', 'o', '1', 'l', 'I'][..]));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn set_global_defaults(config: RandKeyConfig) -> Result<(), GenError> {
+    RandKey::from_config(&config)?;
+    *GLOBAL_DEFAULTS.write().unwrap_or_else(|e| e.into_inner()) = Some(config);
+    Ok(())
+}
+
+
+/// Go back to the built-in defaults
+#[inline]
+pub fn reset_global_defaults() { *GLOBAL_DEFAULTS.write().unwrap_or_else(|e| e.into_inner()) = None; }
+
+
+#[inline]
+pub(crate) fn _GLOBAL_DEFAULTS() -> Option<RandKeyConfig> {
+    GLOBAL_DEFAULTS.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
@@ -0,0 +1,87 @@
+use {
+    std::sync::Arc,
+    crate::RandKey,
+};
+
+#[cfg(feature = "secrecy")]
+use secrecy::SecretString;
+
+
+
+
+/// Return the key of `r_p`, moved out of it unless its clones share it
+#[inline]
+fn _TAKE_KEY(r_p: RandKey) -> String { Arc::try_unwrap(r_p.key.into_inner()).unwrap_or_else(|shared| String::clone(&shared)) }
+
+
+impl From<RandKey> for String {
+    /// Take the key out of the `RandKey`, without copying it unless it's shared with the clones of the `RandKey`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join()?;
+    ///
+    /// let key: String = r_p.into();
+    /// assert_eq!(key.len(), 15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn from(r_p: RandKey) -> Self { _TAKE_KEY(r_p) }
+}
+
+
+impl RandKey {
+    /// Return the key as bytes, consuming the `RandKey`, like [`String::from`] does
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::ToRandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "aB3$".to_randkey()?;
+    /// assert_eq!(r_p.into_bytes(), b"aB3$");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> { String::from(self).into_bytes() }
+
+    /// Return the key as a [`SecretString`], which is zeroed when dropped and never printed
+    ///
+    /// The key is moved into it, so no copy is left behind, unless the clones of this `RandKey` share the key:
+    /// it's copied then, and their copy isn't zeroed.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use secrecy::ExposeSecret;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join()?;
+    ///
+    /// let secret = r_p.into_secret_string();
+    /// assert_eq!(secret.expose_secret().len(), 15);
+    /// assert!(!format!("{:?}", secret).contains(secret.expose_secret()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "secrecy")]
+    #[inline]
+    pub fn into_secret_string(self) -> SecretString { SecretString::from(_TAKE_KEY(self)) }
+}
+
+
+impl From<RandKey> for Arc<String> {
+    /// Take the key out of the `RandKey` without copying it, even if it's shared with the clones of the `RandKey`
+    #[inline]
+    fn from(r_p: RandKey) -> Self { r_p.key.into_inner() }
+}
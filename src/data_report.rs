@@ -0,0 +1,233 @@
+use {
+    std::fmt::{self, Display, Formatter},
+    crate::{RandKey, GenError, ASCIIExcludeCtrl::{self, *}, utils::{BigUint, Zero, ToPrimitive}},
+};
+
+
+
+
+/// What to do when a count greatly exceeds the size of its pool
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PoolCheck {
+    /// Don't check the pools
+    Off,
+    /// Report a [`PoolWarning`] in the stats when a character repeats more than this many times on average
+    Warn(f64),
+    /// Fail with `GenError::PoolTooSmall` when a character repeats more than this many times on average
+    Error(f64),
+}
+
+
+impl Default for PoolCheck {
+    #[inline]
+    fn default() -> Self { PoolCheck::Warn(_REPETITION_LIMIT) }
+}
+
+
+/// The average repetitions of a character above which the default `PoolCheck` warns
+pub(crate) const _REPETITION_LIMIT: f64 = 100.0;
+
+
+/// A count which greatly exceeds the size of its pool, so its characters repeat a lot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolWarning {
+    /// The kind of characters
+    pub kind:      ASCIIExcludeCtrl,
+    /// How many characters of the kind a key has
+    pub count:     BigUint,
+    /// How many characters of the kind are in the data
+    pub pool_size: usize,
+}
+
+
+impl PoolWarning {
+    /// Return how many times a character of the pool is in a key on average
+    #[inline]
+    pub fn repetition(&self) -> f64 { _REPETITION(&self.count, self.pool_size) }
+}
+
+
+impl Display for PoolWarning {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+               "{} {} from a pool of {}, each one about {:.0} times",
+               self.count,
+               _KIND_NAME(self.kind),
+               self.pool_size,
+               self.repetition())
+    }
+}
+
+
+/// Return the plural name of `kind`
+#[inline]
+pub(crate) fn _KIND_NAME(kind: ASCIIExcludeCtrl) -> &'static str {
+    match kind {
+        Alphabetic => "letters",
+        Punctuation => "symbols",
+        Digit => "numbers",
+    }
+}
+
+
+/// Return how many times each of `pool_size` characters is among `count` ones on average, 0 for an empty pool
+#[inline]
+pub(crate) fn _REPETITION(count: &BigUint, pool_size: usize) -> f64 {
+    if pool_size == 0 { 0.0 } else { count.to_f64().unwrap_or(f64::INFINITY) / pool_size as f64 }
+}
+
+
+/// Whether the data can provide the characters the counts ask for
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataReport {
+    /// The kinds of characters whose count is non-zero but whose data is empty
+    pub missing:      Vec<ASCIIExcludeCtrl>,
+    /// How many letters, symbols and numbers are in the data
+    pub pool_sizes:   [usize; 3],
+    /// Entropy of a generated key in bits, leaving out the kinds which are missing
+    pub entropy_bits: f64,
+    /// How many times a letter, a symbol and a number are in a key on average
+    pub repetition:   [f64; 3],
+    /// The counts repeating their characters more than the `PoolCheck` allows
+    pub warnings:     Vec<PoolWarning>,
+}
+
+
+impl DataReport {
+    /// Return `true` if a key can be generated, i.e. no kind of characters is missing
+    #[inline]
+    pub fn is_consistent(&self) -> bool { self.missing.is_empty() }
+}
+
+
+impl Display for DataReport {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_consistent() {
+            return write!(f, "The data is consistent, a key has {:.0} bits of entropy", self.entropy_bits);
+        }
+
+        let names: Vec<&str> = self.missing.iter().map(|k| _KIND_NAME(*k)).collect();
+
+        write!(f, "The key asks for {} but there are none in the data", names.join(", "))
+    }
+}
+
+
+impl RandKey {
+    /// Return a detailed report of the consistency between the counts and the data,
+    /// which explains what [`GenError::MissChar`](crate::GenError::MissChar) complains about.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// assert!(r_p.data_report().is_consistent());
+    ///
+    /// r_p.clear(Punctuation);
+    /// let report = r_p.data_report();
+    /// assert_eq!(report.missing, vec![Punctuation]);
+    /// assert_eq!(report.pool_sizes, [52, 0, 10]);
+    /// assert_eq!(report.to_string(), "The key asks for symbols but there are none in the data");
+    /// assert!(r_p.join().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn data_report(&self) -> DataReport {
+        let cnts = [&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt];
+        let kinds = [Alphabetic, Punctuation, Digit];
+
+        let pool_sizes = [self.DATA[0].len(), self.DATA[1].len(), self.DATA[2].len()];
+
+        let missing = kinds.iter()
+                           .zip(cnts.iter().zip(pool_sizes.iter()))
+                           .filter(|(_, (cnt, size))| !cnt.is_zero() && **size == 0)
+                           .map(|(kind, _)| *kind)
+                           .collect();
+
+        let repetition = [
+            _REPETITION(cnts[0], pool_sizes[0]),
+            _REPETITION(cnts[1], pool_sizes[1]),
+            _REPETITION(cnts[2], pool_sizes[2]),
+        ];
+
+        DataReport { missing, pool_sizes, entropy_bits: self.strength().bits, repetition, warnings: self.pool_warnings() }
+    }
+
+    /// Return how a count greatly exceeding the size of its pool is reported
+    #[inline]
+    pub fn pool_check(&self) -> PoolCheck { self.POOL_CHECK }
+
+    /// Set how a count greatly exceeding the size of its pool is reported, like a million numbers out of ten.
+    ///
+    /// By default, [`join_with_stats`](RandKey::join_with_stats) reports a warning in the stats
+    /// when a character repeats more than 100 times on average.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, GenError, PoolCheck, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "0", "100000")?;
+    ///
+    /// let stats = r_p.join_with_stats()?;
+    /// assert_eq!(stats.warnings[0].kind, Digit);
+    /// assert_eq!(stats.warnings[0].to_string(), "100000 numbers from a pool of 10, each one about 10000 times");
+    /// assert_eq!(r_p.data_report().repetition[2], 10000.0);
+    ///
+    /// r_p.set_pool_check(PoolCheck::Error(1000.0));
+    /// assert!(matches!(r_p.join(), Err(GenError::PoolTooSmall(_))));
+    ///
+    /// r_p.set_pool_check(PoolCheck::Off);
+    /// assert!(r_p.join_with_stats()?.warnings.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_pool_check(&mut self, check: PoolCheck) { self.POOL_CHECK = check; }
+
+    /// Return the counts repeating their characters more than the `PoolCheck` allows
+    #[inline]
+    pub(crate) fn pool_warnings(&self) -> Vec<PoolWarning> { _POOL_WARNINGS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.POOL_CHECK) }
+
+    /// Return the warnings about the pools, or the first one as `GenError::PoolTooSmall` if they are errors
+    #[inline]
+    pub(crate) fn check_pools(&self) -> Result<Vec<PoolWarning>, GenError> {
+        _CHECK_POOLS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.POOL_CHECK)
+    }
+}
+
+
+/// Return the counts of `cnts` repeating the characters of `data` more than `check` allows
+#[inline]
+pub(crate) fn _POOL_WARNINGS(cnts: [&BigUint; 3], data: &[Vec<String>], check: PoolCheck) -> Vec<PoolWarning> {
+    let limit = match check {
+        PoolCheck::Off => return Vec::new(),
+        PoolCheck::Warn(limit) | PoolCheck::Error(limit) => limit,
+    };
+
+    [Alphabetic, Punctuation, Digit].iter()
+                                    .zip(cnts.iter())
+                                    .zip(data.iter())
+                                    .filter(|((_, cnt), data)| _REPETITION(cnt, data.len()) > limit)
+                                    .map(|((kind, cnt), data)| PoolWarning { kind: *kind, count: (*cnt).clone(), pool_size: data.len() })
+                                    .collect()
+}
+
+
+/// Return the warnings about the pools, or the first one as `GenError::PoolTooSmall` if `check` makes them errors
+#[inline]
+pub(crate) fn _CHECK_POOLS(cnts: [&BigUint; 3], data: &[Vec<String>], check: PoolCheck) -> Result<Vec<PoolWarning>, GenError> {
+    let warnings = _POOL_WARNINGS(cnts, data, check);
+
+    match (check, warnings.first()) {
+        (PoolCheck::Error(_), Some(warning)) => Err(GenError::PoolTooSmall(warning.to_string())),
+        _ => Ok(warnings),
+    }
+}
@@ -0,0 +1,64 @@
+//! Honeytokens, fake keys looking like a real one, for deception setups.
+use {
+    std::collections::HashSet,
+    crate::{RandKey, GenError, SeedStrategy, shuffle::_MAX_RETRIES, utils::_CNT},
+};
+
+
+
+
+/// Generate `n` distinct decoys of `real`, never equal to its key
+///
+/// The decoys have the length, the counts of letters, symbols and numbers, the data and the check digit of the key of `real`,
+/// or of the keys it generates if it has none. They are always random, whatever the seed of `real`.
+/// Return `GenError::AttemptsExhausted` if the data can't make `n` distinct decoys.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, decoy::generate_decoys, strength::class_counts};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut real = RandKey::default();
+/// real.set_key("aB3$dE5%gH", rand_key::SetRandKeyOp::Update)?;
+///
+/// let decoys = generate_decoys(&real, 5)?;
+/// assert_eq!(decoys.len(), 5);
+/// for decoy in &decoys {
+///     assert_ne!(decoy, &real.key());
+///     assert_eq!(class_counts(decoy)?, [6, 2, 2]);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn generate_decoys(real: &RandKey, n: usize) -> Result<Vec<String>, GenError> {
+    let mut profile = real.clone();
+    profile.SEED = None;
+    profile.SEEDING = SeedStrategy::Os;
+
+    let key = real.key();
+    if let Ok((l, s, num)) = _CNT(&key) {
+        if !key.is_empty() {
+            profile.ltr_cnt = l;
+            profile.sbl_cnt = s;
+            profile.num_cnt = num;
+        }
+    }
+
+    let mut seen = HashSet::with_capacity(n + 1);
+    seen.insert(key);
+    let mut decoys = Vec::with_capacity(n);
+
+    for _ in 0..n.saturating_mul(_MAX_RETRIES) {
+        if decoys.len() == n {
+            break;
+        }
+        let decoy = profile.generate()?;
+        if seen.insert(decoy.clone()) {
+            decoys.push(decoy);
+        }
+    }
+
+    if decoys.len() == n { Ok(decoys) } else { Err(GenError::AttemptsExhausted) }
+}
@@ -0,0 +1,123 @@
+//! Sub-keys derived by HKDF-SHA256 from a master key, so one strong secret fans out
+//! into per-service credentials which can be derived again from it.
+use {
+    hmac::{Hmac, Mac},
+    sha2::Sha256,
+    rand::{Rng, rngs::StdRng, SeedableRng, seq::SliceRandom},
+    crate::{RandKey, GenError, LabeledKey, Strategy, rules::_PLACE, strategy::_DISTINCT, utils::_COUNTS},
+};
+
+
+
+
+type HmacSha256 = Hmac<Sha256>;
+
+
+/// The salt of the HKDF extraction
+pub(crate) const _DERIVE_SALT: &[u8] = b"rand_key derive_children";
+
+
+/// Return the label of the child at `index`
+#[inline]
+pub(crate) fn _CHILD_LABEL(index: usize) -> String { format!("child-{}", index) }
+
+
+/// Return 32 bytes derived from `master` for `info`, by HKDF-SHA256 of RFC 5869
+#[inline]
+pub(crate) fn _HKDF(master: &[u8], info: &[u8]) -> [u8; 32] {
+    let mut extract = HmacSha256::new_from_slice(_DERIVE_SALT).expect("HMAC takes keys of any length");
+    extract.update(master);
+    let prk = extract.finalize().into_bytes();
+
+    let mut expand = HmacSha256::new_from_slice(&prk).expect("HMAC takes keys of any length");
+    expand.update(info);
+    expand.update(&[1]);
+
+    expand.finalize().into_bytes().into()
+}
+
+
+impl RandKey {
+    /// Return the child at `index` of the key, derived like [`derive_children`](RandKey::derive_children) does
+    ///
+    /// Return `GenError::MissChar` if there is no key to derive from.
+    /// The child has no character twice with `Strategy::Unique` and follows the [rules](RandKey::add_rule),
+    /// like the keys of [`join`](RandKey::join), and meets the same checks of the entropy and the pools.
+    /// Return `GenError::InconsistentField` with `Strategy::Coverage`, which the children don't follow.
+    #[inline]
+    pub fn derive_child(&self, index: usize) -> Result<String, GenError> {
+        let master = self.key.borrow().clone();
+        if master.is_empty() {
+            return Err(GenError::MissChar);
+        }
+
+        self.check_entropy()?;
+        self.check_pools()?;
+
+        let counts = _COUNTS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM)?;
+        let unique = self.STRATEGY == Strategy::Unique;
+        if (unique && self.CHECKSUM.is_some()) || self.STRATEGY == Strategy::Coverage {
+            return Err(GenError::InconsistentField);
+        }
+
+        let mut rng = StdRng::from_seed(_HKDF(master.as_bytes(), _CHILD_LABEL(index).as_bytes()));
+
+        let mut picked: Vec<&str> = Vec::with_capacity(counts.iter().sum());
+        for (count, pool) in counts.iter().zip(self.DATA.iter()) {
+            if unique {
+                picked.extend(_DISTINCT(pool, *count, &mut rng)?.into_iter().map(String::as_str));
+            } else {
+                picked.extend((0..*count).map(|_| pool[rng.gen_range(0, pool.len())].as_str()));
+            }
+        }
+        if self.RULES.is_empty() {
+            picked.shuffle(&mut rng);
+        } else {
+            // Placed by a seed of the child's generator, so the child is derived the same again
+            picked = _PLACE(picked, counts, &self.RULES, self.CHECKSUM.is_some(), Some(rng.gen()))?;
+        }
+
+        let mut child = picked.concat();
+        if let Some(checksum) = self.CHECKSUM {
+            child.push(checksum.digit(&child)?);
+        }
+
+        #[cfg(feature = "audit-log")]
+        crate::audit_log::_RECORD(|| self.config(), &child)?;
+
+        Ok(child)
+    }
+
+    /// Return `n` sub-keys derived from the key, labeled `child-0`, `child-1` and so on.
+    ///
+    /// The key is generated first if there is none. Each child is made like the keys this `RandKey`
+    /// generates, from a generator seeded by the HKDF-SHA256 of the key and the label of the child,
+    /// so the same key and settings always derive the same children, and a child tells nothing of the key.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let master = RandKey::new("20", "4", "8")?;
+    /// let children = master.derive_children(3)?;
+    ///
+    /// assert_eq!(children[1].label, "child-1");
+    /// assert_eq!(children[1].key.len(), 32);
+    /// assert_ne!(children[0].key, children[1].key);
+    ///
+    /// // The same master key derives the same children
+    /// assert_eq!(master.derive_child(1)?, children[1].key);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn derive_children(&self, n: usize) -> Result<Vec<LabeledKey>, GenError> {
+        if self.key.borrow().is_empty() {
+            self.join()?;
+        }
+
+        (0..n).map(|i| Ok(LabeledKey { label: _CHILD_LABEL(i), key: self.derive_child(i)? })).collect()
+    }
+}
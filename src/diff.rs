@@ -0,0 +1,119 @@
+use crate::{RandKey, ASCIIExcludeCtrl::*};
+
+
+
+
+/// How two keys differ, returned by [`RandKey::diff`]
+///
+/// The positions are in [char]s, the ones past the end of the shorter key are all changed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyDiff {
+    /// The positions which have other characters
+    pub changed:         Vec<usize>,
+    /// The positions which have characters of another kind, a letter for a number say
+    pub classes_changed: Vec<usize>,
+    /// How many more letters, symbols and numbers the other key has
+    pub count_delta:     [i64; 3],
+    /// The positions which have other characters, `None` if the keys aren't as long
+    pub hamming:         Option<usize>,
+    /// The fewest insertions, deletions and substitutions of characters from a key to the other
+    pub levenshtein:     usize,
+    /// The length of the longer key
+    pub max_len:         usize,
+}
+
+
+impl KeyDiff {
+    /// Return how alike the keys are, from 0 for nothing in common to 1 for the same keys
+    #[inline]
+    pub fn similarity(&self) -> f64 {
+        if self.max_len == 0 { 1.0 } else { 1.0 - self.levenshtein as f64 / self.max_len as f64 }
+    }
+}
+
+
+/// Return the Levenshtein distance of `a` and `b`, with a single row of the table
+#[inline]
+pub(crate) fn _LEVENSHTEIN(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb { diagonal } else { 1 + diagonal.min(above).min(row[j]) };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+
+impl RandKey {
+    /// Return how `other` differs from the current key, for the audits of rotations and the hints of new passwords
+    ///
+    /// The kinds are the ones of the [`classifier`](RandKey::classifier).
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::ToRandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "Summer2023!".to_randkey()?;
+    ///
+    /// let diff = r_p.diff("Summer2024!");
+    /// assert_eq!(diff.changed, vec![9]);
+    /// assert!(diff.classes_changed.is_empty());
+    /// assert_eq!((diff.hamming, diff.levenshtein), (Some(1), 1));
+    /// assert!(diff.similarity() > 0.9);
+    ///
+    /// let diff = r_p.diff("Summer2023!x");
+    /// assert_eq!(diff.changed, vec![11]);
+    /// assert_eq!(diff.count_delta, [1, 0, 0]);
+    /// assert_eq!((diff.hamming, diff.levenshtein), (None, 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn diff(&self, other: &str) -> KeyDiff {
+        let key: Vec<char> = self.key.borrow().chars().collect();
+        let other: Vec<char> = other.chars().collect();
+        let max_len = key.len().max(other.len());
+
+        let kind = |c: Option<&char>| c.and_then(|c| self.CLASSIFIER.classify(*c));
+
+        let mut diff = KeyDiff { max_len, levenshtein: _LEVENSHTEIN(&key, &other), ..Default::default() };
+
+        for i in 0..max_len {
+            let (a, b) = (key.get(i), other.get(i));
+
+            if a != b {
+                diff.changed.push(i);
+            }
+            if kind(a) != kind(b) {
+                diff.classes_changed.push(i);
+            }
+        }
+
+        for (chars, sign) in [(&key, -1), (&other, 1)] {
+            for c in chars.iter() {
+                match kind(Some(c)) {
+                    Some(Alphabetic) => diff.count_delta[0] += sign,
+                    Some(Punctuation) => diff.count_delta[1] += sign,
+                    Some(Digit) => diff.count_delta[2] += sign,
+                    None => {}
+                }
+            }
+        }
+
+        if key.len() == other.len() {
+            diff.hamming = Some(diff.changed.len());
+        }
+
+        diff
+    }
+}
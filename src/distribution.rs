@@ -0,0 +1,70 @@
+use {
+    rand::{Rng, distributions::Distribution},
+    crate::{RandKey, GenError, SeedStrategy},
+};
+
+
+
+
+/// The keys of a `RandKey` as a distribution of `rand`, sampled with `rng.sample(&dist)`
+/// or iterated with `rng.sample_iter(&dist)`
+///
+/// The characters and their order are picked by generators seeded from the sampling `rng`,
+/// so a seeded `rng` samples the same keys again. The characters are seeded by 256 bits of it,
+/// and only the order by 64, so a key has as much entropy as its profile gives it up to 256 bits.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use {
+///     rand::{Rng, SeedableRng, rngs::StdRng},
+///     rand_key::{RandKey, KeyDistribution},
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dist = KeyDistribution::new(&RandKey::new("10", "2", "4")?)?;
+///
+/// let key: String = rand::thread_rng().sample(&dist);
+/// assert_eq!(key.len(), 16);
+///
+/// let keys: Vec<String> = StdRng::seed_from_u64(7).sample_iter(&dist).take(3).collect();
+/// let again: Vec<String> = StdRng::seed_from_u64(7).sample_iter(&dist).take(3).collect();
+/// assert_eq!(keys, again);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyDistribution {
+    profile: RandKey,
+}
+
+
+impl KeyDistribution {
+    /// Return the distribution of the keys of `r_p`, with its counts, data, strategy and rules
+    ///
+    /// Return the error of generating a key with `r_p` if it can't generate one,
+    /// since sampling can't fail.
+    #[inline]
+    pub fn new(r_p: &RandKey) -> Result<Self, GenError> {
+        let profile = r_p.clone();
+        profile.generate()?;
+
+        Ok(KeyDistribution { profile })
+    }
+
+    /// Return the `RandKey` generating the keys
+    #[inline]
+    pub fn profile(&self) -> &RandKey { &self.profile }
+}
+
+
+impl Distribution<String> for KeyDistribution {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let mut r_p = self.profile.clone();
+        r_p.set_seed(Some(rng.gen()));
+        r_p.set_seed_strategy(SeedStrategy::Derived256(rng.gen()));
+
+        r_p.generate().expect("the profile generated a key in KeyDistribution::new")
+    }
+}
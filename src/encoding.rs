@@ -0,0 +1,233 @@
+//! Text encodings of the keys and the raw bytes.
+use crate::{RandKey, GenError};
+
+
+
+
+/// The alphabet of Crockford's Base32, without `I`, `L`, `O` and `U`
+pub const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+
+/// Encode `bytes` in Crockford's Base32, without padding
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::encoding::to_base32_crockford;
+///
+/// assert_eq!(to_base32_crockford(b"hello"), "D1JPRV3F");
+/// assert_eq!(to_base32_crockford(&[0xff]), "ZW");
+/// ```
+#[inline]
+pub fn to_base32_crockford(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let (mut buffer, mut bits) = (0u16, 0);
+
+    for &b in bytes {
+        buffer = (buffer << 8) | b as u16;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(CROCKFORD_ALPHABET[(buffer >> bits) as usize & 31] as char);
+        }
+    }
+
+    if bits > 0 {
+        encoded.push(CROCKFORD_ALPHABET[(buffer << (5 - bits)) as usize & 31] as char);
+    }
+
+    encoded
+}
+
+
+/// Decode Crockford's Base32, case-insensitively, reading `I` and `L` as `1`, `O` as `0` and skipping `-`
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::encoding::from_base32_crockford;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(from_base32_crockford("d1jp-rv3f")?, b"hello");
+/// assert_eq!(from_base32_crockford("O1")?, from_base32_crockford("0l")?);
+/// assert!(from_base32_crockford("U0").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn from_base32_crockford(s: &str) -> Result<Vec<u8>, GenError> {
+    let mut decoded = Vec::with_capacity(s.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u16, 0);
+
+    for c in s.chars().filter(|c| *c != '-') {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let value = CROCKFORD_ALPHABET.iter().position(|&a| a as char == c).ok_or(GenError::InvalidChar)?;
+
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+        }
+    }
+
+    Ok(decoded)
+}
+
+
+/// The alphabet of the standard Base64 of RFC 4648
+pub const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+
+/// Encode `bytes` in the standard Base64, with padding
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::encoding::to_base64;
+///
+/// assert_eq!(to_base64(b"hello"), "aGVsbG8=");
+/// assert_eq!(to_base64(&[0xff, 0xfe]), "//4=");
+/// ```
+#[inline]
+pub fn to_base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(buffer >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+
+/// Decode the standard Base64, skipping whitespace and ignoring the padding
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::encoding::from_base64;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(from_base64("aGVs\nbG8=")?, b"hello");
+/// assert!(from_base64("aGV-").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn from_base64(s: &str) -> Result<Vec<u8>, GenError> {
+    let mut decoded = Vec::with_capacity(s.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0);
+
+    for c in s.chars().filter(|c| !c.is_whitespace()).take_while(|c| *c != '=') {
+        let value = BASE64_ALPHABET.iter().position(|&a| a as char == c).ok_or(GenError::InvalidChar)?;
+
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+        }
+    }
+
+    Ok(decoded)
+}
+
+
+impl RandKey {
+    /// Return the key like `hexdump -C`, the offset, 16 bytes in hex and in ASCII on each line
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::ToRandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "abcdefgh12345678$%".to_randkey()?;
+    /// assert_eq!(
+    ///     r_p.hexdump(),
+    ///     "00000000  61 62 63 64 65 66 67 68  31 32 33 34 35 36 37 38  |abcdefgh12345678|\n\
+    ///      00000010  24 25                                             |$%|\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn hexdump(&self) -> String {
+        let key = self.key.borrow();
+        let mut dump = String::new();
+
+        for (i, line) in key.as_bytes().chunks(16).enumerate() {
+            let mut hex = String::new();
+            for (j, b) in line.iter().enumerate() {
+                hex.push_str(&format!("{}{:02x} ", if j == 8 { " " } else { "" }, b));
+            }
+            let ascii: String = line.iter().map(|&b| if (b' '..=b'~').contains(&b) { b as char } else { '.' }).collect();
+
+            dump.push_str(&format!("{:08x}  {:<49} |{}|\n", i * 16, hex, ascii));
+        }
+
+        dump
+    }
+}
+
+
+impl RandKey {
+    /// Return `len` random bytes in Crockford's Base32, for tokens people have to type like invite codes
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, encoding::from_base32_crockford};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = RandKey::default().to_base32_crockford(10);
+    /// assert_eq!(token.len(), 16);
+    /// assert_eq!(from_base32_crockford(&token)?.len(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn to_base32_crockford(&self, len: usize) -> String { to_base32_crockford(&self.raw_bytes(len)) }
+
+    /// Return the key URL-encoded per RFC 3986, every character but the unreserved ones is percent-encoded
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::ToRandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "a/b@c:d%e~f".to_randkey()?;
+    /// assert_eq!(r_p.percent_encoded(), "a%2Fb%40c%3Ad%25e~f");
+    /// let uri = format!("postgres://app:{}@db/prod", r_p.percent_encoded());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn percent_encoded(&self) -> String {
+        self.key
+            .borrow()
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
+    }
+}
@@ -22,5 +22,74 @@ pub enum GenError {
 
     #[error("Require consistent field")]
     InconsistentField,
+
+    #[error("No key passed the filter within the attempts")]
+    AttemptsExhausted,
+
+    #[error("Require at least one and at most all shares, with distinct non-zero indexes")]
+    InvalidShares,
+
+    #[error("Require at least one digit to be the check digit")]
+    NoCheckDigit,
+
+    #[error("Invalid profile: {0}")]
+    InvalidProfile(String),
+
+    #[error("Too many characters requested, try again later")]
+    RateLimited,
+
+    #[error("The worker is unavailable: {0}")]
+    WorkerUnavailable(String),
+
+    #[error("Unknown preset `{0}`")]
+    UnknownPreset(String),
+
+    #[error("Require at most {0} keys per request")]
+    BatchTooLarge(usize),
+
+    #[error("Require every character to be in the data")]
+    OutOfData,
+
+    #[error("Unknown strength `{0}`")]
+    UnknownStrength(String),
+
+    #[error("Require 8 to 63 printable ASCII characters")]
+    InvalidPassphrase,
+
+    #[error("GnuPG failed: {0}")]
+    Gpg(String),
+
+    #[error("Invalid charsets: {0}")]
+    InvalidCharsets(String),
+
+    #[error("Not enough memory for the key")]
+    AllocationFailed,
+
+    #[error("The key is weak, it has a {0}")]
+    WeakKey(String),
+
+    #[error("Invalid armor: {0}")]
+    InvalidArmor(String),
+
+    #[error("The pool is too small: {0}")]
+    PoolTooSmall(String),
+
+    #[error("The rules can't be followed: {0}")]
+    UnsatisfiableRules(String),
+
+    #[error("The keys have {0:.0} bits of entropy, below the floor of {1:.0}")]
+    BelowEntropyFloor(f64, f64),
+
+    #[error("Invalid header: {0}")]
+    InvalidHeader(String),
+
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+
+    #[error("Invalid spelling alphabet: {0}")]
+    InvalidAlphabet(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
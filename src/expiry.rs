@@ -0,0 +1,44 @@
+use {
+    crate::RandKey,
+    std::time::{Duration, SystemTime},
+};
+
+
+
+
+impl RandKey {
+    /// Let the key expire after `duration` from now
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// assert!(!r_p.is_expired());
+    ///
+    /// r_p.valid_for(Duration::from_secs(90 * 24 * 3600));
+    /// assert!(!r_p.is_expired());
+    ///
+    /// r_p.valid_for(Duration::from_secs(0));
+    /// assert!(r_p.is_expired());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn valid_for(&mut self, duration: Duration) { self.EXPIRY = Some(SystemTime::now() + duration); }
+
+    /// Let the key expire at `time`, or never if it's `None`
+    #[inline]
+    pub fn expires_at(&mut self, time: Option<SystemTime>) { self.EXPIRY = time; }
+
+    /// Return when the key expires, `None` means never
+    #[inline]
+    pub fn expiry(&self) -> Option<SystemTime> { self.EXPIRY }
+
+    /// Returns true if the key has expired, and false otherwise.
+    #[inline]
+    pub fn is_expired(&self) -> bool { self.EXPIRY.is_some_and(|t| SystemTime::now() >= t) }
+}
@@ -0,0 +1,566 @@
+//! Write generated batches in the formats other tools import.
+use {
+    std::io::Write,
+    crate::{
+        GenError,
+        batch::LabeledKey,
+        encoding::{to_base64, from_base64},
+    },
+};
+
+#[cfg(feature = "gpg")]
+use std::{
+    thread,
+    process::{Command, Stdio},
+};
+
+#[cfg(feature = "pass")]
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+#[cfg(feature = "signing")]
+use ed25519_dalek::{Signature, Signer};
+
+#[cfg(feature = "signing")]
+pub use ed25519_dalek::{SigningKey, VerifyingKey};
+
+
+
+
+/// Quote a CSV field if it has to be
+#[inline]
+pub(crate) fn _CSV_FIELD(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.into()
+    }
+}
+
+
+/// The CSV schemas [`to_csv_as`] writes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsvFormat {
+    /// A `label,key` header
+    Plain,
+    /// The individual vault import of Bitwarden, the labels are the names of the logins
+    Bitwarden,
+    /// The CSV import of 1Password, the labels are the titles of the logins
+    OnePassword,
+}
+
+
+/// What a CSV column holds
+#[derive(Clone, Copy, Debug)]
+enum Column {
+    Label,
+    Key,
+    Empty,
+    Literal(&'static str),
+}
+
+
+impl CsvFormat {
+    /// Return the header and the columns of a row
+    #[inline]
+    #[rustfmt::skip]
+    fn columns(self) -> (&'static str, &'static [Column]) {
+        use self::Column::*;
+
+        match self {
+            CsvFormat::Plain       => ("label,key", &[Label, Key]),
+            CsvFormat::Bitwarden   => ("folder,favorite,type,name,notes,fields,reprompt,login_uri,login_username,login_password,login_totp",
+                                       &[Empty, Empty, Literal("login"), Label, Empty, Empty, Literal("0"), Empty, Empty, Key, Empty]),
+            CsvFormat::OnePassword => ("Title,Website,Username,Password,Notes", &[Label, Empty, Empty, Key, Empty]),
+        }
+    }
+}
+
+
+/// Write the keys as CSV with a `label,key` header
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{LabeledKey, export};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let keys = vec![LabeledKey { label: "alice".into(), key: "a,b\"c".into() }];
+/// let mut out = Vec::new();
+/// export::to_csv(&keys, &mut out)?;
+/// assert_eq!(String::from_utf8(out)?, "label,key\nalice,\"a,b\"\"c\"\n");
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn to_csv(keys: &[LabeledKey], writer: impl Write) -> Result<(), GenError> { to_csv_as(keys, CsvFormat::Plain, writer) }
+
+
+/// Write the keys as CSV in the schema of `format`
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{LabeledKey, export::{self, CsvFormat}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let keys = vec![LabeledKey { label: "alice".into(), key: "s3cr3t!".into() }];
+///
+/// let mut out = Vec::new();
+/// export::to_csv_as(&keys, CsvFormat::Bitwarden, &mut out)?;
+/// assert!(String::from_utf8(out)?.ends_with("\n,,login,alice,,,0,,,s3cr3t!,\n"));
+///
+/// let mut out = Vec::new();
+/// export::to_csv_as(&keys, CsvFormat::OnePassword, &mut out)?;
+/// assert_eq!(String::from_utf8(out)?, "Title,Website,Username,Password,Notes\nalice,,,s3cr3t!,\n");
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn to_csv_as(keys: &[LabeledKey], format: CsvFormat, mut writer: impl Write) -> Result<(), GenError> {
+    let (header, columns) = format.columns();
+    writeln!(writer, "{}", header)?;
+
+    for k in keys {
+        let row: Vec<String> = columns.iter()
+                                     .map(|column| match column {
+                                         Column::Label => _CSV_FIELD(&k.label),
+                                         Column::Key => _CSV_FIELD(&k.key),
+                                         Column::Empty => String::new(),
+                                         Column::Literal(s) => (*s).into(),
+                                     })
+                                     .collect();
+        writeln!(writer, "{}", row.join(","))?;
+    }
+
+    Ok(())
+}
+
+
+/// Escape the characters XML reserves
+#[cfg(feature = "keepass")]
+#[inline]
+pub(crate) fn _XML_ESCAPE(s: &str) -> String {
+    s.chars().fold(String::with_capacity(s.len()), |mut escaped, c| {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+        escaped
+    })
+}
+
+
+/// Write the keys as a KeePass 2 XML file, in a group named `group`, titled by their labels.
+///
+/// Import it in KeePass with *File > Import > KeePass XML (2.x)*.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{LabeledKey, export};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let keys = vec![LabeledKey { label: "db & cache".into(), key: "a<b".into() }];
+/// let mut out = Vec::new();
+/// export::to_keepass_xml(&keys, "Handover", &mut out)?;
+///
+/// let xml = String::from_utf8(out)?;
+/// assert!(xml.contains("<Name>Handover</Name>"));
+/// assert!(xml.contains("<Value>db &amp; cache</Value>"));
+/// assert!(xml.contains("<Value ProtectInMemory=\"True\">a&lt;b</Value>"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "keepass")]
+#[inline]
+pub fn to_keepass_xml(keys: &[LabeledKey], group: &str, mut writer: impl Write) -> Result<(), GenError> {
+    writeln!(writer, "<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>")?;
+    writeln!(writer, "<KeePassFile>\n\t<Root>\n\t\t<Group>\n\t\t\t<Name>{}</Name>", _XML_ESCAPE(group))?;
+
+    for k in keys {
+        writeln!(writer, "\t\t\t<Entry>")?;
+        writeln!(writer, "\t\t\t\t<String><Key>Title</Key><Value>{}</Value></String>", _XML_ESCAPE(&k.label))?;
+        writeln!(writer,
+                 "\t\t\t\t<String><Key>Password</Key><Value ProtectInMemory=\"True\">{}</Value></String>",
+                 _XML_ESCAPE(&k.key))?;
+        writeln!(writer, "\t\t\t</Entry>")?;
+    }
+
+    writeln!(writer, "\t\t</Group>\n\t</Root>\n</KeePassFile>")?;
+
+    Ok(())
+}
+
+
+/// Return the GPG ids of the `.gpg-id` file nearest to `dir`, up to the root of the store
+#[cfg(feature = "pass")]
+#[inline]
+pub(crate) fn _GPG_IDS(store: &Path, dir: &Path) -> Result<Vec<String>, GenError> {
+    for dir in dir.ancestors().take_while(|d| d.starts_with(store)) {
+        if let Ok(ids) = fs::read_to_string(dir.join(".gpg-id")) {
+            return Ok(ids.lines().map(str::trim).filter(|id| !id.is_empty()).map(String::from).collect());
+        }
+    }
+
+    Err(GenError::Gpg(format!("No .gpg-id in {}", store.display())))
+}
+
+
+/// Encrypt `plaintext` to `recipients` by running `gpg`, in ASCII armor if `armor`
+#[cfg(feature = "gpg")]
+#[inline]
+pub(crate) fn _GPG_ENCRYPT(recipients: &[impl AsRef<str>], plaintext: Vec<u8>, armor: bool) -> Result<Vec<u8>, GenError> {
+    if recipients.is_empty() {
+        return Err(GenError::Gpg("No recipient".into()));
+    }
+
+    let mut gpg = Command::new("gpg");
+    gpg.args(["--batch", "--yes", "--quiet", "--encrypt", "--output", "-"]);
+    if armor {
+        gpg.arg("--armor");
+    }
+    for r in recipients {
+        gpg.arg("--recipient").arg(r.as_ref());
+    }
+
+    let mut child = gpg.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+
+    // Feed gpg while it writes, the pipes would fill up otherwise
+    let mut stdin = child.stdin.take().unwrap();
+    let feeder = thread::spawn(move || stdin.write_all(&plaintext));
+
+    let out = child.wait_with_output()?;
+    feeder.join().unwrap()?;
+
+    if out.status.success() {
+        Ok(out.stdout)
+    } else {
+        Err(GenError::Gpg(String::from_utf8_lossy(&out.stderr).trim().into()))
+    }
+}
+
+
+/// Write the keys as CSV in the schema of `format`, encrypted by `gpg` to every one of `recipients`
+///
+/// The recipients are anything `gpg --recipient` takes, like emails or fingerprints,
+/// their public keys have to be in the keyring. The output is in ASCII armor if `armor`.
+/// # Example
+///
+/// Basic usage:
+/// ```no_run
+/// use rand_key::{RandKey, export::{self, CsvFormat}};
+/// use std::fs::File;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let keys = RandKey::default().generate_labeled(&["alice", "bob"])?;
+/// let file = File::create("handover.csv.gpg")?;
+/// export::to_gpg(&keys, CsvFormat::Plain, &["it@example.com", "alice@example.com"], false, file)?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "gpg")]
+#[inline]
+pub fn to_gpg(keys:       &[LabeledKey],
+              format:     CsvFormat,
+              recipients: &[impl AsRef<str>],
+              armor:      bool,
+              mut writer: impl Write) -> Result<(), GenError> {
+    let mut csv = Vec::new();
+    to_csv_as(keys, format, &mut csv)?;
+
+    writer.write_all(&_GPG_ENCRYPT(recipients, csv, armor)?)?;
+
+    Ok(())
+}
+
+
+/// Insert `key` into the password-store at `store_path` as `entry_name`, like `pass insert` does.
+///
+/// The entry is encrypted by `gpg` to the ids of the nearest `.gpg-id`,
+/// and the directories of `entry_name` like `email/` in `email/work` are created.
+/// Return the path of the `.gpg` file.
+/// # Example
+///
+/// Basic usage:
+/// ```no_run
+/// use rand_key::{RandKey, export};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let r_p = RandKey::default();
+/// let file = export::to_pass("/home/alice/.password-store", "email/work", &r_p.generate()?)?;
+/// assert!(file.ends_with("email/work.gpg"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "pass")]
+#[inline]
+pub fn to_pass(store_path: impl AsRef<Path>, entry_name: &str, key: &str) -> Result<PathBuf, GenError> {
+    let store = store_path.as_ref();
+    let entry = Path::new(entry_name);
+
+    if entry_name.is_empty() || !entry.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(GenError::Gpg(format!("Invalid entry `{}`", entry_name)));
+    }
+
+    let file = store.join(format!("{}.gpg", entry_name));
+    let dir = file.parent().unwrap();
+    let recipients = _GPG_IDS(store, dir)?;
+
+    fs::create_dir_all(dir)?;
+    fs::write(&file, _GPG_ENCRYPT(&recipients, format!("{}\n", key).into_bytes(), false)?)?;
+
+    Ok(file)
+}
+
+
+/// The first line of an armored key
+pub const ARMOR_BEGIN: &str = "-----BEGIN RANDKEY-----";
+
+/// The last line of an armored key
+pub const ARMOR_END: &str = "-----END RANDKEY-----";
+
+/// Base64 characters on each line of an armored key
+pub(crate) const _ARMOR_WIDTH: usize = 64;
+
+
+/// Return the CRC-24 of OpenPGP of `bytes`
+#[inline]
+pub(crate) fn _CRC24(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0xb704ce, |mut crc, &b| {
+        crc ^= (b as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x1000000 != 0 {
+                crc ^= 0x1864cfb;
+            }
+        }
+        crc
+    }) & 0xffffff
+}
+
+
+/// Return `key` in ASCII armor, so it survives being pasted through email and ticketing systems.
+///
+/// Like the armor of OpenPGP, the key is in Base64 lines of 64 characters between
+/// [`ARMOR_BEGIN`] and [`ARMOR_END`], after a `Label` header, and followed by its CRC-24.
+/// The header is left out if `label` is empty.
+///
+/// Return `GenError::InvalidArmor` if `label` has a line break.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::export;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let armored = export::armored("hello", "db")?;
+/// assert_eq!(armored, "-----BEGIN RANDKEY-----\nLabel: db\n\naGVsbG8=\n=R/WK\n-----END RANDKEY-----\n");
+/// assert!(export::armored("hello", "d\nb").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn armored(key: &str, label: &str) -> Result<String, GenError> {
+    if label.contains(&['\n', '\r'][..]) {
+        return Err(GenError::InvalidArmor("Line break in the label".into()));
+    }
+
+    let mut armor = format!("{}\n", ARMOR_BEGIN);
+    if !label.is_empty() {
+        armor.push_str(&format!("Label: {}\n\n", label));
+    }
+
+    let body = to_base64(key.as_bytes());
+    for line in body.as_bytes().chunks(_ARMOR_WIDTH) {
+        armor.push_str(std::str::from_utf8(line).unwrap());
+        armor.push('\n');
+    }
+
+    let crc = _CRC24(key.as_bytes());
+    armor.push_str(&format!("={}\n{}\n", to_base64(&crc.to_be_bytes()[1..]), ARMOR_END));
+
+    Ok(armor)
+}
+
+
+/// Parse the first key in ASCII armor in `text`, the reverse of [`armored`]
+///
+/// The text around the armor, the indentation and the unknown headers are ignored,
+/// the label is empty if there's no `Label` header.
+///
+/// Return `GenError::InvalidArmor` if the armor is broken or the CRC doesn't match.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, export};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = RandKey::new("200", "50", "50")?.generate()?;
+/// let email = format!("Hi,\r\n\r\nhere it is:\r\n{}\r\nBye", export::armored(&key, "db")?.replace('\n', "\r\n  "));
+///
+/// let parsed = export::parse_armored(&email)?;
+/// assert_eq!(parsed.label, "db");
+/// assert_eq!(parsed.key, key);
+///
+/// let tampered = export::armored("hello", "")?.replace("aGVsbG8=", "aGVsbH8=");
+/// assert!(export::parse_armored(&tampered).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn parse_armored(text: &str) -> Result<LabeledKey, GenError> {
+    let invalid = |reason: &str| GenError::InvalidArmor(reason.into());
+
+    let mut lines = text.lines().map(str::trim).skip_while(|l| *l != ARMOR_BEGIN);
+    lines.next().ok_or_else(|| invalid("No BEGIN line"))?;
+
+    let (mut label, mut body, mut crc) = (String::new(), String::new(), None);
+
+    for line in lines.by_ref() {
+        match line.split_once(": ") {
+            Some(("Label", value)) => label = value.into(),
+            Some(_) => {}
+            None if line.is_empty() => {}
+            None => match line.strip_prefix('=') {
+                Some(checksum) => {
+                    crc = Some(checksum);
+                    break;
+                }
+                None if line == ARMOR_END => return Err(invalid("No CRC line")),
+                None => body.push_str(line),
+            },
+        }
+    }
+
+    let crc = crc.ok_or_else(|| invalid("No CRC line"))?;
+    if lines.next() != Some(ARMOR_END) {
+        return Err(invalid("No END line after the CRC"));
+    }
+
+    let bytes = from_base64(&body).map_err(|_| invalid("Invalid Base64"))?;
+    let expected = match from_base64(crc) {
+        Ok(c) if c.len() == 3 => u32::from_be_bytes([0, c[0], c[1], c[2]]),
+        _ => return Err(invalid("Invalid CRC line")),
+    };
+
+    if _CRC24(&bytes) != expected {
+        return Err(invalid("CRC mismatch"));
+    }
+
+    let key = String::from_utf8(bytes).map_err(|_| invalid("The key isn't UTF-8"))?;
+
+    Ok(LabeledKey { label, key })
+}
+
+
+/// The first line of the signature of a signed export
+#[cfg(feature = "signing")]
+pub const SIGNATURE_BEGIN: &str = "-----BEGIN RANDKEY SIGNATURE-----";
+
+/// The last line of the signature of a signed export
+#[cfg(feature = "signing")]
+pub const SIGNATURE_END: &str = "-----END RANDKEY SIGNATURE-----";
+
+
+/// Return `export` followed by its Ed25519 signature by `key`, for the recipients to check it with [`verify_export`]
+///
+/// The signature is in Base64 lines between [`SIGNATURE_BEGIN`] and [`SIGNATURE_END`], after the export,
+/// so that the file still opens in the tools the export is for once the block is cut off.
+/// Sign any of the exports, like a KeePass XML file, all but the encrypted ones which GnuPG signs itself.
+#[cfg(feature = "signing")]
+#[inline]
+pub fn sign_export(export: &[u8], key: &SigningKey) -> Vec<u8> {
+    let mut signed = export.to_vec();
+    if !signed.is_empty() && !signed.ends_with(b"\n") {
+        signed.push(b'\n');
+    }
+
+    let signature = to_base64(&key.sign(&signed).to_bytes());
+
+    signed.extend_from_slice(SIGNATURE_BEGIN.as_bytes());
+    signed.push(b'\n');
+    for line in signature.as_bytes().chunks(_ARMOR_WIDTH) {
+        signed.extend_from_slice(line);
+        signed.push(b'\n');
+    }
+    signed.extend_from_slice(SIGNATURE_END.as_bytes());
+    signed.push(b'\n');
+
+    signed
+}
+
+
+/// Write the keys as CSV in the schema of `format`, signed by `key` like [`sign_export`] does
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{LabeledKey, export::{self, CsvFormat, SigningKey}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = SigningKey::from_bytes(&[7; 32]);
+/// let keys = vec![LabeledKey { label: "alice".into(), key: "s3cr3t!".into() }];
+///
+/// let mut out = Vec::new();
+/// export::to_csv_signed(&keys, CsvFormat::Plain, &key, &mut out)?;
+/// assert_eq!(export::verify_export(&out, &key.verifying_key())?, b"label,key\nalice,s3cr3t!\n");
+///
+/// let tampered = String::from_utf8(out)?.replace("s3cr3t!", "s3cr3t?");
+/// assert!(export::verify_export(tampered.as_bytes(), &key.verifying_key()).is_err());
+///
+/// let other = SigningKey::from_bytes(&[8; 32]);
+/// let mut out = Vec::new();
+/// export::to_csv_signed(&keys, CsvFormat::Plain, &other, &mut out)?;
+/// assert!(export::verify_export(&out, &key.verifying_key()).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "signing")]
+#[inline]
+pub fn to_csv_signed(keys: &[LabeledKey], format: CsvFormat, key: &SigningKey, mut writer: impl Write) -> Result<(), GenError> {
+    let mut csv = Vec::new();
+    to_csv_as(keys, format, &mut csv)?;
+
+    writer.write_all(&sign_export(&csv, key))?;
+
+    Ok(())
+}
+
+
+/// Check that a signed export was signed by `key` and wasn't changed since, return the export without the signature
+///
+/// Return `GenError::InvalidSignature` if there's no signature at the end of `signed`, it's broken, or it doesn't match.
+#[cfg(feature = "signing")]
+#[inline]
+pub fn verify_export<'a>(signed: &'a [u8], key: &VerifyingKey) -> Result<&'a [u8], GenError> {
+    let invalid = |reason: &str| GenError::InvalidSignature(reason.into());
+
+    let begin = format!("{}\n", SIGNATURE_BEGIN);
+    let start = signed.windows(begin.len())
+                      .rposition(|w| w == begin.as_bytes())
+                      .filter(|&i| i == 0 || signed[i - 1] == b'\n')
+                      .ok_or_else(|| invalid("No BEGIN line"))?;
+
+    let block = std::str::from_utf8(&signed[start + begin.len()..]).map_err(|_| invalid("The signature isn't UTF-8"))?;
+    let mut lines = block.lines();
+    let body: String = lines.by_ref().take_while(|l| *l != SIGNATURE_END).collect();
+    if !block.lines().any(|l| l == SIGNATURE_END) || lines.any(|l| !l.trim().is_empty()) {
+        return Err(invalid("No END line at the end"));
+    }
+
+    let bytes = from_base64(&body).map_err(|_| invalid("Invalid Base64"))?;
+    let signature = Signature::from_slice(&bytes).map_err(|_| invalid("Not an Ed25519 signature"))?;
+
+    let export = &signed[..start];
+    key.verify_strict(export, &signature).map_err(|_| invalid("The signature doesn't match"))?;
+
+    Ok(export)
+}
@@ -0,0 +1,66 @@
+//! Entry points for fuzzers, decoding arbitrary bytes into sequences of `RandKey` calls.
+//!
+//! The commands are separated by `0xff`, the first byte of each one picks the call and the rest is its argument.
+//! None of them may panic, whatever the bytes are.
+use crate::{RandKey, ToRandKey, SetRandKeyOp::*};
+
+
+
+
+#[inline]
+fn _COMMANDS(data: &[u8]) -> impl Iterator<Item = (u8, String)> + '_ {
+    data.split(|b| *b == 0xff)
+        .filter_map(|cmd| cmd.split_first())
+        .map(|(op, arg)| (*op, String::from_utf8_lossy(arg).into_owned()))
+}
+
+
+/// Run `set_key` with `Update`, `Check`, `StrictCheck` or `Append`, or `to_randkey`, on the arguments, and `join` after each call
+/// ```
+/// rand_key::fuzz::fuzz_set_key(b"\x00abc123\xff\x01\xf0\x9f\xa6\x80\xff\x02$\xff\x03$\xff\x04x\x00y");
+/// ```
+#[inline]
+pub fn fuzz_set_key(data: &[u8]) {
+    let mut r_p = RandKey::new("10", "2", "3").unwrap();
+
+    for (op, arg) in _COMMANDS(data) {
+        let ok = match op % 5 {
+            0 => r_p.set_key(&arg, Update).is_ok(),
+            1 => r_p.set_key(&arg, Check).is_ok(),
+            2 => r_p.set_key(&arg, StrictCheck).is_ok(),
+            3 => r_p.set_key_owned(arg.clone(), Append).is_ok(),
+            _ => match arg.to_randkey() {
+                Ok(new) => {
+                    r_p = new;
+                    true
+                }
+                Err(_) => false,
+            },
+        };
+
+        if ok {
+            let _ = r_p.join();
+        }
+    }
+}
+
+
+/// Run `replace_data`, `add_item`, `del_item` or `join`, the arguments are split into items by `0x00`
+/// ```
+/// rand_key::fuzz::fuzz_replace_data(b"\x00a\x001\x00-\xff\x01ab\x00\xff\x02a\xff\x03");
+/// ```
+#[inline]
+pub fn fuzz_replace_data(data: &[u8]) {
+    let mut r_p = RandKey::new("10", "2", "3").unwrap();
+
+    for (op, arg) in _COMMANDS(data) {
+        let items: Vec<&str> = arg.split('\0').collect();
+
+        let _ = match op % 4 {
+            0 => r_p.replace_data(&items),
+            1 => r_p.add_item(&items),
+            2 => r_p.del_item(&items),
+            _ => r_p.join(),
+        };
+    }
+}
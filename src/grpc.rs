@@ -0,0 +1,87 @@
+//! The `KeyGenerator` gRPC service of `proto/randkey.proto`, backed by a [`KeyService`].
+use {
+    std::{net::SocketAddr, sync::Arc},
+    tonic::{Request, Response, Status, transport::Server},
+    crate::{KeyService, GenError, service::BatchRequest},
+};
+
+
+/// The messages and service traits generated from `proto/randkey.proto`
+pub mod pb {
+    tonic::include_proto!("randkey.v1");
+}
+
+
+use pb::key_generator_server::{KeyGenerator, KeyGeneratorServer};
+
+
+
+
+/// Serve the gRPC requests with a [`KeyService`], each client IP address is a tenant
+#[derive(Clone, Debug)]
+pub struct GrpcService {
+    service: Arc<KeyService>,
+}
+
+
+impl GrpcService {
+    #[inline]
+    pub fn new(service: KeyService) -> Self { GrpcService { service: Arc::new(service) } }
+
+    /// Return the tonic service to add to a router
+    #[inline]
+    pub fn into_server(self) -> KeyGeneratorServer<Self> { KeyGeneratorServer::new(self) }
+}
+
+
+/// The empty fields of protobuf are the `None` ones
+/// ```
+/// use rand_key::{grpc::pb::KeyRequest, service::BatchRequest};
+///
+/// let req = BatchRequest::from(KeyRequest { preset: "pin".into(), batch: 2, ..Default::default() });
+/// assert_eq!(req, BatchRequest { preset: Some("pin".into()), batch: Some(2), ..Default::default() });
+/// ```
+impl From<pb::KeyRequest> for BatchRequest {
+    #[inline]
+    fn from(req: pb::KeyRequest) -> Self {
+        let some = |s: String| if s.is_empty() { None } else { Some(s) };
+
+        BatchRequest {
+            preset:  some(req.preset),
+            ltr_cnt: some(req.ltr_cnt),
+            sbl_cnt: some(req.sbl_cnt),
+            num_cnt: some(req.num_cnt),
+            batch:   if req.batch == 0 { None } else { Some(req.batch as usize) },
+            labels:  if req.labels.is_empty() { None } else { Some(req.labels) },
+        }
+    }
+}
+
+
+#[tonic::async_trait]
+impl KeyGenerator for GrpcService {
+    async fn generate(&self, request: Request<pb::KeyRequest>) -> Result<Response<pb::KeyResponse>, Status> {
+        let tenant = request.remote_addr().map(|a| a.ip().to_string()).unwrap_or_default();
+        let req = BatchRequest::from(request.into_inner());
+        let service = self.service.clone();
+
+        let keys = tokio::task::spawn_blocking(move || service.generate_batch(&tenant, &req))
+                       .await
+                       .map_err(|e| Status::internal(e.to_string()))?
+                       .map_err(|e| match e {
+                           GenError::RateLimited | GenError::AllocationFailed => Status::resource_exhausted(e.to_string()),
+                           _ => Status::invalid_argument(e.to_string()),
+                       })?;
+
+        Ok(Response::new(pb::KeyResponse {
+            keys: keys.into_iter().map(|k| pb::LabeledKey { label: k.label, key: k.key }).collect(),
+        }))
+    }
+}
+
+
+/// Serve the gRPC requests on `addr` until the server fails
+#[inline]
+pub async fn serve(addr: SocketAddr, service: KeyService) -> Result<(), tonic::transport::Error> {
+    Server::builder().add_service(GrpcService::new(service).into_server()).serve(addr).await
+}
@@ -0,0 +1,123 @@
+//! A machine-readable header around the tokens, so the services receiving them can tell
+//! which profile made a token and reject a mistyped one before looking it up.
+//!
+//! A token with a header is `rk1.<profile>.<key>.<check>`, where `1` is the version of the header
+//! and `<check>` the CRC-24 of everything before it, in 6 lowercase hex digits.
+use crate::{RandKey, GenError, export::_CRC24};
+
+
+
+
+/// The version of the header [`attach_header`] writes
+pub const HEADER_VERSION: u32 = 1;
+
+
+/// What the header of a token says
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    /// The version of the header
+    pub version: u32,
+    /// Which profile made the token
+    pub profile: String,
+    /// The token without its header
+    pub key:     String,
+}
+
+
+/// Return the check of the header of `s`
+#[inline]
+pub(crate) fn _CHECK(s: &str) -> String { format!("{:06x}", _CRC24(s.as_bytes())) }
+
+
+/// Return `key` with a header naming `profile`
+///
+/// Return `GenError::InvalidHeader` if `profile` is empty or not made of ASCII letters, digits, `-` and `_`.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::header::attach_header;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(attach_header("s3cr.t", "invite")?, "rk1.invite.s3cr.t.fc59d6");
+/// assert!(attach_header("s3cr.t", "in.vite").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn attach_header(key: &str, profile: &str) -> Result<String, GenError> {
+    if profile.is_empty() || !profile.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(GenError::InvalidHeader(format!("Invalid profile `{}`", profile)));
+    }
+
+    let token = format!("rk{}.{}.{}", HEADER_VERSION, profile, key);
+    let check = _CHECK(&token);
+
+    Ok(format!("{}.{}", token, check))
+}
+
+
+/// Parse the header of a token, the reverse of [`attach_header`]
+///
+/// Return `GenError::InvalidHeader` if there is no header, its version is unknown or the check doesn't match.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::header::parse_header;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let header = parse_header("rk1.invite.s3cr.t.fc59d6")?;
+/// assert_eq!(header.version, 1);
+/// assert_eq!(header.profile, "invite");
+/// assert_eq!(header.key, "s3cr.t");
+///
+/// // A mistyped token
+/// assert!(parse_header("rk1.invite.s3cr.f.fc59d6").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn parse_header(token: &str) -> Result<Header, GenError> {
+    let invalid = |reason: &str| GenError::InvalidHeader(reason.into());
+
+    let (body, check) = token.rsplit_once('.').ok_or_else(|| invalid("No check"))?;
+    let mut parts = body.splitn(3, '.');
+    let (version, profile, key) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(version), Some(profile), Some(key)) => (version, profile, key),
+        _ => return Err(invalid("No header")),
+    };
+
+    let version: u32 = version.strip_prefix("rk").and_then(|v| v.parse().ok()).ok_or_else(|| invalid("No version"))?;
+    if version != HEADER_VERSION {
+        return Err(GenError::InvalidHeader(format!("Unknown version {}", version)));
+    }
+    if _CHECK(body) != check {
+        return Err(invalid("Check mismatch"));
+    }
+
+    Ok(Header { version, profile: profile.into(), key: key.into() })
+}
+
+
+impl RandKey {
+    /// Generate a key like [`generate`](RandKey::generate), with a header naming `profile`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, header::parse_header};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("16", "0", "4")?;
+    /// let token = r_p.generate_with_header("api")?;
+    ///
+    /// let header = parse_header(&token)?;
+    /// assert_eq!(header.profile, "api");
+    /// assert_eq!(header.key, r_p.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_with_header(&self, profile: &str) -> Result<String, GenError> { attach_header(&self.generate()?, profile) }
+}
@@ -0,0 +1,153 @@
+use {
+    std::convert::TryFrom,
+    crate::{RandKey, GenError, Classifier, DefaultClassifier, ASCIIExcludeCtrl::{self, *}, utils::{BigUint, ToPrimitive, _CHAR_FROM_STR}},
+};
+
+
+
+
+/// The counts and the characters of the keys, without a key nor the settings of the generation,
+/// for the services checking passwords against a spec
+///
+/// It's `Copy`: the characters of each kind are a set of ASCII in a bitmask,
+/// so the spec of a `RandKey` counts them in the kinds its [`Classifier`] put them in.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, KeySpec};
+/// use std::convert::TryFrom;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let spec = KeySpec::new(4, 1, 2);
+/// assert!(spec.matches("ab-Cd12"));
+/// assert!(!spec.matches("ab-Cd123"));
+///
+/// // Without look-alikes
+/// let spec = spec.without("lI1O0")?;
+/// assert!(!spec.matches("ab-Cd01"));
+///
+/// let mut r_p = RandKey::new("10", "2", "3")?;
+/// r_p.del_item(&["0", "1"])?;
+/// let spec = KeySpec::try_from(&r_p)?;
+/// assert!(spec.matches(&r_p.generate()?));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeySpec {
+    counts:  [usize; 3],
+    /// The charsets of the letters, the symbols and the numbers
+    classes: [u128; 3],
+}
+
+
+/// Return the bit of `c` in a charset, `None` if it's not printable ASCII
+#[inline]
+fn _BIT(c: char) -> Option<u128> { if c.is_ascii_graphic() { Some(1 << c as u32) } else { None } }
+
+
+/// Return the charset of `chars`, `None` if one of them isn't printable ASCII
+#[inline]
+fn _CHARSET(chars: impl IntoIterator<Item = char>) -> Option<u128> { chars.into_iter().try_fold(0, |set, c| _BIT(c).map(|bit| set | bit)) }
+
+
+/// Return the charsets of the kinds of `chars` classified like ASCII, `None` if one of them isn't printable ASCII
+#[inline]
+fn _ASCII_CLASSES(chars: impl IntoIterator<Item = char>) -> Option<[u128; 3]> {
+    chars.into_iter().try_fold([0; 3], |mut classes, c| {
+        let kind = match DefaultClassifier.classify(c)? {
+            Alphabetic => 0,
+            Punctuation => 1,
+            Digit => 2,
+        };
+        classes[kind] |= _BIT(c)?;
+        Some(classes)
+    })
+}
+
+
+impl KeySpec {
+    /// Return the spec of `ltr` letters, `sbl` symbols and `num` numbers, from all the printable ASCII
+    #[inline]
+    pub fn new(ltr: usize, sbl: usize, num: usize) -> Self {
+        KeySpec { counts: [ltr, sbl, num], classes: _ASCII_CLASSES((33..127u8).map(char::from)).expect("printable ASCII") }
+    }
+
+    /// Return the spec of the same counts from `chars` only, classified like ASCII
+    ///
+    /// Return `GenError::InvalidChar` if one of them isn't printable ASCII.
+    #[inline]
+    pub fn with_charset(self, chars: &str) -> Result<Self, GenError> {
+        let classes = _ASCII_CLASSES(chars.chars()).ok_or(GenError::InvalidChar)?;
+
+        Ok(KeySpec { classes, ..self })
+    }
+
+    /// Return the spec without `chars`
+    ///
+    /// Return `GenError::InvalidChar` if one of them isn't printable ASCII.
+    #[inline]
+    pub fn without(self, chars: &str) -> Result<Self, GenError> {
+        let removed = _CHARSET(chars.chars()).ok_or(GenError::InvalidChar)?;
+
+        Ok(KeySpec { classes: self.classes.map(|set| set & !removed), ..self })
+    }
+
+    /// Return the count of `kind`
+    #[inline]
+    pub fn count(&self, kind: ASCIIExcludeCtrl) -> usize {
+        match kind {
+            Alphabetic => self.counts[0],
+            Punctuation => self.counts[1],
+            Digit => self.counts[2],
+        }
+    }
+
+    /// Check whether `c` is one of the characters of the spec
+    #[inline]
+    pub fn allows(&self, c: char) -> bool { self.kind(c).is_some() }
+
+    /// Return the index of the kind of `c` in the spec, `None` if it's not one of its characters
+    #[inline]
+    fn kind(&self, c: char) -> Option<usize> {
+        let bit = _BIT(c)?;
+        self.classes.iter().position(|set| set & bit != 0)
+    }
+
+    /// Check whether `key` has exactly the counts of the spec, and nothing but its characters
+    #[inline]
+    pub fn matches(&self, key: &str) -> bool {
+        let mut counts = [0; 3];
+
+        for c in key.chars() {
+            match self.kind(c) {
+                Some(kind) => counts[kind] += 1,
+                None => return false,
+            }
+        }
+
+        counts == self.counts
+    }
+}
+
+
+impl TryFrom<&RandKey> for KeySpec {
+    type Error = GenError;
+
+    /// Return the spec of the counts and the data of `r_p`, each character of the kind of `r_p` puts it in
+    ///
+    /// Return `GenError::InvalidNumber` if a count doesn't fit in `usize`,
+    /// and `GenError::InvalidChar` if the data has other characters than printable ASCII.
+    #[inline]
+    fn try_from(r_p: &RandKey) -> Result<Self, GenError> {
+        let count = |n: &BigUint| n.to_usize().ok_or(GenError::InvalidNumber);
+
+        let mut classes = [0; 3];
+        for (set, pool) in classes.iter_mut().zip(r_p.DATA.iter()) {
+            *set = _CHARSET(pool.iter().map(_CHAR_FROM_STR)).ok_or(GenError::InvalidChar)?;
+        }
+
+        Ok(KeySpec { counts: [count(&r_p.ltr_cnt)?, count(&r_p.sbl_cnt)?, count(&r_p.num_cnt)?], classes })
+    }
+}
@@ -0,0 +1,172 @@
+use {
+    std::fmt,
+    crate::{RandKey, GenError, Classifier, ASCIIExcludeCtrl::*, utils::{_GROUP_WITH, _CHAR_FROM_STR}},
+};
+
+
+
+
+/// Why an item was skipped by [`add_item_lenient`](RandKey::add_item_lenient)
+/// and [`replace_data_lenient`](RandKey::replace_data_lenient)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The item is an empty string
+    Empty,
+    /// The item has more than one character
+    SeveralChars,
+    /// The classifier rejects the character, like the control characters and the ones out of ASCII by default
+    Unclassified,
+    /// The character is in the data already, or earlier among the items
+    Duplicate,
+}
+
+
+impl fmt::Display for SkipReason {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SkipReason::Empty => "empty item",
+            SkipReason::SeveralChars => "more than one character",
+            SkipReason::Unclassified => "neither a letter, a symbol nor a number",
+            SkipReason::Duplicate => "already in the data",
+        })
+    }
+}
+
+
+/// An item which wasn't added to the data, and its index among the items
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedItem {
+    pub index:  usize,
+    pub item:   String,
+    pub reason: SkipReason,
+}
+
+
+impl fmt::Display for SkippedItem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "item {} {:?}: {}", self.index, self.item, self.reason) }
+}
+
+
+/// The report of a lenient change of the data: how many items were taken, and the skipped ones
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartialResult {
+    pub taken:   usize,
+    pub skipped: Vec<SkippedItem>,
+}
+
+
+impl PartialResult {
+    /// Check whether no item was skipped
+    #[inline]
+    pub fn is_complete(&self) -> bool { self.skipped.is_empty() }
+}
+
+
+/// Split `v` into the items `classifier` accepts and their indices, and the report of the others
+#[inline]
+pub(crate) fn _SORT_ITEMS(v: &[impl AsRef<str>], classifier: &dyn Classifier) -> (Vec<(usize, String)>, PartialResult) {
+    let mut valid = Vec::new();
+    let mut result = PartialResult::default();
+
+    for (index, s) in v.iter().enumerate() {
+        let mut chars = s.as_ref().chars();
+        let reason = match (chars.next(), chars.next()) {
+            (None, _) => SkipReason::Empty,
+            (Some(_), Some(_)) => SkipReason::SeveralChars,
+            (Some(c), None) if classifier.classify(c).is_none() => SkipReason::Unclassified,
+            (Some(_), None) => {
+                valid.push((index, s.as_ref().to_string()));
+                continue;
+            }
+        };
+
+        result.skipped.push(SkippedItem { index, item: s.as_ref().to_string(), reason });
+    }
+
+    result.taken = valid.len();
+
+    (valid, result)
+}
+
+
+impl RandKey {
+    /// Add the valid items to the data like [`add_item`](RandKey::add_item),
+    /// and return the report of the skipped ones rather than failing on them
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, SkipReason, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.clear_all();
+    ///
+    /// let result = r_p.add_item_lenient(&["a", "ab", "7", "", "é", "-", "a"]);
+    /// assert_eq!(result.taken, 3);
+    /// assert_eq!(result.skipped.iter().map(|s| s.index).collect::<Vec<_>>(), vec![1, 3, 4, 6]);
+    /// assert_eq!(result.skipped[2].reason, SkipReason::Unclassified);
+    /// assert_eq!(result.skipped[3].reason, SkipReason::Duplicate);
+    /// assert_eq!(r_p.data(Digit), vec!["7"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn add_item_lenient(&mut self, val: &[impl AsRef<str>]) -> PartialResult {
+        let (valid, mut result) = _SORT_ITEMS(val, &*self.CLASSIFIER);
+        result.taken = 0;
+
+        for (index, item) in valid {
+            let data = match self.CLASSIFIER.classify(_CHAR_FROM_STR(&item)) {
+                Some(Alphabetic) => &mut self.DATA[0],
+                Some(Punctuation) => &mut self.DATA[1],
+                Some(Digit) => &mut self.DATA[2],
+                None => unreachable!("the items are classified"),
+            };
+
+            if data.contains(&item) {
+                result.skipped.push(SkippedItem { index, item, reason: SkipReason::Duplicate });
+            } else {
+                data.push(item);
+                result.taken += 1;
+            }
+        }
+        result.skipped.sort_by_key(|s| s.index);
+
+        result
+    }
+
+    /// Replace the data by the valid items like [`replace_data`](RandKey::replace_data),
+    /// and return the report of the skipped ones rather than failing on them
+    ///
+    /// Return `GenError::MissChar` like `replace_data` if the valid items miss a kind of characters the key needs.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// let result = r_p.replace_data_lenient(&["1", "a", ".", "\t", "xy"])?;
+    /// assert!(!result.is_complete());
+    /// for skipped in &result.skipped {
+    ///     println!("Skipped {}", skipped);
+    /// }
+    /// // Skipped item 3 "\t": neither a letter, a symbol nor a number
+    /// // Skipped item 4 "xy": more than one character
+    /// r_p.join()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn replace_data_lenient(&mut self, val: &[impl AsRef<str>]) -> Result<PartialResult, GenError> {
+        let (valid, result) = _SORT_ITEMS(val, &*self.CLASSIFIER);
+        let valid: Vec<String> = valid.into_iter().map(|(_, item)| item).collect();
+        self.DATA = _GROUP_WITH(&valid, &*self.CLASSIFIER);
+
+        self.check_data().map(|_| result)
+    }
+}
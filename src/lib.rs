@@ -43,26 +43,163 @@
 mod error;
 mod prelude;
 mod utils;
+mod stats;
+mod stream;
+mod expiry;
+mod metadata;
+mod batch;
+mod config;
+mod shared;
+mod worker;
+mod data_report;
+mod convert;
+mod raw;
+mod ops;
+mod shuffle;
+mod strategy;
+mod seeding;
+mod rules;
+mod schedule;
+mod mix;
+mod distribution;
+mod classify;
+mod lenient;
+mod plan;
+mod parallel;
+mod snapshot;
+mod key_spec;
+mod measure;
+mod diff;
+mod budget;
+mod ordered;
+mod wrap;
+mod checkpoint;
+#[cfg(feature = "json")]
+mod charsets;
+#[cfg(feature = "mac")]
+mod mac;
+#[cfg(feature = "derive")]
+mod derive;
+#[cfg(feature = "pool")]
+mod pool;
+pub mod audit;
+#[cfg(feature = "audit-log")]
+pub mod audit_log;
+pub mod checksum;
+pub mod compat;
+pub mod decoy;
+pub mod encoding;
+pub mod export;
+pub mod header;
+pub mod mask;
+pub mod nonce;
+pub mod otp;
+pub mod passphrase;
+pub mod pipeline;
+pub mod presets;
+pub mod pronounceable;
+pub mod quality;
+pub mod scan;
+pub mod service;
+pub mod spelling;
+pub mod storage;
+#[cfg(feature = "profiles")]
+pub mod profiles;
+pub mod strength;
+pub mod testdata;
+pub mod token;
+pub mod wordlist;
+pub mod xor;
+#[cfg(feature = "alloc-track")]
+pub mod alloc_track;
+#[cfg(feature = "spill")]
+mod spill;
+#[cfg(feature = "shamir")]
+pub mod shamir;
+#[cfg(feature = "unicode")]
+pub mod unicode;
+#[cfg(feature = "metrics")]
+pub mod telemetry;
+#[cfg(feature = "transcript")]
+pub mod transcript;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
 
 
 use {
     utils::*,
-    error::GenError,
-    std::cell::RefCell,
+    std::{cell::RefCell, sync::Arc, time::SystemTime},
     self::ASCIIExcludeCtrl::*,
-    crate::prelude::{AsBiguint, _DEFAULT_UNIT,}
+    checksum::Checksum,
+    crate::prelude::AsBiguint,
+};
+
+
+pub use {
+    error::GenError,
+    batch::LabeledKey,
+    checkpoint::Checkpoint,
+    data_report::{DataReport, PoolCheck, PoolWarning},
+    config::{RandKeyConfig, set_global_defaults, reset_global_defaults},
+    metadata::KeyMetadata,
+    raw::salt,
+    shuffle::Difference,
+    strategy::Strategy,
+    seeding::SeedStrategy,
+    rules::Rule,
+    schedule::ScheduledKey,
+    mix::ProfileMix,
+    pipeline::{Pipeline, KeyTransform},
+    classify::{Classifier, DefaultClassifier, ClassCounter},
+    distribution::KeyDistribution,
+    lenient::{PartialResult, SkippedItem, SkipReason},
+    plan::{GenerationPlan, KindPlan},
+    parallel::{parallel_available, SEQUENTIAL_ENV},
+    snapshot::DataSnapshot,
+    key_spec::KeySpec,
+    diff::KeyDiff,
+    budget::{EntropyBudget, Factor},
+    service::{KeyService, ServiceMetrics},
+    shared::SharedRandKey,
+    stats::{GenStats, ThroughputReport},
+    stream::{KeyStream, KeyChunks},
+    strength::StrengthReport,
+    worker::{Worker, spawn_worker},
+    wrap::WrappedWriter,
 };
 
+#[cfg(feature = "pool")]
+pub use pool::KeyPool;
+
 
 /// struct `RandKey`
+///
+/// The key is shared by the clones of a `RandKey` until one of them generates or sets a new one,
+/// so cloning is cheap however long the key is.
 #[derive(Clone, Debug)]
 pub struct RandKey {
     ltr_cnt: BigUint,
     sbl_cnt: BigUint,
     num_cnt: BigUint,
-    key:     RefCell<String>,
+    key:     RefCell<Arc<String>>,
     UNIT:    RefCell<BigUint>,
     DATA:    Vec<Vec<String>>,
+    CHECKSUM: Option<Checksum>,
+    EXPIRY:  Option<SystemTime>,
+    METADATA: RefCell<Option<KeyMetadata>>,
+    SEED:    Option<u64>,
+    STRATEGY: Strategy,
+    SEEDING: SeedStrategy,
+    RULES:   Vec<Rule>,
+    MIN_ENTROPY: f64,
+    POOL_CHECK: PoolCheck,
+    CLASSIFIER: Arc<dyn Classifier>,
+    #[cfg(feature = "spill")]
+    SPILL:   RefCell<BigUint>,
 }
 
 
@@ -70,6 +207,19 @@ pub struct RandKey {
 pub trait ToRandKey {
     /// Converts the value of `self` to a `RandKey`.
     fn to_randkey(&self) -> Result<RandKey, GenError>;
+
+    /// Converts the value of `self` to a `RandKey` whose characters are classified by `classifier`.
+    #[inline]
+    fn to_randkey_with(&self, classifier: impl Classifier + 'static) -> Result<RandKey, GenError>
+    where
+        Self: AsRef<str> + Sized,
+    {
+        let mut r_p = RandKey::default();
+        r_p.set_classifier(classifier);
+        r_p.set_key(self.as_ref(), SetRandKeyOp::Update)?;
+
+        Ok(r_p)
+    }
 }
 
 
@@ -77,9 +227,13 @@ pub trait ToRandKey {
 pub enum SetRandKeyOp {
     Update,
     Check,
+    StrictCheck,
+    HardenedCheck,
+    Append,
 }
 
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ASCIIExcludeCtrl {
     Alphabetic,
     Punctuation,
@@ -111,9 +265,7 @@ impl RandKey {
                 ltr_cnt: ltr_cnt.as_biguint()?,
                 sbl_cnt: sbl_cnt.as_biguint()?,
                 num_cnt: num_cnt.as_biguint()?,
-                key:     RefCell::new(String::new()),
-                UNIT:    RefCell::new(BigUint::from(_DEFAULT_UNIT)),
-                DATA:    _DEFAULT_DATA(),
+                ..Default::default()
             })
         } else {
             Err(GenError::InvalidNumber)
@@ -143,18 +295,48 @@ impl RandKey {
     /// # }
     /// ```
     #[inline]
-    pub fn key(&self) -> String { self.key.clone().into_inner() }
+    pub fn key(&self) -> String { self.key.borrow().to_string() }
+
+    /// Return the key without copying it, it's shared with the clones of this `RandKey`.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::sync::Arc;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("100000", "0", "0")?;
+    /// r_p.join()?;
+    ///
+    /// let copy = r_p.clone();
+    /// assert!(Arc::ptr_eq(&r_p.shared_key(), &copy.shared_key()));
+    ///
+    /// // A new key doesn't affect the clones
+    /// copy.join()?;
+    /// assert!(!Arc::ptr_eq(&r_p.shared_key(), &copy.shared_key()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn shared_key(&self) -> Arc<String> { Arc::clone(&self.key.borrow()) }
 
     /// Set the key of `RandKey`, depend on the name of operation.
     ///
     /// * **Update** : Replace the key you've passed and update the field.
     ///
     /// * **Check** : If the field of new value doesn't match the old one, it will return an `Err` or the old `key` will be replaced.
+    ///
+    /// * **StrictCheck** : Like **Check**, and every character of the new value has to be in the data.
+    ///
+    /// * **HardenedCheck** : Like **Check**, and [`scan`](scan::scan) must find no date, word or other human pattern in the new value.
+    ///
+    /// * **Append** : Append the value you've passed to the key and add its field to the old one.
     /// # Example
     ///
     /// Basic usage:
     /// ```
-    /// use rand_key::{RandKey, SetRandKeyOp::*};
+    /// use rand_key::{RandKey, SetRandKeyOp::*, ASCIIExcludeCtrl::*};
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// // Update
     /// let mut r_p = RandKey::new("10", "2", "3")?;
@@ -166,19 +348,39 @@ impl RandKey {
     ///
     /// assert!(r_p.set_key("]EH1zyqx3Bl/F8a", Check).is_ok());
     /// assert!(r_p.set_key("123456", Check).is_err());
+    ///
+    /// // StrictCheck
+    /// r_p.del_item(&["E"])?;
+    /// assert!(r_p.set_key("]EH1zyqx3Bl/F8a", StrictCheck).is_err());
+    /// assert!(r_p.set_key("]AH1zyqx3Bl/F8a", StrictCheck).is_ok());
+    ///
+    /// // HardenedCheck
+    /// assert!(r_p.set_key("monkey*2019?aaaa", HardenedCheck).is_err());
+    /// assert!(r_p.set_key("]AH1zyqx3Bl/F8a", HardenedCheck).is_ok());
+    ///
+    /// // Append
+    /// assert!(r_p.set_key("ab-", Append).is_ok());
+    /// assert_eq!(r_p.key(), "]AH1zyqx3Bl/F8aab-");
+    /// assert_eq!(r_p.get_cnt(Alphabetic), "12");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
+    pub fn set_key(&mut self, val: &str, op: SetRandKeyOp) -> Result<(), GenError> { self.set_key_owned(val.into(), op) }
+
+    /// Set the key like [`set_key`](RandKey::set_key), but take the ownership of `val` to avoid copying it.
+    ///
+    /// `Append` pushes `val` onto the key in place, unless the clones of this `RandKey` share the key.
+    #[inline]
     #[rustfmt::skip]
-    pub fn set_key(&mut self, val: &str, op: SetRandKeyOp) -> Result<(), GenError> {
+    pub fn set_key_owned(&mut self, val: String, op: SetRandKeyOp) -> Result<(), GenError> {
 
         use self::SetRandKeyOp::*;
-        let (val_ltr_cnt, val_sbl_cnt, val_num_cnt) = _CNT(val)?;
+        let (val_ltr_cnt, val_sbl_cnt, val_num_cnt) = _CNT_WITH(&val, &*self.CLASSIFIER)?;
 
         let mut mut_ref_key = self.key.borrow_mut();
 
-        match op {
+        let result = match op {
 
             Update => {
                 self.ltr_cnt = val_ltr_cnt;
@@ -203,8 +405,56 @@ impl RandKey {
                 }
             }
 
+            StrictCheck => {
+                if (&self.ltr_cnt,
+                    &self.sbl_cnt,
+                    &self.num_cnt,) != (&val_ltr_cnt,
+                                        &val_sbl_cnt,
+                                        &val_num_cnt,) {
+                    Err(GenError::InconsistentField)
+                } else if !val.chars().all(|c| self.DATA.iter().any(|v| v.contains(&c.to_string()))) {
+                    Err(GenError::OutOfData)
+                } else {
+                    *mut_ref_key = val.into();
+
+                    Ok(())
+                }
+            }
+
+            HardenedCheck => {
+                if (&self.ltr_cnt,
+                    &self.sbl_cnt,
+                    &self.num_cnt,) != (&val_ltr_cnt,
+                                        &val_sbl_cnt,
+                                        &val_num_cnt,) {
+                    Err(GenError::InconsistentField)
+                } else if let Some(finding) = scan::scan(&val).first() {
+                    Err(GenError::WeakKey(finding.to_string()))
+                } else {
+                    *mut_ref_key = val.into();
+
+                    Ok(())
+                }
+            }
+
+            Append => {
+                self.ltr_cnt += val_ltr_cnt;
+                self.sbl_cnt += val_sbl_cnt;
+                self.num_cnt += val_num_cnt;
+                Arc::make_mut(&mut mut_ref_key).push_str(&val);
+
+                Ok(())
+            }
+
+        };
+
+        // The key set from outside wasn't generated by `RandKey`
+        if result.is_ok() {
+            *self.METADATA.borrow_mut() = None;
         }
 
+        result
+
     }
 
     /// Return the value of `UNIT`
@@ -238,6 +488,28 @@ impl RandKey {
         }
     }
 
+    /// Set the algorithm of the check digit ending the key, or `None` to stop appending it.
+    ///
+    /// The check digit is one of the digits `RandKey` counts, so it requires at least one digit and no symbols.
+    /// Only `join` and the methods built on it append the check digit, `stream` doesn't.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, checksum::Checksum::Luhn};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("0", "0", "6")?;
+    /// r_p.set_checksum(Some(Luhn));
+    /// r_p.join()?;
+    /// assert_eq!(r_p.len(), "6");
+    /// assert!(Luhn.verify(&r_p.key()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_checksum(&mut self, checksum: Option<Checksum>) { self.CHECKSUM = checksum; }
+
     /// Return the shared reference of `DATA`
     #[inline]
     pub fn all_data(&self) -> &Vec<Vec<String>> { &self.DATA }
@@ -268,25 +540,8 @@ impl RandKey {
 
     /// Check the data
     #[inline]
-    #[allow(non_snake_case)]
     pub(crate) fn check_data(&self) -> Result<(), GenError> {
-        let L = self.ltr_cnt.is_zero();
-        let S = self.sbl_cnt.is_zero();
-        let N = self.num_cnt.is_zero();
-
-        let dl = self.DATA[0].is_empty();
-        let ds = self.DATA[1].is_empty();
-        let dn = self.DATA[2].is_empty();
-
-        let dl_L = !L && dl;
-        let ds_S = !S && ds;
-        let dn_N = !N && dn;
-
-        if !(dl_L || ds_S || dn_N) {
-            Ok(())
-        } else {
-            Err(GenError::MissChar)
-        }
+        _CHECK_DATA([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA)
     }
 
     /// Delete the data
@@ -309,14 +564,15 @@ impl RandKey {
 
         let mut concat_data: Vec<String> = self.DATA.concat();
 
-        if _CHECK_ASCII(items) {
+        if _CHECK_ITEMS(items, &*self.CLASSIFIER) {
 
             let mut items: Vec<char> = items.iter().map(_CHAR_FROM_STR).collect();
-            items.dedup_by_key(|x| *x as u8);
+            items.sort_unstable();
+            items.dedup();
 
             if items.iter().any(|x| concat_data.contains(&x.to_string())) {
                 concat_data.retain(|x| !items.contains(&_CHAR_FROM_STR(x)));
-                self.DATA = _GROUP(&concat_data);
+                self.DATA = _GROUP_WITH(&concat_data, &*self.CLASSIFIER);
 
                 Ok(())
             } else {
@@ -347,12 +603,16 @@ impl RandKey {
     #[inline]
     #[allow(clippy::needless_range_loop)]
     pub fn add_item(&mut self, val: &[impl AsRef<str>]) -> Result<(), GenError> {
-        if _CHECK_ASCII(val) {
-            let val = _GROUP(val);
+        if _CHECK_ITEMS(val, &*self.CLASSIFIER) {
+            let val = _GROUP_WITH(val, &*self.CLASSIFIER);
 
+            // The characters are compared whole, the ones out of ASCII may share their low byte
             for i in 0..self.DATA.len() {
-                self.DATA[i].extend_from_slice(&val[i]);
-                self.DATA[i].dedup_by_key(|x| _CHAR_FROM_STR(x) as u8);
+                for item in &val[i] {
+                    if !self.DATA[i].contains(item) {
+                        self.DATA[i].push(item.clone());
+                    }
+                }
             }
 
             Ok(())
@@ -385,25 +645,9 @@ impl RandKey {
     #[rustfmt::skip]
     pub fn replace_data(&mut self, val: &[impl AsRef<str>]) -> Result<(), GenError> {
 
-        if _CHECK_ASCII(val) {
-
-            self.DATA = {
-
-                let mut ltr = vec![];
-                let mut sbl = vec![];
-                let mut num = vec![];
-
-                val.iter().for_each(|x| {
-                    let x = _CHAR_FROM_STR(x);
+        if _CHECK_ITEMS(val, &*self.CLASSIFIER) {
 
-                    if x.is_ascii_alphabetic()  { ltr.push(x.into()); }
-                    if x.is_ascii_punctuation() { sbl.push(x.into()); }
-                    if x.is_ascii_digit()       { num.push(x.into()); }
-                });
-
-                vec![ltr, sbl, num]
-
-            };
+            self.DATA = _GROUP_WITH(val, &*self.CLASSIFIER);
 
             self.check_data()
 
@@ -434,6 +678,30 @@ impl RandKey {
     #[inline]
     pub fn is_empty(&self) -> bool { self.key.borrow().is_empty() }
 
+    /// Return the kind of each character of the key, as the [`Classifier`] puts it
+    ///
+    /// A character the classifier rejects, only in a key set without checks, is a symbol.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{ToRandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "a-1".to_randkey()?;
+    /// assert_eq!(r_p.class_map(), vec![Alphabetic, Punctuation, Digit]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn class_map(&self) -> Vec<ASCIIExcludeCtrl> {
+        self.key
+            .borrow()
+            .chars()
+            .map(|c| self.CLASSIFIER.classify(c).unwrap_or(Punctuation))
+            .collect()
+    }
+
     /// Get count of `RandKey`
     /// # Example
     ///
@@ -492,63 +760,167 @@ impl RandKey {
     }
 
     /// Generate the password for `RandKey`
+    ///
+    /// Return `GenError::AllocationFailed` if there isn't enough memory for the key.
     /// # Example
     ///
     /// Basic usage:
     /// ```
-    /// use rand_key::RandKey;
+    /// use rand_key::{RandKey, GenError};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut r_p = RandKey::new("10", "2", "3")?;
     /// r_p.join()?;
     /// println!("{}", r_p);
+    ///
+    /// let huge = RandKey::new("10000000000000000000", "0", "0")?;
+    /// assert!(matches!(huge.join(), Err(GenError::AllocationFailed)));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[rustfmt::skip]
-    pub fn join(&self) -> Result<(), GenError> {
-
-        let mut inner = self.clone();
-
-        if inner.check_data().is_ok() {
-            let unit = &inner.UNIT.clone().into_inner();
-            let data = &inner.DATA;
-
-            // TODO: - Improve readability
-            let mut PWD =
-                vec![(&mut inner.ltr_cnt, &data[0]),
-                     (&mut inner.sbl_cnt, &data[1]),
-                     (&mut inner.num_cnt, &data[2]),]
-                    .into_iter()
-                    .map(|(bignum, data)| {
-                        _DIV_UNIT(unit, bignum)
-                            .par_iter()
-                            .map(|cnt| {
-                                _RAND_IDX(cnt, data.len())
-                                    .par_iter()
-                                    .map(|idx| data[*idx].clone())
-                                    .collect::<String>()
-                            })
-                            .collect()
-                    })
-                    .collect::<Vec<Vec<_>>>()
-                    .concat()
-                    .join("");
-
-            // This is absolutely safe, because they are all ASCII characters except control ones.
-            let bytes = unsafe { PWD.as_bytes_mut() };
-            bytes.shuffle(&mut thread_rng());
-
-            let mut mut_ref_key = self.key.borrow_mut();
-
-            *mut_ref_key = bytes.par_iter().map(|s| *s as char).collect::<String>();
+    pub fn join(&self) -> Result<(), GenError> { self.join_with_stats().map(|_| ()) }
 
-            Ok(())
+    /// Generate the password like [`join`](RandKey::join), and return it.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::default();
+    /// let key = r_p.generate()?;
+    /// assert_eq!(key, r_p.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate(&self) -> Result<String, GenError> {
+        self.join()?;
+        Ok(self.key())
+    }
 
-        } else {
-            inner.check_data()
+    /// Generate a key like [`join`](RandKey::join) without storing it, for the callers which own it from then on
+    #[inline]
+    pub(crate) fn generate_detached(&self) -> Result<(String, GenStats), GenError> {
+        self.check_entropy()?;
+        let warnings = self.check_pools()?;
+
+        let (key, mut stats) = _GENERATE(
+            [&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt],
+            &self.UNIT.borrow(),
+            &self.DATA,
+            self.CHECKSUM,
+            self.arrangement(),
+        )?;
+        stats.warnings = warnings;
+
+        Ok((key, stats))
+    }
+
+    /// Generate the password like [`join`](RandKey::join), and report how long each phase took.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("100000", "0", "0")?;
+    /// r_p.set_unit("1000")?;
+    /// let stats = r_p.join_with_stats()?;
+    /// assert_eq!(stats.chars, 100000);
+    /// println!("{}", stats);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_with_stats(&self) -> Result<GenStats, GenError> {
+        let (key, stats) = self.generate_detached()?;
+
+        #[cfg(feature = "audit-log")]
+        crate::audit_log::_RECORD(|| self.config(), &key)?;
+
+        *self.key.borrow_mut() = key.into();
+        self.record_metadata();
+
+        Ok(stats)
+    }
+
+    /// Generate `n` keys and keep the one `scorer` rates highest, at least one key is generated.
+    ///
+    /// Use [`strength::score`] to keep the one with the highest estimated entropy.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, strength};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join_best_of(5, strength::score)?;
+    /// assert_eq!(r_p.len(), "15");
+    ///
+    /// // Or prefer the keys without two identical adjacent characters
+    /// r_p.join_best_of(5, |key| {
+    ///     let bytes = key.as_bytes();
+    ///     -(bytes.windows(2).filter(|w| w[0] == w[1]).count() as f64)
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_best_of(&self, n: usize, scorer: impl Fn(&str) -> f64) -> Result<(), GenError> {
+        let mut best: Option<(f64, Arc<String>, Option<KeyMetadata>)> = None;
+
+        for _ in 0..n.max(1) {
+            self.join()?;
+            let key = self.shared_key();
+            let score = scorer(&key);
+
+            if best.as_ref().is_none_or(|(s, ..)| score > *s) {
+                best = Some((score, key, self.metadata()));
+            }
         }
+
+        if let Some((_, key, metadata)) = best {
+            *self.key.borrow_mut() = key;
+            *self.METADATA.borrow_mut() = metadata;
+        }
+
+        Ok(())
     }
-}
 
+    /// Generate keys until one passes `predicate`, make at most `max_attempts` attempts.
+    ///
+    /// Return `GenError::AttemptsExhausted` if none of them passes, the key is left as the last attempt.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, GenError};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// // The key never starts with a digit
+    /// r_p.join_filtered(|key| !key.starts_with(|c: char| c.is_ascii_digit()), 100)?;
+    /// assert!(!r_p.key().starts_with(|c: char| c.is_ascii_digit()));
+    ///
+    /// assert!(matches!(r_p.join_filtered(|_| false, 3), Err(GenError::AttemptsExhausted)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_filtered(&self, predicate: impl Fn(&str) -> bool, max_attempts: usize) -> Result<(), GenError> {
+        for _ in 0..max_attempts {
+            self.join()?;
+            if predicate(&self.key.borrow()) {
+                return Ok(());
+            }
+        }
+
+        Err(GenError::AttemptsExhausted)
+    }
+}
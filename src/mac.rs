@@ -0,0 +1,49 @@
+//! HMAC-SHA256 tags of the keys, so the batches can be checked for integrity
+//! without a plain hash, which a dictionary would crack.
+use {
+    hmac::{Hmac, Mac},
+    sha2::Sha256,
+    crate::RandKey,
+};
+
+
+
+
+type HmacSha256 = Hmac<Sha256>;
+
+
+impl RandKey {
+    /// Return the HMAC-SHA256 tag of the key, keyed by `hmac_key`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::default();
+    /// r_p.join()?;
+    ///
+    /// let tag = r_p.tag_with(b"batch secret");
+    /// assert!(r_p.verify_tag(b"batch secret", &tag));
+    /// assert!(!r_p.verify_tag(b"other secret", &tag));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn tag_with(&self, hmac_key: &[u8]) -> [u8; 32] {
+        let mut mac = HmacSha256::new_from_slice(hmac_key).expect("HMAC takes keys of any length");
+        mac.update(self.key.borrow().as_bytes());
+
+        mac.finalize().into_bytes().into()
+    }
+
+    /// Check whether `tag` is the HMAC-SHA256 tag of the key, in constant time
+    #[inline]
+    pub fn verify_tag(&self, hmac_key: &[u8], tag: &[u8]) -> bool {
+        let mut mac = HmacSha256::new_from_slice(hmac_key).expect("HMAC takes keys of any length");
+        mac.update(self.key.borrow().as_bytes());
+
+        mac.verify_slice(tag).is_ok()
+    }
+}
@@ -0,0 +1,60 @@
+//! Masked keys, recognizable in the logs and the UIs without giving them away.
+use crate::RandKey;
+
+
+
+
+/// The most masked characters shown, more are shortened with an ellipsis
+pub const MAX_STARS: usize = 6;
+
+
+/// Return `key` with all but its first `visible_prefix` and last `visible_suffix` characters masked by `*`
+///
+/// More than [`MAX_STARS`] masked characters are shown `****…**`, so the mask doesn't tell the length of the key.
+/// The whole key is masked if it isn't longer than the visible characters.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::mask::mask;
+///
+/// assert_eq!(mask("aB3xY7#kP2q9!", 3, 2), "aB3****…**9!");
+/// assert_eq!(mask("aB3xY9!", 3, 2), "aB3**9!");
+/// assert_eq!(mask("αβγδεζη", 1, 1), "α*****η");
+/// assert_eq!(mask("abc", 2, 1), "***");
+/// ```
+#[inline]
+pub fn mask(key: &str, visible_prefix: usize, visible_suffix: usize) -> String {
+    let len = key.chars().count();
+    let (prefix, suffix) = if visible_prefix.saturating_add(visible_suffix) < len { (visible_prefix, visible_suffix) } else { (0, 0) };
+    let hidden = len - prefix - suffix;
+
+    let mut masked: String = key.chars().take(prefix).collect();
+    if hidden > MAX_STARS {
+        masked.push_str("****…**");
+    } else {
+        masked.extend(std::iter::repeat_n('*', hidden));
+    }
+    masked.extend(key.chars().skip(len - suffix));
+
+    masked
+}
+
+
+impl RandKey {
+    /// Return the key masked like [`mask`] does
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::ToRandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "Tr0ub4dor&3xyz".to_randkey()?;
+    /// assert_eq!(r_p.masked(2, 2), "Tr****…**yz");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn masked(&self, visible_prefix: usize, visible_suffix: usize) -> String { mask(&self.key.borrow(), visible_prefix, visible_suffix) }
+}
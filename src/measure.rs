@@ -0,0 +1,56 @@
+use crate::RandKey;
+
+#[cfg(feature = "graphemes")]
+use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "width")]
+use unicode_width::UnicodeWidthStr;
+
+
+
+
+impl RandKey {
+    /// Return the length of the key in [char]s, which [`len`](RandKey::len) counts in bytes
+    ///
+    /// The keys picked from Unicode ranges can have characters of several bytes,
+    /// and characters which are shown together, like the combining marks,
+    /// see [`len_graphemes`](RandKey::len_graphemes) and [`display_width`](RandKey::display_width) to size the fields.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{Classifier, DefaultClassifier, ToRandKey, ASCIIExcludeCtrl::{self, *}};
+    ///
+    /// // Anything but the ASCII symbols and digits is a letter
+    /// #[derive(Debug)]
+    /// struct Letters;
+    ///
+    /// impl Classifier for Letters {
+    ///     fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl> { Some(DefaultClassifier.classify(c).unwrap_or(Alphabetic)) }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // An `e` with a combining acute accent, and a wide CJK character
+    /// let r_p = "e\u{301}犬1".to_randkey_with(Letters)?;
+    /// assert_eq!(r_p.len(), "7");
+    /// assert_eq!(r_p.len_chars(), 4);
+    /// # #[cfg(feature = "graphemes")]
+    /// assert_eq!(r_p.len_graphemes(), 3);
+    /// # #[cfg(feature = "width")]
+    /// assert_eq!(r_p.display_width(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn len_chars(&self) -> usize { self.key.borrow().chars().count() }
+
+    /// Return the length of the key in extended grapheme clusters, the characters a reader sees
+    #[cfg(feature = "graphemes")]
+    #[inline]
+    pub fn len_graphemes(&self) -> usize { self.key.borrow().graphemes(true).count() }
+
+    /// Return how many columns of a terminal the key takes,
+    /// two for the wide East Asian characters and none for the combining marks
+    #[cfg(feature = "width")]
+    #[inline]
+    pub fn display_width(&self) -> usize { self.key.borrow().width() }
+}
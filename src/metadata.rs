@@ -0,0 +1,57 @@
+use {
+    std::time::SystemTime,
+    crate::{RandKey, utils::_FINGERPRINT},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// How the current key was generated, for audit trails
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyMetadata {
+    /// When the key was generated
+    pub generated_at: SystemTime,
+    /// The random number generator picking the characters
+    pub rng:          String,
+    /// Fingerprint of the data the characters were picked from
+    pub charset:      String,
+    /// Entropy of the key, in bits
+    pub entropy_bits: f64,
+}
+
+
+impl RandKey {
+    /// Return how the current key was generated, `None` if it wasn't generated by `RandKey`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// assert!(r_p.metadata().is_none());
+    ///
+    /// r_p.join()?;
+    /// let meta = r_p.metadata().unwrap();
+    /// assert_eq!(meta.entropy_bits, r_p.strength().bits);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn metadata(&self) -> Option<KeyMetadata> { self.METADATA.borrow().clone() }
+
+    #[inline]
+    pub(crate) fn record_metadata(&self) {
+        *self.METADATA.borrow_mut() = Some(KeyMetadata {
+            generated_at: SystemTime::now(),
+            rng:          self.SEEDING.rng_name().into(),
+            charset:      _FINGERPRINT(&self.DATA),
+            entropy_bits: self.strength().bits,
+        });
+    }
+}
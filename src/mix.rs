@@ -0,0 +1,78 @@
+use {
+    rand::{thread_rng, distributions::{Distribution, WeightedIndex}},
+    crate::{RandKey, GenError},
+};
+
+
+
+
+/// Profiles which generate the keys in turn, each one chosen by its weight,
+/// for synthetic datasets where the keys aren't all alike
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, ProfileMix};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mix = ProfileMix::new().with_profile(RandKey::new("10", "2", "4")?, 80)
+///                            .with_profile(RandKey::new("40", "8", "16")?, 20);
+///
+/// let keys = mix.generate_many(1000)?;
+/// let long = keys.iter().filter(|k| k.len() == 64).count();
+/// assert!(100 < long && long < 300);
+/// assert!(keys.iter().all(|k| k.len() == 16 || k.len() == 64));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ProfileMix {
+    profiles: Vec<(RandKey, u32)>,
+}
+
+
+impl ProfileMix {
+    /// Return a mix without profiles
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Add `profile`, chosen with odds of `weight` to the sum of the weights
+    #[inline]
+    pub fn with_profile(mut self, profile: RandKey, weight: u32) -> Self {
+        self.profiles.push((profile, weight));
+        self
+    }
+
+    /// Return the profiles and their weights
+    #[inline]
+    pub fn profiles(&self) -> &[(RandKey, u32)] { &self.profiles }
+
+    /// Return the odds of choosing the profiles
+    #[inline]
+    fn weights(&self) -> Result<WeightedIndex<u32>, GenError> {
+        WeightedIndex::new(self.profiles.iter().map(|(_, w)| *w)).map_err(|_| GenError::InconsistentField)
+    }
+
+    /// Generate a key by a profile chosen by weight, return the index of the profile and the key
+    ///
+    /// Return `GenError::InconsistentField` if there is no profile or all the weights are 0.
+    #[inline]
+    pub fn generate_indexed(&self) -> Result<(usize, String), GenError> {
+        let i = self.weights()?.sample(&mut thread_rng());
+
+        Ok((i, self.profiles[i].0.generate()?))
+    }
+
+    /// Generate a key by a profile chosen by weight
+    #[inline]
+    pub fn generate(&self) -> Result<String, GenError> { self.generate_indexed().map(|(_, key)| key) }
+
+    /// Generate `n` keys, each one by a profile chosen by weight
+    #[inline]
+    pub fn generate_many(&self, n: usize) -> Result<Vec<String>, GenError> {
+        let weights = self.weights()?;
+        let mut rng = thread_rng();
+
+        (0..n).map(|_| self.profiles[weights.sample(&mut rng)].0.generate()).collect()
+    }
+}
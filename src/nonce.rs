@@ -0,0 +1,94 @@
+//! Random nonces and IVs of the sizes the AEAD algorithms take.
+use crate::salt;
+
+
+
+
+/// The AEAD algorithms, which decide the size of the nonce
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algo {
+    /// AES-256-GCM, 12 bytes
+    Aes256Gcm,
+    /// ChaCha20-Poly1305, 12 bytes
+    ChaCha20Poly1305,
+    /// XChaCha20-Poly1305, 24 bytes
+    XChaCha,
+}
+
+
+/// How many bytes of a [`NonceSequence`] nonce are the counter
+pub const COUNTER_LEN: usize = 8;
+
+
+impl Algo {
+    /// Return the size of the nonce in bytes
+    #[inline]
+    pub fn nonce_len(self) -> usize {
+        match self {
+            Algo::Aes256Gcm | Algo::ChaCha20Poly1305 => 12,
+            Algo::XChaCha => 24,
+        }
+    }
+}
+
+
+/// Return a random nonce for `algo`
+///
+/// A random 12-byte nonce shouldn't be used for more than 2^32 messages under the same key,
+/// see [`NonceSequence`] otherwise.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::nonce::{for_algorithm, Algo};
+///
+/// assert_eq!(for_algorithm(Algo::Aes256Gcm).len(), 12);
+/// assert_eq!(for_algorithm(Algo::XChaCha).len(), 24);
+/// ```
+#[inline]
+pub fn for_algorithm(algo: Algo) -> Vec<u8> { salt(algo.nonce_len()) }
+
+
+/// Nonces made of a random prefix and a big-endian counter in the last [`COUNTER_LEN`] bytes,
+/// which never repeat under the same key, until the counter runs out and the iterator ends.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::nonce::{NonceSequence, Algo};
+///
+/// let mut nonces = NonceSequence::new(Algo::ChaCha20Poly1305);
+/// let first = nonces.next().unwrap();
+/// let second = nonces.next().unwrap();
+/// assert_eq!(first[..4], second[..4]);
+/// assert_eq!(first[4..], [0, 0, 0, 0, 0, 0, 0, 0]);
+/// assert_eq!(second[4..], [0, 0, 0, 0, 0, 0, 0, 1]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NonceSequence {
+    prefix:  Vec<u8>,
+    counter: Option<u64>,
+}
+
+
+impl NonceSequence {
+    /// Return a sequence of nonces for `algo` with a random prefix
+    #[inline]
+    pub fn new(algo: Algo) -> Self { NonceSequence { prefix: salt(algo.nonce_len() - COUNTER_LEN), counter: Some(0) } }
+}
+
+
+impl Iterator for NonceSequence {
+    type Item = Vec<u8>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Vec<u8>> {
+        let counter = self.counter?;
+        self.counter = counter.checked_add(1);
+
+        let mut nonce = self.prefix.clone();
+        nonce.extend_from_slice(&counter.to_be_bytes());
+
+        Some(nonce)
+    }
+}
@@ -0,0 +1,139 @@
+use {
+    std::{iter::FromIterator, ops::Add},
+    crate::{RandKey, utils::{BigUint, One, Zero}},
+};
+
+
+
+
+impl RandKey {
+    /// Return a `RandKey` whose key is the key of `self` followed by the one of `other`
+    ///
+    /// The counts are added, and the data is the union of both, the other settings come from `self`.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ToRandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut prefix = "site-".to_randkey()?;
+    /// prefix.clear_all();
+    /// prefix.add_item(&["s", "i", "t", "e", "-"])?;
+    ///
+    /// let mut suffix = RandKey::new("4", "0", "4")?;
+    /// suffix.clear(Punctuation);
+    /// suffix.join()?;
+    ///
+    /// let key = prefix.concat(&suffix);
+    /// assert!(key.key().starts_with("site-"));
+    /// assert_eq!(key.get_cnt(Alphabetic), "8");
+    /// assert_eq!(key.data(Alphabetic).len(), 52);
+    /// assert_eq!(key.data(Punctuation), ["-"]);
+    ///
+    /// // Or with `+`
+    /// assert_eq!((prefix + suffix).key(), key.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn concat(&self, other: &RandKey) -> RandKey {
+        let mut r_p = self.clone();
+
+        r_p.ltr_cnt += &other.ltr_cnt;
+        r_p.sbl_cnt += &other.sbl_cnt;
+        r_p.num_cnt += &other.num_cnt;
+        *r_p.key.borrow_mut() = format!("{}{}", self.key.borrow(), other.key.borrow()).into();
+        *r_p.METADATA.borrow_mut() = None;
+
+        for (data, other) in r_p.DATA.iter_mut().zip(other.DATA.iter()) {
+            for item in other {
+                if !data.contains(item) {
+                    data.push(item.clone());
+                }
+            }
+        }
+
+        r_p
+    }
+}
+
+
+impl Add for RandKey {
+    type Output = RandKey;
+
+    /// Concatenate the two `RandKey`s, see [`concat`](RandKey::concat)
+    #[inline]
+    fn add(self, other: RandKey) -> RandKey { self.concat(&other) }
+}
+
+
+impl Add<&RandKey> for &RandKey {
+    type Output = RandKey;
+
+    #[inline]
+    fn add(self, other: &RandKey) -> RandKey { self.concat(other) }
+}
+
+
+impl Extend<char> for RandKey {
+    /// Append the characters to the key and count them, the ones which aren't ASCII letters,
+    /// symbols or numbers are skipped
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{ToRandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = "ab1".to_randkey()?;
+    /// r_p.extend("-2 é".chars());
+    /// assert_eq!(r_p.key(), "ab1-2");
+    /// assert_eq!(r_p.get_cnt(Digit), "2");
+    /// assert_eq!(r_p.get_cnt(Punctuation), "1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let mut key = self.key.borrow().to_string();
+
+        for c in iter {
+            let cnt = match c {
+                c if c.is_ascii_alphabetic() => &mut self.ltr_cnt,
+                c if c.is_ascii_punctuation() => &mut self.sbl_cnt,
+                c if c.is_ascii_digit() => &mut self.num_cnt,
+                _ => continue,
+            };
+            *cnt += BigUint::one();
+            key.push(c);
+        }
+
+        *self.key.borrow_mut() = key.into();
+        *self.METADATA.borrow_mut() = None;
+    }
+}
+
+
+impl FromIterator<char> for RandKey {
+    /// Count the characters into the key of a default `RandKey`, skipping them like [`extend`](RandKey::extend)
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// let r_p: RandKey = ('a'..='z').chain('0'..='4').collect();
+    /// assert_eq!(r_p.key(), "abcdefghijklmnopqrstuvwxyz01234");
+    /// assert_eq!(r_p.get_cnt(Alphabetic), "26");
+    /// assert_eq!(r_p.get_cnt(Punctuation), "0");
+    /// assert_eq!(r_p.get_cnt(Digit), "5");
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut r_p = RandKey { ltr_cnt: BigUint::zero(), sbl_cnt: BigUint::zero(), num_cnt: BigUint::zero(), ..Default::default() };
+        r_p.extend(iter);
+
+        r_p
+    }
+}
@@ -0,0 +1,90 @@
+use {
+    rand::Rng,
+    crate::{RandKey, GenError, Strategy, ASCIIExcludeCtrl::{self, *}, strategy::_DISTINCT, utils::_COUNTS},
+};
+
+
+
+
+impl RandKey {
+    /// Generate the password with a block of each kind of `template`, in that order, without shuffling.
+    ///
+    /// Every kind of characters in use has to be in the template once, and the digits last with a check digit,
+    /// or `GenError::InconsistentField` is returned. It is returned as well if there are [rules](RandKey::add_rule),
+    /// which the blocks can't follow, and with `Strategy::Coverage`. The blocks have no character twice with `Strategy::Unique`.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("6", "0", "4")?;
+    /// r_p.join_ordered(&[Alphabetic, Digit])?;
+    ///
+    /// let key = r_p.key();
+    /// assert!(key[..6].chars().all(|c| c.is_ascii_alphabetic()));
+    /// assert!(key[6..].chars().all(|c| c.is_ascii_digit()));
+    ///
+    /// // The symbols or the numbers are missing
+    /// assert!(r_p.join_ordered(&[Alphabetic]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_ordered(&self, template: &[ASCIIExcludeCtrl]) -> Result<(), GenError> {
+        self.check_entropy()?;
+        self.check_pools()?;
+
+        let counts = _COUNTS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM)?;
+
+        let kinds: Vec<usize> = template.iter()
+                                        .map(|kind| match kind {
+                                            Alphabetic => 0,
+                                            Punctuation => 1,
+                                            Digit => 2,
+                                        })
+                                        .collect();
+
+        let once = (0..3).all(|i| kinds.iter().filter(|k| **k == i).count() <= 1);
+        let complete = (0..3).all(|i| counts[i] == 0 || kinds.contains(&i));
+        let digits_last = self.CHECKSUM.is_none() || kinds.last() == Some(&2);
+        if !(once && complete && digits_last && self.RULES.is_empty() && self.STRATEGY != Strategy::Coverage) {
+            return Err(GenError::InconsistentField);
+        }
+
+        let unique = self.STRATEGY == Strategy::Unique;
+        if unique && self.CHECKSUM.is_some() {
+            return Err(GenError::InconsistentField);
+        }
+
+        let extra = self.CHECKSUM.map_or(0, |_| 1);
+        let len = counts.iter().try_fold(extra, |acc: usize, c| acc.checked_add(*c)).ok_or(GenError::AllocationFailed)?;
+        let mut key = String::new();
+        key.try_reserve_exact(len).map_err(|_| GenError::AllocationFailed)?;
+        for i in kinds {
+            let pool = &self.DATA[i];
+            let mut rng = self.SEEDING.unit_rng(i, 0);
+
+            if unique {
+                key.extend(_DISTINCT(pool, counts[i], &mut rng)?.into_iter().map(String::as_str));
+            } else {
+                for _ in 0..counts[i] {
+                    key.push_str(&pool[rng.gen_range(0, pool.len())]);
+                }
+            }
+        }
+
+        if let Some(checksum) = self.CHECKSUM {
+            key.push(checksum.digit(&key)?);
+        }
+
+        #[cfg(feature = "audit-log")]
+        crate::audit_log::_RECORD(|| self.config(), &key)?;
+
+        *self.key.borrow_mut() = key.into();
+        self.record_metadata();
+
+        Ok(())
+    }
+}
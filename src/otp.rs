@@ -0,0 +1,112 @@
+//! Short-lived codes for email and SMS verification.
+use {
+    std::time::{Duration, SystemTime},
+    crate::{GenError, token, encoding::CROCKFORD_ALPHABET},
+};
+
+
+
+
+/// The characters of a [`OneTimeCode`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeKind {
+    /// Digits only, the usual SMS codes
+    Numeric,
+    /// Digits and uppercase letters without `I`, `L`, `O` and `U`, checked case-insensitively
+    Alphanumeric,
+}
+
+
+/// A code and when it expires
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OneTimeCode {
+    pub code:       String,
+    pub kind:       CodeKind,
+    pub expires_at: SystemTime,
+}
+
+
+/// Compare `a` and `b` in a time which depends on their lengths only
+#[inline]
+pub(crate) fn _CT_EQ(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+
+impl OneTimeCode {
+    /// Returns true if the code has expired, and false otherwise.
+    #[inline]
+    pub fn is_expired(&self) -> bool { SystemTime::now() >= self.expires_at }
+
+    /// Check whether `code` is this code and it hasn't expired, in constant time
+    #[inline]
+    pub fn verify(&self, code: &str) -> bool {
+        let code = match self.kind {
+            CodeKind::Numeric => code.trim().to_string(),
+            CodeKind::Alphanumeric => code.trim().to_ascii_uppercase(),
+        };
+
+        // Both are checked, so an expired code takes as long as a live one
+        let matches = _CT_EQ(code.as_bytes(), self.code.as_bytes());
+        !self.is_expired() & matches
+    }
+}
+
+
+/// Return a numeric code of `len` digits, which expires after `ttl`
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::otp::one_time_code;
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let otp = one_time_code(6, Duration::from_secs(600))?;
+/// assert_eq!(otp.code.len(), 6);
+/// assert!(otp.code.chars().all(|c| c.is_ascii_digit()));
+///
+/// assert!(otp.verify(&otp.code));
+/// assert!(!otp.verify("12345"));
+///
+/// let expired = one_time_code(6, Duration::from_secs(0))?;
+/// assert!(!expired.verify(&expired.code));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn one_time_code(len: usize, ttl: Duration) -> Result<OneTimeCode, GenError> { one_time_code_with(CodeKind::Numeric, len, ttl) }
+
+
+/// Return a code of `len` characters of `kind`, which expires after `ttl`
+///
+/// The characters are picked like the ones of [`TokenSpec`](crate::token::TokenSpec),
+/// uniformly by the thread-local generator seeded by the OS.
+/// Return `GenError::InvalidNumber` if `len` is zero.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::otp::{one_time_code_with, CodeKind};
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let otp = one_time_code_with(CodeKind::Alphanumeric, 8, Duration::from_secs(900))?;
+/// assert_eq!(otp.code.len(), 8);
+/// assert!(otp.verify(&otp.code.to_lowercase()));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn one_time_code_with(kind: CodeKind, len: usize, ttl: Duration) -> Result<OneTimeCode, GenError> {
+    if len == 0 {
+        return Err(GenError::InvalidNumber);
+    }
+
+    let spec = match kind {
+        CodeKind::Numeric => token::digits(len),
+        CodeKind::Alphanumeric => token::charset(std::str::from_utf8(CROCKFORD_ALPHABET).unwrap(), len),
+    };
+
+    Ok(OneTimeCode { code: spec.generate()?, kind, expires_at: SystemTime::now() + ttl })
+}
@@ -0,0 +1,55 @@
+use std::{env, sync::OnceLock, thread};
+
+
+
+
+/// Whether rayon can run its threads, checked on the first generation
+static _AVAILABLE: OnceLock<bool> = OnceLock::new();
+
+
+/// The environment variable which makes the generations sequential when it's set to `1`
+pub const SEQUENTIAL_ENV: &str = "RAND_KEY_SEQUENTIAL";
+
+
+/// Check whether the units can be picked in parallel
+///
+/// They can't on `wasm32` without the `atomics` feature, nor where a thread can't be spawned,
+/// like the sandboxes limiting the threads of a process, nor with the environment variable [`SEQUENTIAL_ENV`] set to `1`.
+/// They are then picked one after the other, the same characters with a seed,
+/// and [`GenStats::sequential`](crate::GenStats::sequential) tells it, rather than rayon panicking.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, parallel_available, SEQUENTIAL_ENV};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// std::env::set_var(SEQUENTIAL_ENV, "1");
+/// assert!(!parallel_available());
+///
+/// let r_p = RandKey::new("100000", "0", "0")?;
+/// r_p.set_unit("1000")?;
+/// let stats = r_p.join_with_stats()?;
+/// assert!(stats.sequential);
+/// assert_eq!(stats.units, 100);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn parallel_available() -> bool {
+    *_AVAILABLE.get_or_init(|| {
+        if cfg!(all(target_arch = "wasm32", not(target_feature = "atomics"))) || env::var_os(SEQUENTIAL_ENV).is_some_and(|v| v == "1") {
+            return false;
+        }
+
+        // Rayon spawns its threads like this on its first use, and panics if it can't
+        thread::Builder::new().name("rand_key probe".into())
+                              .spawn(|| ())
+                              .is_ok_and(|probe| probe.join().is_ok())
+    })
+}
+
+
+/// Return how many threads pick the units, one without parallelism
+#[inline]
+pub(crate) fn _THREADS() -> usize { if parallel_available() { rayon::current_num_threads() } else { 1 } }
@@ -0,0 +1,128 @@
+//! Memorable passphrases of random words.
+//!
+//! The words are lowercase ASCII, without diacritics, so they are typed the same on any keyboard.
+use {
+    rand::{thread_rng, seq::SliceRandom},
+    crate::GenError,
+};
+
+
+
+
+/// The languages of the built-in wordlists, each but English behind the feature `lang-<code>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    #[cfg(feature = "lang-de")]
+    German,
+    #[cfg(feature = "lang-fr")]
+    French,
+    #[cfg(feature = "lang-es")]
+    Spanish,
+    #[cfg(feature = "lang-it")]
+    Italian,
+}
+
+
+impl Language {
+    /// Return the built-in wordlist of the language, 256 words
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::passphrase::Language;
+    ///
+    /// assert_eq!(Language::English.wordlist().len(), 256);
+    /// ```
+    #[inline]
+    pub fn wordlist(self) -> Vec<&'static str> {
+        let words = match self {
+            Language::English => include_str!("wordlists/en.txt"),
+            #[cfg(feature = "lang-de")]
+            Language::German => include_str!("wordlists/de.txt"),
+            #[cfg(feature = "lang-fr")]
+            Language::French => include_str!("wordlists/fr.txt"),
+            #[cfg(feature = "lang-es")]
+            Language::Spanish => include_str!("wordlists/es.txt"),
+            #[cfg(feature = "lang-it")]
+            Language::Italian => include_str!("wordlists/it.txt"),
+        };
+
+        words.lines().collect()
+    }
+}
+
+
+/// A generator of passphrases, `count` words picked from a wordlist and joined by a separator
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::passphrase::{Passphrase, Language};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let gen = Passphrase::new(6).language(Language::English).separator("-");
+/// assert_eq!(gen.entropy_bits(), 48.0);
+///
+/// let phrase = gen.generate()?;
+/// assert_eq!(phrase.split('-').count(), 6);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Passphrase {
+    words:            Vec<String>,
+    pub(crate) count: usize,
+    separator:        String,
+}
+
+
+impl Passphrase {
+    /// Return a generator of `count` English words separated by spaces
+    #[inline]
+    pub fn new(count: usize) -> Self { Passphrase { words: vec![], count, separator: " ".into() }.language(Language::English) }
+
+    /// Pick the words from the built-in wordlist of `language`
+    #[inline]
+    pub fn language(self, language: Language) -> Self { self.wordlist(language.wordlist()) }
+
+    /// Pick the words from `words`, check a custom list with [`wordlist::validate`](crate::wordlist::validate) first
+    #[inline]
+    pub fn wordlist(mut self, words: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.words = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Join the words by `separator`
+    #[inline]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Return how many words a passphrase has
+    #[inline]
+    pub fn word_count(&self) -> usize { self.count }
+
+    /// Return the entropy of a passphrase in bits, assuming the words are distinct
+    #[inline]
+    pub fn entropy_bits(&self) -> f64 {
+        if self.words.is_empty() {
+            0.0
+        } else {
+            self.count as f64 * (self.words.len() as f64).log2()
+        }
+    }
+
+    /// Generate a passphrase, return `GenError::MissChar` if the wordlist is empty
+    #[inline]
+    pub fn generate(&self) -> Result<String, GenError> {
+        let mut rng = thread_rng();
+
+        let words = (0..self.count).map(|_| self.words.choose(&mut rng).map(String::as_str))
+                                   .collect::<Option<Vec<_>>>()
+                                   .ok_or(GenError::MissChar)?;
+
+        Ok(words.join(&self.separator))
+    }
+}
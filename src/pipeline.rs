@@ -0,0 +1,168 @@
+//! Transforms of the generated keys, chained in a [`Pipeline`] which keeps the counts of the key right.
+use crate::{RandKey, GenError, SetRandKeyOp, checksum::Checksum};
+
+
+
+
+/// A change of a generated key
+///
+/// The closures from `&str` to `Result<String, GenError>` are transforms too.
+pub trait KeyTransform {
+    /// Return the transformed key
+    fn apply(&self, key: &str) -> Result<String, GenError>;
+}
+
+
+impl<F: Fn(&str) -> Result<String, GenError>> KeyTransform for F {
+    #[inline]
+    fn apply(&self, key: &str) -> Result<String, GenError> { self(key) }
+}
+
+
+/// Spell some letters in digits, `a` as `4`, `e` as `3`, `i` as `1`, `o` as `0`, `s` as `5` and `t` as `7`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Leetify;
+
+
+impl KeyTransform for Leetify {
+    #[inline]
+    fn apply(&self, key: &str) -> Result<String, GenError> {
+        Ok(key.chars()
+              .map(|c| match c.to_ascii_lowercase() {
+                  'a' => '4',
+                  'e' => '3',
+                  'i' => '1',
+                  'o' => '0',
+                  's' => '5',
+                  't' => '7',
+                  _ => c,
+              })
+              .collect())
+    }
+}
+
+
+/// Append the check digit of the key, which has to be made of letters and digits
+#[derive(Clone, Copy, Debug)]
+pub struct AppendCheckDigit(pub Checksum);
+
+
+impl KeyTransform for AppendCheckDigit {
+    #[inline]
+    fn apply(&self, key: &str) -> Result<String, GenError> { Ok(format!("{}{}", key, self.0.digit(key)?)) }
+}
+
+
+/// Split the key in groups of `size` characters, joined by `separator`
+#[derive(Clone, Copy, Debug)]
+pub struct Group {
+    pub size:      usize,
+    pub separator: char,
+}
+
+
+impl KeyTransform for Group {
+    #[inline]
+    fn apply(&self, key: &str) -> Result<String, GenError> {
+        if self.size == 0 {
+            return Err(GenError::InvalidUnit);
+        }
+
+        let chars: Vec<char> = key.chars().collect();
+        let groups: Vec<String> = chars.chunks(self.size).map(|g| g.iter().collect()).collect();
+
+        Ok(groups.join(&self.separator.to_string()))
+    }
+}
+
+
+/// Put the letters of the key in one case
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaseFold {
+    Upper,
+    Lower,
+}
+
+
+impl KeyTransform for CaseFold {
+    #[inline]
+    fn apply(&self, key: &str) -> Result<String, GenError> {
+        Ok(match self {
+            CaseFold::Upper => key.to_uppercase(),
+            CaseFold::Lower => key.to_lowercase(),
+        })
+    }
+}
+
+
+/// Transforms applied in turn to the generated keys
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, Pipeline, pipeline::{Leetify, Group, CaseFold}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pipeline = Pipeline::new().then(CaseFold::Upper).then(Group { size: 4, separator: '-' });
+/// assert_eq!(pipeline.apply("abcdefgh12")?, "ABCD-EFGH-12");
+///
+/// let r_p = RandKey::new("12", "0", "4")?;
+/// let grouped = r_p.join_through(&Pipeline::new().then(Leetify).then(Group { size: 4, separator: '-' }))?;
+/// assert_eq!(grouped.key().len(), 19);
+/// // The counts are the ones of the transformed key
+/// assert_eq!(grouped.get_cnt(rand_key::ASCIIExcludeCtrl::Punctuation), "3");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    transforms: Vec<Box<dyn KeyTransform>>,
+}
+
+
+impl Pipeline {
+    /// Return a pipeline without transforms, which leaves the keys as they are
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Add `transform` after the others
+    #[inline]
+    pub fn then(mut self, transform: impl KeyTransform + 'static) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+
+    /// Return how many transforms there are
+    #[inline]
+    pub fn len(&self) -> usize { self.transforms.len() }
+
+    /// Check whether there is no transform
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.transforms.is_empty() }
+
+    /// Return `key` transformed by all the transforms in turn
+    #[inline]
+    pub fn apply(&self, key: &str) -> Result<String, GenError> {
+        self.transforms.iter().try_fold(key.to_string(), |key, t| t.apply(&key))
+    }
+}
+
+
+impl RandKey {
+    /// Generate a key like [`join`](RandKey::join), and return a copy of this `RandKey`
+    /// with the key transformed by `pipeline`, and the counts of the transformed key.
+    ///
+    /// This `RandKey` is left with the key before the transforms and its own counts,
+    /// so it generates the same kind of keys next time.
+    ///
+    /// Return `GenError::InvalidChar` if a transform put other characters than ASCII in the key.
+    #[inline]
+    pub fn join_through(&self, pipeline: &Pipeline) -> Result<RandKey, GenError> {
+        self.join()?;
+
+        let mut transformed = self.clone();
+        transformed.set_key_owned(pipeline.apply(&self.key())?, SetRandKeyOp::Update)?;
+
+        Ok(transformed)
+    }
+}
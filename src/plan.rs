@@ -0,0 +1,161 @@
+use {
+    std::fmt::{self, Display, Formatter},
+    crate::{
+        RandKey, GenError, PoolWarning, Strategy,
+        parallel::_THREADS,
+        utils::{ToPrimitive, _COUNTS, _SHORT_KEY, _CHAR_FROM_STR},
+    },
+};
+
+
+
+
+/// How the characters of one kind are split into units
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KindPlan {
+    /// How many characters of the kind are picked
+    pub count:     usize,
+    /// How many units pick them
+    pub units:     usize,
+    /// How many characters each unit picks, but the last one
+    pub unit_size: usize,
+    /// How many characters the last unit picks
+    pub last_unit: usize,
+}
+
+
+impl KindPlan {
+    /// Return the split of `count` characters into units of `unit`
+    #[inline]
+    fn split(count: usize, unit: usize) -> Self {
+        match count {
+            0 => KindPlan::default(),
+            _ => KindPlan { count, units: count.div_ceil(unit), unit_size: unit.min(count), last_unit: (count - 1) % unit + 1 },
+        }
+    }
+}
+
+
+/// How [`join`](RandKey::join) would generate the key, returned by [`RandKey::plan`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GenerationPlan {
+    /// Length of the key, with the check digit
+    pub chars:    usize,
+    /// The strategy picking the characters, the one `Strategy::Auto` picks for the key
+    pub strategy: Strategy,
+    /// The splits of the letters, the symbols and the numbers
+    pub kinds:    [KindPlan; 3],
+    /// How many units pick the characters, the other strategies than the shuffle pick them in one
+    pub units:    usize,
+    /// How many threads of the rayon pool would pick them at once, only the shuffle picks in parallel
+    pub threads:  usize,
+    /// Whether the short key is picked on the stack, with the key as the only allocation
+    pub on_stack: bool,
+    /// Bytes allocated by the generation, the buffer of the picked characters and the key
+    pub memory:   usize,
+    /// The counts which greatly exceed the size of their pool
+    pub warnings: Vec<PoolWarning>,
+}
+
+
+impl Display for GenerationPlan {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+               "{} chars in {} units ({}/{}/{}) on {} threads, {} bytes{}",
+               self.chars,
+               self.units,
+               self.kinds[0].units,
+               self.kinds[1].units,
+               self.kinds[2].units,
+               self.threads,
+               self.memory,
+               if self.on_stack { " on the stack" } else { "" })
+    }
+}
+
+
+impl RandKey {
+    /// Return how [`join`](RandKey::join) would split the work, without generating anything
+    ///
+    /// Return the errors `join` would return before picking any character,
+    /// like `GenError::AllocationFailed` if the key takes more bytes than fit in `usize`.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("100000", "10", "2500")?;
+    /// r_p.set_unit("1000")?;
+    ///
+    /// let plan = r_p.plan()?;
+    /// assert_eq!(plan.chars, 102510);
+    /// assert_eq!(plan.units, 100 + 1 + 3);
+    /// assert_eq!(plan.kinds[2].last_unit, 500);
+    /// assert!(!plan.on_stack);
+    /// println!("{}", plan);
+    ///
+    /// // The key isn't generated
+    /// assert_eq!(r_p.key(), "");
+    ///
+    /// // Nor planned if it can't be
+    /// let r_p = RandKey::new("10000000000000000000", "0", "0")?;
+    /// assert!(matches!(r_p.plan(), Err(rand_key::GenError::AllocationFailed)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn plan(&self) -> Result<GenerationPlan, GenError> {
+        self.check_entropy()?;
+        let warnings = self.check_pools()?;
+
+        let counts = _COUNTS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM)?;
+
+        match self.STRATEGY {
+            Strategy::Unique | Strategy::Coverage if self.CHECKSUM.is_some() => return Err(GenError::InconsistentField),
+            Strategy::Streaming if !self.RULES.is_empty() => return Err(GenError::InconsistentField),
+            _ => {}
+        }
+
+        let extra = self.CHECKSUM.map_or(0, |_| 1);
+        let total = counts.iter().try_fold(extra, |acc: usize, c| acc.checked_add(*c)).ok_or(GenError::AllocationFailed)?;
+        let ascii = self.DATA.iter().flatten().all(|s| s.is_ascii());
+
+        let unit = self.UNIT.borrow().to_usize().unwrap_or(usize::MAX).max(1);
+        let (strategy, unit) = self.STRATEGY.resolve(total - extra, unit, !self.RULES.is_empty());
+
+        // Only the shuffle splits the counts, the other strategies pick each kind at once
+        let unit = if strategy == Strategy::Shuffle { unit } else { usize::MAX };
+        let kinds = [KindPlan::split(counts[0], unit), KindPlan::split(counts[1], unit), KindPlan::split(counts[2], unit)];
+        let units = kinds.iter().map(|k| k.units).sum::<usize>().max(1);
+
+        let on_stack = ascii && strategy == Strategy::Shuffle && self.RULES.is_empty() && total - extra <= _SHORT_KEY;
+
+        // The counts may be as large as `usize`, the bytes they take then don't fit in it
+        let key_bytes = if ascii {
+            Some(total)
+        } else {
+            counts.iter()
+                  .zip(&self.DATA)
+                  .try_fold(extra, |acc, (count, pool)| {
+                      count.checked_mul(pool.iter().map(|s| _CHAR_FROM_STR(s).len_utf8()).max().unwrap_or(0))
+                           .and_then(|bytes| acc.checked_add(bytes))
+                  })
+        };
+        let buf_bytes = if on_stack { Some(0) } else { total.checked_mul(if ascii { 1 } else { std::mem::size_of::<char>() }) };
+        let memory = key_bytes.zip(buf_bytes).and_then(|(key, buf)| key.checked_add(buf)).ok_or(GenError::AllocationFailed)?;
+
+        Ok(GenerationPlan {
+            chars: total,
+            strategy,
+            kinds,
+            units: if on_stack || strategy != Strategy::Shuffle { 1 } else { units },
+            threads: if on_stack || strategy != Strategy::Shuffle { 1 } else { units.min(_THREADS()) },
+            on_stack,
+            memory,
+            warnings,
+        })
+    }
+}
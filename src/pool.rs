@@ -0,0 +1,168 @@
+use {
+    std::{collections::VecDeque, sync::Arc, thread::{self, JoinHandle}},
+    parking_lot::{Condvar, Mutex},
+    zeroize::Zeroizing,
+    crate::{RandKey, GenError},
+};
+
+
+
+
+#[derive(Debug, Default)]
+struct _Keys {
+    ready:   VecDeque<Zeroizing<String>>,
+    stopped: bool,
+}
+
+
+#[derive(Debug)]
+struct _Shared {
+    keys:     Mutex<_Keys>,
+    /// Wakes the refilling thread up once a key is taken, or the pool is dropped
+    taken:    Condvar,
+    capacity: usize,
+}
+
+
+/// Keys generated ahead of time by a background thread, to hand them out without waiting for a generation
+///
+/// The thread refills the pool as soon as a key is taken, up to its capacity.
+/// The keys waiting in the pool are zeroized once they are dropped, as are the ones handed out.
+/// They are never stored in the profile, and the buffers they are generated in are wiped.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, KeyPool};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = KeyPool::new(RandKey::new("10", "2", "4")?, 8)?;
+/// let key = pool.take()?;
+/// assert_eq!(key.len(), 16);
+///
+/// // Every key is handed out once
+/// let keys: Vec<_> = (0..20).map(|_| pool.take()).collect::<Result<_, _>>()?;
+/// assert!(keys.iter().all(|k| *k != key));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct KeyPool {
+    shared:  Arc<_Shared>,
+    /// Generates the keys when the pool is empty, rather than waiting for the thread
+    profile: Mutex<RandKey>,
+    refill:  Option<JoinHandle<()>>,
+}
+
+
+impl KeyPool {
+    /// Return a pool of `capacity` keys of `profile`, and start the thread filling it
+    ///
+    /// The first key is generated right away to check the profile, and the others in the background.
+    /// Return the error of the generation if it fails, and `GenError::WorkerUnavailable` if the thread can't be spawned.
+    #[inline]
+    pub fn new(profile: RandKey, capacity: usize) -> Result<Self, GenError> {
+        let first = Zeroizing::new(profile.generate_detached()?.0);
+
+        let shared = Arc::new(_Shared {
+            keys:     Mutex::new(_Keys { ready: VecDeque::with_capacity(capacity), stopped: false }),
+            taken:    Condvar::new(),
+            capacity,
+        });
+        if capacity > 0 {
+            shared.keys.lock().ready.push_back(first);
+        }
+
+        let worker = profile.clone();
+        let refill_shared = Arc::clone(&shared);
+        let refill = thread::Builder::new().name("rand_key-pool".into())
+                                           .spawn(move || _REFILL(&refill_shared, &worker))
+                                           .map_err(|e| GenError::WorkerUnavailable(e.to_string()))?;
+
+        Ok(KeyPool { shared, profile: Mutex::new(profile), refill: Some(refill) })
+    }
+
+    /// Return a key of the pool, or a key generated on the spot if the pool is empty
+    ///
+    /// The key is recorded in the audit log once it's handed out, on the thread taking it.
+    #[inline]
+    pub fn take(&self) -> Result<Zeroizing<String>, GenError> {
+        match self.try_take()? {
+            Some(key) => Ok(key),
+            None => {
+                let profile = self.profile.lock();
+                let key = Zeroizing::new(profile.generate_detached()?.0);
+
+                #[cfg(feature = "audit-log")]
+                crate::audit_log::_RECORD(|| profile.config(), &key)?;
+
+                Ok(key)
+            }
+        }
+    }
+
+    /// Return a key of the pool, `None` if it's empty
+    ///
+    /// Return the error of the audit log if the key can't be recorded, the key is dropped then.
+    #[inline]
+    pub fn try_take(&self) -> Result<Option<Zeroizing<String>>, GenError> {
+        let key = self.shared.keys.lock().ready.pop_front();
+        self.shared.taken.notify_one();
+
+        #[cfg(feature = "audit-log")]
+        if let Some(key) = &key {
+            crate::audit_log::_RECORD(|| self.profile.lock().config(), key)?;
+        }
+
+        Ok(key)
+    }
+
+    /// Return how many keys are ready
+    #[inline]
+    pub fn len(&self) -> usize { self.shared.keys.lock().ready.len() }
+
+    /// Check whether no key is ready
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Return how many keys the pool holds once it's full
+    #[inline]
+    pub fn capacity(&self) -> usize { self.shared.capacity }
+}
+
+
+/// Fill the pool with the keys of `profile`, until it's dropped or a generation fails
+#[inline]
+fn _REFILL(shared: &_Shared, profile: &RandKey) {
+    loop {
+        {
+            let mut keys = shared.keys.lock();
+            while keys.ready.len() >= shared.capacity && !keys.stopped {
+                shared.taken.wait(&mut keys);
+            }
+            if keys.stopped {
+                return;
+            }
+        }
+
+        // Generate without the lock so that the keys are taken meanwhile
+        match profile.generate_detached() {
+            Ok((key, _)) => shared.keys.lock().ready.push_back(Zeroizing::new(key)),
+            // `take` generates the keys itself from then on, and returns the error
+            Err(_) => return,
+        }
+    }
+}
+
+
+impl Drop for KeyPool {
+    #[inline]
+    fn drop(&mut self) {
+        self.shared.keys.lock().stopped = true;
+        self.shared.taken.notify_one();
+
+        if let Some(refill) = self.refill.take() {
+            let _ = refill.join();
+        }
+    }
+}
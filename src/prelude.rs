@@ -1,12 +1,14 @@
 use {
     std::{
         cell::RefCell,
+        sync::Arc,
         fmt::{self, Display, Formatter},
     },
     crate::{
         error::GenError,
-        RandKey, ToRandKey,
+        RandKey, ToRandKey, Strategy, SeedStrategy, PoolCheck, DefaultClassifier,
         SetRandKeyOp::Update,
+        config::_GLOBAL_DEFAULTS,
         utils::{_DEFAULT_DATA, BigUint},
     },
 };
@@ -17,6 +19,14 @@ use {
 pub(crate) const _DEFAULT_UNIT: usize = 2 << 19;
 
 
+/// The default counts of letters, symbols and numbers
+pub(crate) const _DEFAULT_CNTS: [usize; 3] = [12, 2, 3];
+
+
+#[cfg(feature = "spill")]
+pub(crate) const _DEFAULT_SPILL: usize = 2 << 25;
+
+
 pub trait AsBiguint {
     type Output;
     fn as_biguint(&self) -> Self::Output;
@@ -39,17 +49,56 @@ impl<T: AsRef<str>> AsBiguint for T {
 
 
 impl Default for RandKey {
-    /// The default value of `RandKey`
+    /// The default value of `RandKey`, or the one set by [`set_global_defaults`](crate::set_global_defaults)
+    ///
+    /// Without global defaults, it generates 12 letters, 2 symbols and 3 numbers from the default data.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(Default)]
+    /// struct Account {
+    ///     name: String,
+    ///     key:  RandKey,
+    /// }
+    ///
+    /// let account = Account::default();
+    /// assert!(account.name.is_empty());
+    /// assert_eq!(account.key.generate()?.len(), 17);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
     fn default() -> Self {
-        RandKey {
-            ltr_cnt: Default::default(),
-            sbl_cnt: Default::default(),
-            num_cnt: Default::default(),
-            key:     Default::default(),
+        let mut r_p = RandKey {
+            ltr_cnt: BigUint::from(_DEFAULT_CNTS[0]),
+            sbl_cnt: BigUint::from(_DEFAULT_CNTS[1]),
+            num_cnt: BigUint::from(_DEFAULT_CNTS[2]),
+            key:     RefCell::default(),
             UNIT:    RefCell::new(BigUint::from(_DEFAULT_UNIT)),
             DATA:    _DEFAULT_DATA(),
+            CHECKSUM: None,
+            EXPIRY:  None,
+            METADATA: Default::default(),
+            SEED:    None,
+            STRATEGY: Strategy::default(),
+            SEEDING: SeedStrategy::default(),
+            RULES:   Vec::new(),
+            MIN_ENTROPY: 0.0,
+            POOL_CHECK: PoolCheck::default(),
+            CLASSIFIER: Arc::new(DefaultClassifier),
+            #[cfg(feature = "spill")]
+            SPILL:   RefCell::new(BigUint::from(_DEFAULT_SPILL)),
+        };
+
+        if let Some(config) = _GLOBAL_DEFAULTS() {
+            r_p.apply_config(&config);
         }
+
+        r_p
     }
 }
 
@@ -0,0 +1,164 @@
+//! Ready-made settings for the common kinds of keys.
+use {
+    std::str::FromStr,
+    crate::{RandKey, GenError},
+};
+
+
+
+
+/// Built-in settings, parsed from their lowercase names like `"pin"`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preset {
+    /// 6 digits
+    Pin,
+    /// 16 letters and 4 digits
+    Alphanumeric,
+    /// 12 letters, 2 symbols and 3 digits
+    Standard,
+    /// 24 letters, 4 symbols and 4 digits
+    Strong,
+    /// A WPA2/WPA3 passphrase of 18 letters, 2 symbols and 4 digits, without the ambiguous characters
+    Wpa,
+}
+
+
+/// The characters `Preset::Wpa` leaves out, which look alike or need escaping in the configs
+pub const WPA_EXCLUDED: &[&str] = &["0", "O", "1", "l", "I", "\"", "'", "`", "\\"];
+
+
+impl Preset {
+    /// Return an empty `RandKey` with the settings of the preset
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::presets::Preset;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "pin".parse::<Preset>()?.to_randkey();
+    /// r_p.join()?;
+    /// assert!(r_p.key().chars().all(|c| c.is_ascii_digit()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn to_randkey(self) -> RandKey {
+        let (l, s, n) = match self {
+            Preset::Pin => ("0", "0", "6"),
+            Preset::Alphanumeric => ("16", "0", "4"),
+            Preset::Standard => ("12", "2", "3"),
+            Preset::Strong => ("24", "4", "4"),
+            Preset::Wpa => ("18", "2", "4"),
+        };
+
+        let mut r_p = RandKey::new(l, s, n).unwrap();
+        if self == Preset::Wpa {
+            r_p.del_item(WPA_EXCLUDED).unwrap();
+        }
+
+        r_p
+    }
+}
+
+
+impl FromStr for Preset {
+    type Err = GenError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, GenError> {
+        match s {
+            "pin" => Ok(Preset::Pin),
+            "alphanumeric" => Ok(Preset::Alphanumeric),
+            "standard" => Ok(Preset::Standard),
+            "strong" => Ok(Preset::Strong),
+            "wpa" => Ok(Preset::Wpa),
+            _ => Err(GenError::UnknownPreset(s.into())),
+        }
+    }
+}
+
+
+/// Return `GenError::InvalidPassphrase` unless `passphrase` is a valid WPA passphrase,
+/// i.e. 8 to 63 printable ASCII characters
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::presets::{Preset, check_wpa_passphrase};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// check_wpa_passphrase(&Preset::Wpa.to_randkey().generate()?)?;
+/// assert!(check_wpa_passphrase("short").is_err());
+/// assert!(check_wpa_passphrase("tab\tinside").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn check_wpa_passphrase(passphrase: &str) -> Result<(), GenError> {
+    let printable = passphrase.bytes().all(|b| (b' '..=b'~').contains(&b));
+
+    if printable && (8..=63).contains(&passphrase.len()) {
+        Ok(())
+    } else {
+        Err(GenError::InvalidPassphrase)
+    }
+}
+
+
+/// Return the SSID for the configs, quoted if it's printable, in hex otherwise
+#[inline]
+fn _SSID(ssid: &str) -> String {
+    if ssid.bytes().all(|b| (b' '..=b'~').contains(&b) && b != b'"') {
+        format!("\"{}\"", ssid)
+    } else {
+        ssid.bytes().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
+
+/// Return the `network` block of `wpa_supplicant.conf` joining `ssid` with `passphrase`,
+/// in WPA2/WPA3 transition mode
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::presets::wpa_supplicant_conf;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conf = wpa_supplicant_conf("home", "correct-horse-battery")?;
+/// assert!(conf.contains("\tssid=\"home\"\n\tpsk=\"correct-horse-battery\"\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn wpa_supplicant_conf(ssid: &str, passphrase: &str) -> Result<String, GenError> {
+    check_wpa_passphrase(passphrase)?;
+
+    Ok(format!("network={{\n\tssid={}\n\tpsk=\"{}\"\n\tkey_mgmt=WPA-PSK SAE\n\tieee80211w=1\n}}\n",
+               _SSID(ssid),
+               passphrase))
+}
+
+
+/// Return the lines of `hostapd.conf` serving `ssid` with `passphrase`, in WPA2/WPA3 transition mode
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::presets::hostapd_conf;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conf = hostapd_conf("home", "correct-horse-battery")?;
+/// assert!(conf.starts_with("ssid2=\"home\"\nwpa=2\nwpa_passphrase=correct-horse-battery\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn hostapd_conf(ssid: &str, passphrase: &str) -> Result<String, GenError> {
+    check_wpa_passphrase(passphrase)?;
+
+    Ok(format!("ssid2={}\nwpa=2\nwpa_passphrase={}\nwpa_key_mgmt=WPA-PSK SAE\nrsn_pairwise=CCMP\nieee80211w=1\n",
+               _SSID(ssid),
+               passphrase))
+}
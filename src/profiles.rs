@@ -0,0 +1,247 @@
+//! Named generation settings, saved as JSON in the user's config directory, or in any [`Storage`].
+use {
+    serde::{Serialize, Deserialize},
+    crate::{RandKey, GenError, RandKeyConfig, checksum::Checksum, storage::{Storage, FileStorage}},
+    std::{env, path::PathBuf},
+};
+
+
+
+
+/// Saved settings, only overriding the ones of its `base` profile
+///
+/// The fields left `None` come from the base, or from `RandKey::default()` without a base.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// Name of the profile to inherit from
+    pub base:     Option<String>,
+    pub ltr_cnt:  Option<String>,
+    pub sbl_cnt:  Option<String>,
+    pub num_cnt:  Option<String>,
+    pub unit:     Option<String>,
+    pub data:     Option<Vec<Vec<String>>>,
+    /// Characters removed from the inherited data
+    pub exclude:  Vec<String>,
+    pub checksum: Option<Checksum>,
+}
+
+
+impl Profile {
+    /// Apply the overrides of the profile on `base`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, profiles::Profile};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let base = RandKey::new("10", "2", "3")?.config();
+    /// let delta = Profile { num_cnt: Some("6".into()), exclude: vec!["0".into(), "O".into()], ..Default::default() };
+    ///
+    /// let merged = delta.merge(base);
+    /// assert_eq!(merged.ltr_cnt, "10");
+    /// assert_eq!(merged.num_cnt, "6");
+    /// assert!(!merged.data.concat().contains(&"0".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn merge(&self, mut base: RandKeyConfig) -> RandKeyConfig {
+        let fields = [
+            (&self.ltr_cnt, &mut base.ltr_cnt),
+            (&self.sbl_cnt, &mut base.sbl_cnt),
+            (&self.num_cnt, &mut base.num_cnt),
+            (&self.unit, &mut base.unit),
+        ];
+        for (over, field) in fields {
+            if let Some(val) = over {
+                *field = val.clone();
+            }
+        }
+
+        if let Some(data) = &self.data {
+            base.data = data.clone();
+        }
+        base.data.iter_mut().for_each(|v| v.retain(|c| !self.exclude.contains(c)));
+
+        if self.checksum.is_some() {
+            base.checksum = self.checksum;
+        }
+
+        base
+    }
+}
+
+
+impl From<RandKeyConfig> for Profile {
+    #[inline]
+    fn from(config: RandKeyConfig) -> Self {
+        Profile {
+            base:     None,
+            ltr_cnt:  Some(config.ltr_cnt),
+            sbl_cnt:  Some(config.sbl_cnt),
+            num_cnt:  Some(config.num_cnt),
+            unit:     Some(config.unit),
+            data:     Some(config.data),
+            exclude:  vec![],
+            checksum: config.checksum,
+        }
+    }
+}
+
+
+/// Named `RandKey` settings, in the files of a directory unless given another storage
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Registry<S: Storage = FileStorage> {
+    storage: S,
+}
+
+
+impl Registry {
+    /// Return the registry in `$XDG_CONFIG_HOME/rand_key/profiles`,
+    /// falling back to `~/.config` without `XDG_CONFIG_HOME` and to `%APPDATA%` on Windows.
+    #[inline]
+    pub fn new() -> Result<Self, GenError> {
+        let base = env::var_os("XDG_CONFIG_HOME")
+                       .map(PathBuf::from)
+                       .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+                       .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+                       .ok_or_else(|| GenError::InvalidProfile("no config directory".into()))?;
+
+        Ok(Registry::at(base.join("rand_key").join("profiles")))
+    }
+
+    /// Return the registry in `dir`
+    #[inline]
+    pub fn at(dir: impl Into<PathBuf>) -> Self { Registry { storage: FileStorage::new(dir) } }
+
+    /// Return the directory of the registry
+    #[inline]
+    pub fn dir(&self) -> &PathBuf { self.storage.dir() }
+}
+
+
+impl<S: Storage> Registry<S> {
+    /// Return the registry saving the settings in `storage`, like the database of a server
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, profiles::Registry, storage::MemoryStorage};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = Registry::with_storage(MemoryStorage::new());
+    /// registry.save("pin", &RandKey::new("0", "0", "6")?)?;
+    ///
+    /// assert_eq!(registry.list()?, vec!["pin"]);
+    /// assert_eq!(registry.load_config("pin")?.num_cnt, "6");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn with_storage(storage: S) -> Self { Registry { storage } }
+
+    /// Return the storage of the registry
+    #[inline]
+    pub fn storage(&self) -> &S { &self.storage }
+
+    #[inline]
+    fn check_name(name: &str) -> Result<&str, GenError> {
+        if name.is_empty() || name.starts_with('.') || name.contains(&['/', '\\'][..]) {
+            Err(GenError::InvalidProfile(name.into()))
+        } else {
+            Ok(name)
+        }
+    }
+
+    /// Save the settings of `r_p` as `name`, replacing the old ones
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, profiles::Registry};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = Registry::at(std::env::temp_dir().join("rand_key_registry_doc"));
+    /// let r_p = RandKey::new("20", "4", "4")?;
+    /// registry.save("admin-keys", &r_p)?;
+    ///
+    /// let loaded = registry.load("admin-keys")?;
+    /// assert_eq!(loaded.config(), r_p.config());
+    /// assert!(registry.list()?.contains(&"admin-keys".to_string()));
+    /// # registry.remove("admin-keys")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn save(&self, name: &str, r_p: &RandKey) -> Result<(), GenError> { self.save_config(name, &r_p.config()) }
+
+    /// Save `config` as `name`, replacing the old one
+    #[inline]
+    pub fn save_config(&self, name: &str, config: &RandKeyConfig) -> Result<(), GenError> {
+        self.save_profile(name, &config.clone().into())
+    }
+
+    /// Save `profile` as `name`, replacing the old one
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, profiles::{Registry, Profile}};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = Registry::at(std::env::temp_dir().join("rand_key_inherit_doc"));
+    /// registry.save("base", &RandKey::new("16", "2", "2")?)?;
+    /// registry.save_profile("db", &Profile { base: Some("base".into()), unit: Some("64".into()), ..Default::default() })?;
+    ///
+    /// let db = registry.load_config("db")?;
+    /// assert_eq!((db.ltr_cnt.as_str(), db.unit.as_str()), ("16", "64"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn save_profile(&self, name: &str, profile: &Profile) -> Result<(), GenError> {
+        let name = Self::check_name(name)?;
+        let json = serde_json::to_string_pretty(profile).map_err(|e| GenError::InvalidProfile(e.to_string()))?;
+
+        self.storage.write(name, &json)
+    }
+
+    /// Return the profile saved as `name`, without resolving its base
+    #[inline]
+    pub fn load_profile(&self, name: &str) -> Result<Profile, GenError> {
+        let json = self.storage.read(Self::check_name(name)?)?;
+        serde_json::from_str(&json).map_err(|e| GenError::InvalidProfile(format!("{}: {}", name, e)))
+    }
+
+    /// Return an empty `RandKey` with the settings saved as `name`
+    #[inline]
+    pub fn load(&self, name: &str) -> Result<RandKey, GenError> { RandKey::from_config(&self.load_config(name)?) }
+
+    /// Return the settings saved as `name`, with the ones of its bases applied first
+    #[inline]
+    pub fn load_config(&self, name: &str) -> Result<RandKeyConfig, GenError> {
+        let mut chain = vec![name.to_string()];
+        let mut profiles = vec![self.load_profile(name)?];
+
+        while let Some(base) = profiles.last().unwrap().base.clone() {
+            if chain.contains(&base) {
+                chain.push(base);
+                return Err(GenError::InvalidProfile(format!("inheritance cycle {}", chain.join(" -> "))));
+            }
+            profiles.push(self.load_profile(&base)?);
+            chain.push(base);
+        }
+
+        Ok(profiles.iter().rev().fold(RandKey::default().config(), |config, p| p.merge(config)))
+    }
+
+    /// Return the names of the saved settings, in alphabetical order
+    #[inline]
+    pub fn list(&self) -> Result<Vec<String>, GenError> { self.storage.list() }
+
+    /// Remove the settings saved as `name`
+    #[inline]
+    pub fn remove(&self, name: &str) -> Result<(), GenError> { self.storage.remove(Self::check_name(name)?) }
+}
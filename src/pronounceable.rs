@@ -0,0 +1,95 @@
+//! Pronounceable keys made of syllables.
+//!
+//! A syllable is an onset, a vowel and a coda picked from the tables of [`SyllableRules`],
+//! which are public so the clusters awkward in a language can be left out.
+use {
+    rand::{thread_rng, Rng, seq::SliceRandom},
+    crate::GenError,
+};
+
+
+
+
+/// The tables and the length rules of the syllables
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::pronounceable::SyllableRules;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut rules = SyllableRules::default();
+/// // No "th", and no coda at all
+/// rules.onsets.retain(|o| o != "th");
+/// rules.codas = vec!["".into()];
+/// rules.syllables = 4..=4;
+///
+/// let key = rules.generate()?;
+/// assert!(!key.contains("th"));
+/// println!("{} ({:.0} bits)", key, rules.entropy_bits());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyllableRules {
+    /// The consonants and clusters starting a syllable, `""` for none
+    pub onsets:    Vec<String>,
+    /// The vowels and diphthongs in the middle of a syllable
+    pub vowels:    Vec<String>,
+    /// The consonants and clusters ending a syllable, `""` for none
+    pub codas:     Vec<String>,
+    /// How many syllables a key has
+    pub syllables: std::ops::RangeInclusive<usize>,
+}
+
+
+/// Split a table written with spaces
+#[inline]
+fn _TABLE(s: &str) -> Vec<String> { s.split(' ').map(String::from).collect() }
+
+
+impl Default for SyllableRules {
+    /// Tables which read well in English, and 4 to 6 syllables
+    #[inline]
+    fn default() -> Self {
+        SyllableRules {
+            onsets:    _TABLE("b bl br c ch cl cr d dr f fl fr g gl gr h j k l m n p pl pr qu r s sh sl sm sn sp st t th tr v w z"),
+            vowels:    _TABLE("a e i o u ai ea ee oo ou"),
+            codas:     _TABLE(" l m n r s t ck nd ng nt rt st"),
+            syllables: 4..=6,
+        }
+    }
+}
+
+
+impl SyllableRules {
+    /// Return how many bits of entropy a key has at least, the one of the fewest syllables
+    ///
+    /// Different choices may spell the same, like `ch` + `a` and `c` + `ha`, so this is an upper bound of the shortest key.
+    #[inline]
+    pub fn entropy_bits(&self) -> f64 {
+        let choices = (self.onsets.len() * self.vowels.len() * self.codas.len()) as f64;
+
+        if choices == 0.0 { 0.0 } else { *self.syllables.start() as f64 * choices.log2() }
+    }
+
+    /// Generate a key, return `GenError::MissChar` if a table is empty or the length rule is
+    #[inline]
+    pub fn generate(&self) -> Result<String, GenError> {
+        let mut rng = thread_rng();
+
+        if self.syllables.is_empty() {
+            return Err(GenError::MissChar);
+        }
+        let syllables = rng.gen_range(*self.syllables.start(), *self.syllables.end() + 1);
+
+        let mut key = String::new();
+        for _ in 0..syllables {
+            for table in [&self.onsets, &self.vowels, &self.codas].iter() {
+                key.push_str(table.choose(&mut rng).ok_or(GenError::MissChar)?);
+            }
+        }
+
+        Ok(key)
+    }
+}
@@ -0,0 +1,207 @@
+//! Everything there is to know about the quality of a single key.
+use {
+    std::fmt::{self, Display, Formatter},
+    crate::{StrengthReport, audit::COMMON_PASSWORDS, strength::Strength},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// A weakness spotted in a key
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Pattern {
+    /// `len` times the same character from the `at`th character, like `aaa`
+    Repeat { at: usize, len: usize },
+    /// `len` consecutive characters from the `at`th character, like `abc` or `321`
+    Sequence { at: usize, len: usize },
+    /// The key is one of the [`COMMON_PASSWORDS`]
+    Common,
+}
+
+
+/// The rules a key has to follow
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Policy {
+    /// The lowest verdict accepted
+    pub min_strength: Strength,
+    /// How many kinds of characters the key contains at least
+    pub min_classes:  usize,
+    /// How many characters the key contains at least
+    pub min_length:   usize,
+    /// Whether the common passwords are rejected
+    pub reject_common: bool,
+}
+
+
+impl Default for Policy {
+    /// A `reasonable` key of the three kinds, and not a common password
+    #[inline]
+    fn default() -> Self { Policy { min_strength: Strength::Reasonable, min_classes: 3, min_length: 0, reject_common: true } }
+}
+
+
+/// Whether a key follows one rule of the [`Policy`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolicyResult {
+    /// The rule, like `"at least 3 kinds of characters"`
+    pub rule:   String,
+    /// Whether the key follows it
+    pub passed: bool,
+}
+
+
+/// The quality of a key, returned by [`analyze`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QualityReport {
+    /// How many characters the key has
+    pub length:   usize,
+    /// How many letters, symbols and numbers the key has
+    pub counts:   [usize; 3],
+    /// How many characters are neither letters, symbols nor numbers
+    pub others:   usize,
+    /// The estimated entropy of the key
+    pub entropy:  StrengthReport,
+    /// The weaknesses spotted in the key
+    pub patterns: Vec<Pattern>,
+    /// The result of each rule of the policy
+    pub policy:   Vec<PolicyResult>,
+}
+
+
+impl QualityReport {
+    /// Return `true` if the key follows every rule of the policy
+    #[inline]
+    pub fn passed(&self) -> bool { self.policy.iter().all(|r| r.passed) }
+}
+
+
+/// Return the runs of at least 3 repeated or consecutive characters
+#[inline]
+fn _PATTERNS(chars: &[char]) -> Vec<Pattern> {
+    let mut patterns = vec![];
+
+    for (step, is_sequence) in [(0, false), (1, true), (-1, true)].iter() {
+        let mut start = 0;
+
+        for i in 1..=chars.len() {
+            let continues = i < chars.len() && chars[i] as i64 - chars[i - 1] as i64 == *step;
+            if continues {
+                continue;
+            }
+
+            let len = i - start;
+            if len >= 3 {
+                patterns.push(if *is_sequence { Pattern::Sequence { at: start, len } } else { Pattern::Repeat { at: start, len } });
+            }
+            start = i;
+        }
+    }
+
+    patterns
+}
+
+
+/// Analyze `key` against `policy`
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::quality::{analyze, Pattern, Policy};
+///
+/// let report = analyze("aaa123", &Policy::default());
+/// assert_eq!(report.counts, [3, 0, 3]);
+/// assert_eq!(report.patterns, vec![Pattern::Repeat { at: 0, len: 3 }, Pattern::Sequence { at: 3, len: 3 }]);
+/// assert!(!report.passed());
+/// println!("{}", report);
+///
+/// let report = analyze("q7#Lm2!xZ9@kT4$w", &Policy::default());
+/// assert!(report.patterns.is_empty());
+/// assert!(report.passed());
+/// ```
+#[inline]
+pub fn analyze(key: &str, policy: &Policy) -> QualityReport {
+    let chars: Vec<char> = key.chars().collect();
+    let counts = [
+        chars.iter().filter(|c| c.is_ascii_alphabetic()).count(),
+        chars.iter().filter(|c| c.is_ascii_punctuation()).count(),
+        chars.iter().filter(|c| c.is_ascii_digit()).count(),
+    ];
+
+    let mut patterns = _PATTERNS(&chars);
+    let common = COMMON_PASSWORDS.iter().any(|c| c.eq_ignore_ascii_case(key));
+    if common {
+        patterns.push(Pattern::Common);
+    }
+
+    let entropy = StrengthReport::of(key);
+
+    let mut results = vec![
+        PolicyResult {
+            rule:   format!("at least {:?}", policy.min_strength),
+            passed: entropy.strength() >= policy.min_strength,
+        },
+        PolicyResult {
+            rule:   format!("at least {} kinds of characters", policy.min_classes),
+            passed: entropy.classes >= policy.min_classes,
+        },
+        PolicyResult {
+            rule:   format!("at least {} characters", policy.min_length),
+            passed: chars.len() >= policy.min_length,
+        },
+    ];
+    if policy.reject_common {
+        results.push(PolicyResult { rule: "not a common password".into(), passed: !common });
+    }
+
+    QualityReport {
+        length: chars.len(),
+        counts,
+        others: chars.len() - counts.iter().sum::<usize>(),
+        entropy,
+        patterns,
+        policy: results,
+    }
+}
+
+
+impl Display for Pattern {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Repeat { at, len } => write!(f, "{} repeated characters at {}", len, at),
+            Pattern::Sequence { at, len } => write!(f, "{} consecutive characters at {}", len, at),
+            Pattern::Common => write!(f, "common password"),
+        }
+    }
+}
+
+
+impl Display for QualityReport {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Length:   {}", self.length)?;
+        writeln!(f,
+                 "Counts:   {} letters, {} symbols, {} numbers, {} others",
+                 self.counts[0],
+                 self.counts[1],
+                 self.counts[2],
+                 self.others)?;
+        writeln!(f, "Entropy:  {}", self.entropy)?;
+
+        for pattern in &self.patterns {
+            writeln!(f, "Pattern:  {}", pattern)?;
+        }
+        for result in &self.policy {
+            writeln!(f, "Policy:   [{}] {}", if result.passed { "pass" } else { "fail" }, result.rule)?;
+        }
+
+        Ok(())
+    }
+}
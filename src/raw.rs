@@ -0,0 +1,41 @@
+use {
+    rand::{thread_rng, RngCore},
+    crate::RandKey,
+};
+
+
+
+
+/// Return `len` uniformly random bytes, for salts and other secrets which needn't be printable
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// let salt = rand_key::salt(16);
+/// assert_eq!(salt.len(), 16);
+/// assert_ne!(salt, rand_key::salt(16));
+/// ```
+#[inline]
+pub fn salt(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    thread_rng().fill_bytes(&mut bytes);
+
+    bytes
+}
+
+
+impl RandKey {
+    /// Return `len` uniformly random bytes like [`salt`](crate::salt), the data and the counts aren't involved
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// let r_p = RandKey::default();
+    /// assert_eq!(r_p.raw_bytes(32).len(), 32);
+    /// assert!(r_p.key().is_empty());
+    /// ```
+    #[inline]
+    pub fn raw_bytes(&self, len: usize) -> Vec<u8> { salt(len) }
+}
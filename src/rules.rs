@@ -0,0 +1,174 @@
+use {
+    rand::{Rng, rngs::StdRng, SeedableRng, thread_rng, seq::{SliceRandom, index}},
+    crate::{RandKey, GenError, ASCIIExcludeCtrl::{self, *}, utils::{_TRY_BUF, _WIPE}},
+};
+
+
+
+
+/// Where the characters of a kind may be in a key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// No two characters of the kind are next to each other
+    NoAdjacent(ASCIIExcludeCtrl),
+    /// The key doesn't start with a character of the kind
+    NeverFirst(ASCIIExcludeCtrl),
+}
+
+
+/// The kinds in the order of the counts
+const _KINDS: [ASCIIExcludeCtrl; 3] = [Alphabetic, Punctuation, Digit];
+
+
+/// Return the kinds of the characters of a key, in an order following `rules`,
+/// followed by a check digit if `check_digit`
+///
+/// The kinds without `NoAdjacent` rule are shuffled first, and the characters of the other kinds
+/// are put in distinct gaps between them, one kind after another.
+/// So the kinds without `NoAdjacent` rule have to separate the others.
+/// The gap after the last character is left out for the digits with a check digit, which is next to it.
+#[inline]
+pub(crate) fn _KIND_ORDER<R: Rng>(counts: [usize; 3], rules: &[Rule], check_digit: bool, rng: &mut R) -> Result<Vec<u8>, GenError> {
+    let is = |rule: Rule| rules.contains(&rule);
+    let never_first = |kind: u8| is(Rule::NeverFirst(_KINDS[kind as usize]));
+
+    let mut order = _TRY_BUF(counts.iter().sum())?;
+    for (kind, count) in counts.iter().enumerate() {
+        if !is(Rule::NoAdjacent(_KINDS[kind])) {
+            order.extend(std::iter::repeat_n(kind as u8, *count));
+        }
+    }
+    order.shuffle(rng);
+
+    // A character which may be first is swapped to the front, or one of the other kinds has to be put there
+    let mut need_front = false;
+    if order.first().is_some_and(|k| never_first(*k)) {
+        let eligible: Vec<usize> = (0..order.len()).filter(|i| !never_first(order[*i])).collect();
+        match eligible.choose(rng) {
+            Some(i) => order.swap(0, *i),
+            None => need_front = true,
+        }
+    }
+
+    for (kind, count) in counts.iter().enumerate() {
+        if !is(Rule::NoAdjacent(_KINDS[kind])) || *count == 0 {
+            continue;
+        }
+
+        let before_check = check_digit && _KINDS[kind] == Digit;
+        if before_check && order.is_empty() {
+            return Err(GenError::UnsatisfiableRules(format!("{} Digit characters can't be apart from the check digit", count)));
+        }
+
+        let front = need_front && !never_first(kind as u8);
+        let lowest = if never_first(kind as u8) || front { 1 } else { 0 };
+        let gaps = order.len() + 1 - lowest - before_check as usize;
+        let picked = count - front as usize;
+
+        if picked > gaps {
+            return Err(GenError::UnsatisfiableRules(format!("{} {:?} characters can't be apart among {} others{}",
+                                                            count, _KINDS[kind], order.len(),
+                                                            if before_check { " and the check digit" } else { "" })));
+        }
+
+        let mut at: Vec<usize> = index::sample(rng, gaps, picked).into_iter().map(|g| g + lowest).collect();
+        if front {
+            at.push(0);
+            need_front = false;
+        }
+        at.sort_unstable();
+
+        let mut merged = _TRY_BUF(order.len() + count)?;
+        let mut at = at.into_iter().peekable();
+        for gap in 0..=order.len() {
+            if at.next_if_eq(&gap).is_some() {
+                merged.push(kind as u8);
+            }
+            if let Some(k) = order.get(gap) {
+                merged.push(*k);
+            }
+        }
+        order = merged;
+    }
+
+    // A key of the check digit alone starts with a digit
+    let check_first = check_digit && order.is_empty() && never_first(2);
+    if need_front || check_first || order.first().is_some_and(|k| never_first(*k)) {
+        return Err(GenError::UnsatisfiableRules("No character may be first".into()));
+    }
+
+    Ok(order)
+}
+
+
+/// Arrange `buf`, the characters of each kind after another, in an order following `rules`
+/// with the check digit pushed after it if `check_digit`, with the seeded generator if there is a seed
+#[inline]
+pub(crate) fn _PLACE<T: Copy + Default>(mut buf:     Vec<T>,
+                                        counts:      [usize; 3],
+                                        rules:       &[Rule],
+                                        check_digit: bool,
+                                        seed:        Option<u64>) -> Result<Vec<T>, GenError> {
+    let order = match seed {
+        Some(seed) => _KIND_ORDER(counts, rules, check_digit, &mut StdRng::seed_from_u64(seed))?,
+        None => _KIND_ORDER(counts, rules, check_digit, &mut thread_rng())?,
+    };
+
+    // The characters of a kind are picked independently, so they are taken in the order they were picked
+    let mut next = [0, counts[0], counts[0] + counts[1]];
+    let mut placed = _TRY_BUF(buf.capacity())?;
+    for kind in order {
+        placed.push(buf[next[kind as usize]]);
+        next[kind as usize] += 1;
+    }
+    _WIPE(&mut buf);
+
+    Ok(placed)
+}
+
+
+impl RandKey {
+    /// Return the rules of the positions of the characters
+    #[inline]
+    pub fn rules(&self) -> &[Rule] { &self.RULES }
+
+    /// Add a rule of the positions of the characters, like no two symbols next to each other.
+    ///
+    /// The rules are followed while the characters are arranged, not by generating keys until one does,
+    /// so they cost the same however long the key is.
+    /// The characters of the kinds without `NoAdjacent` rule keep apart the ones with it,
+    /// so there have to be enough of them, and the `Streaming` strategy can't follow rules.
+    /// The check digit at the end counts as a digit for them.
+    ///
+    /// Return `GenError::UnsatisfiableRules` from [`join`](RandKey::join) if the counts can't follow them.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, Rule, GenError, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "8", "5")?;
+    /// r_p.add_rule(Rule::NoAdjacent(Punctuation));
+    /// r_p.add_rule(Rule::NeverFirst(Digit));
+    ///
+    /// let key: Vec<char> = r_p.generate()?.chars().collect();
+    /// assert!(!key[0].is_ascii_digit());
+    /// assert!(key.windows(2).all(|w| !(w[0].is_ascii_punctuation() && w[1].is_ascii_punctuation())));
+    ///
+    /// r_p.set_cnt(Punctuation, "17");
+    /// assert!(matches!(r_p.join(), Err(GenError::UnsatisfiableRules(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn add_rule(&mut self, rule: Rule) {
+        if !self.RULES.contains(&rule) {
+            self.RULES.push(rule);
+        }
+    }
+
+    /// Remove all the rules of the positions of the characters
+    #[inline]
+    pub fn clear_rules(&mut self) { self.RULES.clear(); }
+}
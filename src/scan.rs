@@ -0,0 +1,251 @@
+//! Human patterns hidden in a key, the ones guessers try first.
+use {
+    std::{collections::HashSet, fmt::{self, Display, Formatter}, sync::OnceLock},
+    crate::{audit::COMMON_PASSWORDS, passphrase::Language},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// What a [`Finding`] is
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FindingKind {
+    /// A date, like `1990-04-12`, `12/04/1990` or `19900412`
+    Date,
+    /// A year from 1900 to 2099
+    Year,
+    /// A phone number, 10 to 15 digits or 3 then 4 digits, maybe separated
+    Phone,
+    /// A token of 2 to 64 characters repeated, like `abcabc`
+    RepeatedToken,
+    /// A dictionary word, maybe in l33t like `p4ssw0rd`
+    Word(String),
+}
+
+
+/// A pattern found by [`scan`], over the `len` characters from the `at`th one
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub at:   usize,
+    pub len:  usize,
+}
+
+
+/// The separators of the groups of digits in dates and phone numbers
+const _SEPARATORS: &[char] = &['-', '.', '/', ' '];
+
+
+/// The shortest words looked for
+const _MIN_WORD: usize = 4;
+
+
+/// The longest tokens looked for repeated, so the scan takes a time linear in the length of the key
+const _MAX_PERIOD: usize = 64;
+
+
+/// The words looked for, built on the first scan
+static _WORDLIST: OnceLock<HashSet<Vec<char>>> = OnceLock::new();
+
+
+/// Spell the l33t characters as letters, `1` being either `l` or `i`
+#[inline]
+fn _UNLEET(chars: &[char], one: char) -> Vec<char> {
+    chars.iter()
+         .map(|c| match c {
+             '0' => 'o',
+             '1' | '!' => one,
+             '3' => 'e',
+             '4' | '@' => 'a',
+             '5' | '$' => 's',
+             '7' => 't',
+             c => c.to_ascii_lowercase(),
+         })
+         .collect()
+}
+
+
+/// Return whether the groups of digits are a valid date
+#[inline]
+fn _IS_DATE(groups: &[&[char]]) -> bool {
+    let num = |g: &[char]| g.iter().fold(0, |n, c| n * 10 + c.to_digit(10).unwrap());
+    let valid = |y: u32, m: u32, d: u32| (1900..=2099).contains(&y) && (1..=12).contains(&m) && (1..=31).contains(&d);
+
+    match groups {
+        [y, m, d] if y.len() == 4 && m.len() <= 2 && d.len() <= 2 => valid(num(y), num(m), num(d)),
+        [a, b, y] if a.len() <= 2 && b.len() <= 2 && y.len() == 4 => {
+            valid(num(y), num(b), num(a)) || valid(num(y), num(a), num(b))
+        }
+        [g] if g.len() == 8 => {
+            let (head, tail) = (num(&g[..4]), num(&g[4..]));
+            valid(head, tail / 100, tail % 100)
+            || valid(tail, head % 100, head / 100)
+            || valid(tail, head / 100, head % 100)
+        }
+        _ => false,
+    }
+}
+
+
+/// Find the dates, years and phone numbers in the runs of digits and separators
+#[inline]
+fn _DIGIT_RUNS(chars: &[char], findings: &mut Vec<Finding>) {
+    let mut i = 0;
+
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        // A separator belongs to the run if a digit follows
+        let start = i;
+        while i + 1 < chars.len()
+              && (chars[i + 1].is_ascii_digit()
+                  || (_SEPARATORS.contains(&chars[i + 1]) && chars.get(i + 2).is_some_and(char::is_ascii_digit))) {
+            i += 1;
+        }
+        i += 1;
+
+        let run = &chars[start..i];
+        let groups: Vec<&[char]> = run.split(|c| !c.is_ascii_digit()).collect();
+        let digits: usize = groups.iter().map(|g| g.len()).sum();
+
+        let kind = if _IS_DATE(&groups) {
+            Some(FindingKind::Date)
+        } else if (10..=15).contains(&digits) || groups.iter().map(|g| g.len()).eq([3, 4].iter().copied()) {
+            Some(FindingKind::Phone)
+        } else {
+            None
+        };
+
+        match kind {
+            Some(kind) => findings.push(Finding { kind, at: start, len: run.len() }),
+            None => {
+                let mut at = start;
+                for g in groups {
+                    // The length first, a long group would overflow
+                    if g.len() == 4 && (1900..=2099).contains(&g.iter().fold(0, |n, c| n * 10 + c.to_digit(10).unwrap())) {
+                        findings.push(Finding { kind: FindingKind::Year, at, len: 4 });
+                    }
+                    at += g.len() + 1;
+                }
+            }
+        }
+    }
+}
+
+
+/// Find the tokens of 2 to `_MAX_PERIOD` characters, not all the same, repeated right after themselves
+#[inline]
+fn _REPEATED_TOKENS(chars: &[char], findings: &mut Vec<Finding>) {
+    // The furthest end of the tokens found so far starting at or before each character,
+    // a token is covered by a longer one if it ends before
+    let mut reach = vec![0; chars.len()];
+
+    for period in 2..=(chars.len() / 2).min(_MAX_PERIOD) {
+        let mut found: Vec<(usize, usize)> = vec![];
+        let mut i = 0;
+
+        while i + period < chars.len() {
+            let mut matched = 0;
+            while i + matched + period < chars.len() && chars[i + matched] == chars[i + matched + period] {
+                matched += 1;
+            }
+
+            let len = matched + period;
+            let covered = i + len <= reach[i];
+            let distinct = chars[i..i + period].iter().any(|c| *c != chars[i]);
+
+            if matched >= period && distinct && !covered {
+                found.push((i, len));
+                findings.push(Finding { kind: FindingKind::RepeatedToken, at: i, len });
+            }
+            i += matched.max(1);
+        }
+
+        for (at, len) in found {
+            reach[at] = reach[at].max(at + len);
+        }
+        for i in 1..reach.len() {
+            reach[i] = reach[i].max(reach[i - 1]);
+        }
+    }
+}
+
+
+/// Find the dictionary words, the English wordlist and the common passwords of letters, in l33t or not
+#[inline]
+fn _WORDS(chars: &[char], findings: &mut Vec<Finding>) {
+    let words = _WORDLIST.get_or_init(|| {
+        Language::English.wordlist()
+                         .into_iter()
+                         .chain(COMMON_PASSWORDS.iter().copied().filter(|p| p.chars().all(|c| c.is_ascii_alphabetic())))
+                         .filter(|w| w.len() >= _MIN_WORD)
+                         .map(|w| w.chars().collect())
+                         .collect()
+    });
+    let longest = words.iter().map(Vec::len).max().unwrap_or(0);
+
+    let spellings = [_UNLEET(chars, 'l'), _UNLEET(chars, 'i')];
+
+    // Every word at every place, looked up in the set rather than compared to each character
+    for at in 0..chars.len() {
+        for len in _MIN_WORD..=longest.min(chars.len() - at) {
+            let mut matched: Vec<&[char]> = spellings.iter().map(|s| &s[at..at + len]).filter(|w| words.contains(*w)).collect();
+            matched.sort_unstable();
+            matched.dedup();
+
+            for word in matched {
+                findings.push(Finding { kind: FindingKind::Word(word.iter().collect()), at, len });
+            }
+        }
+    }
+}
+
+
+/// Return the dates, years, phone numbers, repeated tokens and dictionary words in `key`, in order
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::scan::{scan, FindingKind};
+///
+/// let kinds: Vec<FindingKind> = scan("P4ssw0rd!1990-04-12").into_iter().map(|f| f.kind).collect();
+/// assert_eq!(kinds, vec![FindingKind::Word("password".into()), FindingKind::Date]);
+///
+/// assert_eq!(scan("xyzxyz")[0].kind, FindingKind::RepeatedToken);
+/// assert_eq!(scan("call 555-0142")[0].kind, FindingKind::Phone);
+/// assert!(scan("q7#Lm2!xZ9@kT4$w").is_empty());
+/// ```
+#[inline]
+pub fn scan(key: &str) -> Vec<Finding> {
+    let chars: Vec<char> = key.chars().collect();
+    let mut findings = vec![];
+
+    _DIGIT_RUNS(&chars, &mut findings);
+    _REPEATED_TOKENS(&chars, &mut findings);
+    _WORDS(&chars, &mut findings);
+
+    findings.sort_by_key(|f| (f.at, f.len));
+    findings
+}
+
+
+impl Display for Finding {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            FindingKind::Date => write!(f, "date at {}", self.at),
+            FindingKind::Year => write!(f, "year at {}", self.at),
+            FindingKind::Phone => write!(f, "phone number at {}", self.at),
+            FindingKind::RepeatedToken => write!(f, "repeated token of {} characters at {}", self.len, self.at),
+            FindingKind::Word(word) => write!(f, "word `{}` at {}", word, self.at),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use {
+    std::{
+        convert::TryFrom,
+        time::{Duration, SystemTime},
+    },
+    crate::{RandKey, GenError},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// A key of a rotation schedule, with the window it is in use
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScheduledKey {
+    /// `rotation-0` for the first key, `rotation-1` for the next one and so on
+    pub label:       String,
+    pub key:         String,
+    /// When the key comes into use
+    pub valid_from:  SystemTime,
+    /// When the next key replaces it
+    pub valid_until: SystemTime,
+}
+
+
+impl ScheduledKey {
+    /// Check whether the key is in use at `time`
+    #[inline]
+    pub fn is_valid_at(&self, time: SystemTime) -> bool { self.valid_from <= time && time < self.valid_until }
+}
+
+
+impl RandKey {
+    /// Generate the keys of a rotation every `period` from now, until `horizon` from now is covered.
+    ///
+    /// The windows follow each other without a gap, so the last one may end after the horizon.
+    /// The last key is kept in the `key` field.
+    ///
+    /// Return `GenError::InconsistentField` if `period` is zero or the windows don't fit in a `SystemTime`,
+    /// and `GenError::BatchTooLarge` if there would be more than `u32::MAX` keys.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// const DAY: u64 = 24 * 3600;
+    ///
+    /// let r_p = RandKey::new("20", "4", "8")?;
+    /// let quarters = r_p.rotate_schedule(Duration::from_secs(91 * DAY), Duration::from_secs(365 * DAY))?;
+    ///
+    /// assert_eq!(quarters.len(), 5);
+    /// assert_eq!(quarters[1].label, "rotation-1");
+    /// assert_eq!(quarters[0].valid_until, quarters[1].valid_from);
+    /// assert!(quarters[0].is_valid_at(SystemTime::now()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn rotate_schedule(&self, period: Duration, horizon: Duration) -> Result<Vec<ScheduledKey>, GenError> {
+        if period.is_zero() {
+            return Err(GenError::InconsistentField);
+        }
+
+        let start = SystemTime::now();
+        let n = u32::try_from(horizon.as_nanos().div_ceil(period.as_nanos()).max(1))
+                    .map_err(|_| GenError::BatchTooLarge(u32::MAX as usize))?;
+        let window = |i| period.checked_mul(i).and_then(|d| start.checked_add(d)).ok_or(GenError::InconsistentField);
+
+        (0..n).map(|i| {
+                  let (valid_from, valid_until) = (window(i)?, window(i + 1)?);
+                  self.join()?;
+
+                  Ok(ScheduledKey { label: format!("rotation-{}", i), key: self.key(), valid_from, valid_until })
+              })
+              .collect()
+    }
+}
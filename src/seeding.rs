@@ -0,0 +1,144 @@
+use {
+    rand::{thread_rng, RngCore, rngs::{StdRng, ThreadRng}, SeedableRng},
+    crate::RandKey,
+};
+
+
+
+
+/// How the generator of each unit picking the characters of [`join`](RandKey::join) is seeded
+///
+/// With [`Os`](SeedStrategy::Os), the keys are as unpredictable as the OS randomness,
+/// and never generated twice the same.
+/// [`Derived`](SeedStrategy::Derived) makes the characters picked reproducible,
+/// so everyone knowing the master seed knows every key: use it for tests and benchmarks,
+/// never for keys protecting anything, 64 bits of seed being far too few anyway.
+/// [`Derived256`](SeedStrategy::Derived256) is as reproducible, from a master seed of the size of the ChaCha20 key,
+/// so the keys can have up to 256 bits of entropy when it's secret and random.
+/// The order of the characters is the one of [`set_seed`](RandKey::set_seed), either way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedStrategy {
+    /// The `thread_rng` of the rayon worker running the unit, seeded by the OS and reseeded periodically
+    Os,
+    /// A ChaCha20 generator seeded by the master seed, the kind of the unit and its index among the units of its kind
+    Derived(u64),
+    /// A ChaCha20 generator seeded by the 256-bit master seed, with the kind of the unit and its index
+    /// among the units of its kind mixed in
+    Derived256([u8; 32]),
+}
+
+
+impl Default for SeedStrategy {
+    #[inline]
+    fn default() -> Self { SeedStrategy::Os }
+}
+
+
+/// The generator of a unit, on the stack of the unit rather than boxed for each one
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum _UnitRng {
+    Os(ThreadRng),
+    Derived(StdRng),
+}
+
+
+impl SeedStrategy {
+    /// Return the generator of the `unit`-th unit of the kind `kind`
+    #[inline]
+    pub(crate) fn unit_rng(self, kind: usize, unit: usize) -> _UnitRng {
+        match self {
+            SeedStrategy::Os => _UnitRng::Os(thread_rng()),
+            SeedStrategy::Derived(master) => {
+                let mut seed = [0; 32];
+                seed[..8].copy_from_slice(&master.to_le_bytes());
+                seed[8..16].copy_from_slice(&(kind as u64).to_le_bytes());
+                seed[16..24].copy_from_slice(&(unit as u64).to_le_bytes());
+                seed[24..].copy_from_slice(b"rand_key");
+
+                _UnitRng::Derived(StdRng::from_seed(seed))
+            }
+            SeedStrategy::Derived256(mut seed) => {
+                for (s, b) in seed.iter_mut().zip((kind as u64).to_le_bytes().iter().chain(&(unit as u64).to_le_bytes())) {
+                    *s ^= b;
+                }
+
+                _UnitRng::Derived(StdRng::from_seed(seed))
+            }
+        }
+    }
+
+    /// Return the name of the generators, for the metadata
+    #[inline]
+    pub(crate) fn rng_name(self) -> &'static str {
+        match self {
+            SeedStrategy::Os => "ThreadRng",
+            SeedStrategy::Derived(_) => "StdRng derived from a master seed",
+            SeedStrategy::Derived256(_) => "StdRng derived from a 256-bit master seed",
+        }
+    }
+}
+
+
+impl RngCore for _UnitRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            _UnitRng::Os(rng) => rng.next_u32(),
+            _UnitRng::Derived(rng) => rng.next_u32(),
+        }
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            _UnitRng::Os(rng) => rng.next_u64(),
+            _UnitRng::Derived(rng) => rng.next_u64(),
+        }
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            _UnitRng::Os(rng) => rng.fill_bytes(dest),
+            _UnitRng::Derived(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            _UnitRng::Os(rng) => rng.try_fill_bytes(dest),
+            _UnitRng::Derived(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
+
+impl RandKey {
+    /// Return how the generators picking the characters are seeded
+    #[inline]
+    pub fn seed_strategy(&self) -> SeedStrategy { self.SEEDING }
+
+    /// Set how the generators picking the characters are seeded, see [`SeedStrategy`] for the trade-off
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, SeedStrategy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.set_seed_strategy(SeedStrategy::Derived(7));
+    /// r_p.set_seed(Some(42));
+    ///
+    /// // The same characters in the same order
+    /// let other = r_p.clone();
+    /// assert_eq!(r_p.generate()?, other.generate()?);
+    /// assert_eq!(r_p.metadata().unwrap().rng, "StdRng derived from a master seed");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_seed_strategy(&mut self, seeding: SeedStrategy) { self.SEEDING = seeding; }
+}
@@ -0,0 +1,227 @@
+use {
+    parking_lot::Mutex,
+    crate::{RandKey, GenError, SharedRandKey, LabeledKey, ASCIIExcludeCtrl::*, presets::Preset, prelude::AsBiguint, utils::*},
+    std::{
+        collections::HashMap,
+        time::Instant,
+        sync::atomic::{AtomicU64, Ordering::*},
+    },
+};
+
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// The most keys a [`BatchRequest`] may ask for
+pub const MAX_BATCH: usize = 1000;
+
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last:   Instant,
+}
+
+
+/// Counters of a [`KeyService`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ServiceMetrics {
+    /// Requests that got a key
+    pub served:   u64,
+    /// Requests turned down by the rate limit
+    pub rejected: u64,
+    /// Requests failed for any other reason
+    pub failed:   u64,
+    /// Characters generated in total
+    pub chars:    u64,
+}
+
+
+/// A request of keys, as the servers receive it
+///
+/// The counts override the ones of the preset, `Preset::Standard` without a preset.
+/// The labels set how many keys to generate, or `batch` without labels, one key without both.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct BatchRequest {
+    pub preset:  Option<String>,
+    pub ltr_cnt: Option<String>,
+    pub sbl_cnt: Option<String>,
+    pub num_cnt: Option<String>,
+    pub batch:   Option<usize>,
+    pub labels:  Option<Vec<String>>,
+}
+
+
+/// A generator for servers, limiting how many characters each tenant generates per second
+///
+/// Every tenant has a token bucket holding up to `burst` characters, refilled by `rate` characters per second.
+/// A request costs the length of the key and is turned down if the bucket can't afford it.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, GenError, KeyService};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let service = KeyService::new(RandKey::new("10", "2", "3")?.into(), 1.0, 30.0);
+///
+/// assert!(service.generate("alice").is_ok());
+/// assert!(service.generate("alice").is_ok());
+/// // 30 characters are used up
+/// assert!(matches!(service.generate("alice"), Err(GenError::RateLimited)));
+/// // But not by bob
+/// assert!(service.generate("bob").is_ok());
+///
+/// let metrics = service.metrics();
+/// assert_eq!((metrics.served, metrics.rejected, metrics.chars), (3, 1, 45));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct KeyService {
+    generator: SharedRandKey,
+    rate:      f64,
+    burst:     f64,
+    buckets:   Mutex<HashMap<String, Bucket>>,
+    served:    AtomicU64,
+    rejected:  AtomicU64,
+    failed:    AtomicU64,
+    chars:     AtomicU64,
+}
+
+
+impl KeyService {
+    /// Return a service refilling `rate` characters per second, up to `burst` characters for each tenant
+    #[inline]
+    pub fn new(generator: SharedRandKey, rate: f64, burst: f64) -> Self {
+        KeyService {
+            generator,
+            rate,
+            burst,
+            buckets:  Default::default(),
+            served:   Default::default(),
+            rejected: Default::default(),
+            failed:   Default::default(),
+            chars:    Default::default(),
+        }
+    }
+
+    /// Return the generator, changing its settings changes the keys of the service
+    #[inline]
+    pub fn generator(&self) -> &SharedRandKey { &self.generator }
+
+    #[inline]
+    fn take(&self, tenant: &str, cost: f64) -> bool {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock();
+        let bucket = buckets.entry(tenant.into()).or_insert(Bucket { tokens: self.burst, last: now });
+
+        bucket.tokens = (bucket.tokens + (now - bucket.last).as_secs_f64() * self.rate).min(self.burst);
+        bucket.last = now;
+
+        if bucket.tokens >= cost {
+            bucket.tokens -= cost;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Generate a key for `tenant`, or return `GenError::RateLimited` if it's over its limit
+    #[inline]
+    pub fn generate(&self, tenant: &str) -> Result<String, GenError> {
+        let config = self.generator.config();
+        self.charged(tenant, [&config.ltr_cnt, &config.sbl_cnt, &config.num_cnt], || self.generator.generate())
+    }
+
+    /// Generate a key of `r_p` for `tenant` under the same limit, for the requests bringing their own settings
+    #[inline]
+    pub fn generate_with(&self, tenant: &str, r_p: &RandKey) -> Result<String, GenError> {
+        let cnts = [r_p.get_cnt(Alphabetic), r_p.get_cnt(Punctuation), r_p.get_cnt(Digit)];
+        self.charged(tenant, [&cnts[0], &cnts[1], &cnts[2]], || r_p.join().map(|_| r_p.key()))
+    }
+
+    /// Generate the keys of `req` for `tenant` under the same limit, the unlabeled keys have empty labels.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, KeyService, service::BatchRequest};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = KeyService::new(RandKey::default().into(), 100.0, 1000.0);
+    /// let req = BatchRequest { preset: Some("pin".into()), batch: Some(3), ..Default::default() };
+    ///
+    /// let keys = service.generate_batch("alice", &req)?;
+    /// assert_eq!(keys.len(), 3);
+    /// assert!(keys.iter().all(|k| k.key.len() == 6 && k.label.is_empty()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_batch(&self, tenant: &str, req: &BatchRequest) -> Result<Vec<LabeledKey>, GenError> {
+        let mut r_p = match &req.preset {
+            Some(name) => name.parse::<Preset>()?.to_randkey(),
+            None => Preset::Standard.to_randkey(),
+        };
+        for (kind, cnt) in [(Alphabetic, &req.ltr_cnt), (Punctuation, &req.sbl_cnt), (Digit, &req.num_cnt)] {
+            if let Some(cnt) = cnt {
+                cnt.as_biguint()?;
+                r_p.set_cnt(kind, cnt);
+            }
+        }
+
+        // Checked before building the labels, a huge `batch` would abort on the allocation
+        let count = req.labels.as_ref().map_or(req.batch.unwrap_or(1), Vec::len);
+        if count > MAX_BATCH {
+            return Err(GenError::BatchTooLarge(MAX_BATCH));
+        }
+
+        let labels = match &req.labels {
+            Some(labels) => labels.clone(),
+            None => vec![String::new(); count],
+        };
+
+        labels.into_iter()
+              .map(|label| Ok(LabeledKey { key: self.generate_with(tenant, &r_p)?, label }))
+              .collect()
+    }
+
+    #[inline]
+    fn charged(&self, tenant: &str, cnts: [&String; 3], gen: impl FnOnce() -> Result<String, GenError>)
+               -> Result<String, GenError> {
+        let cost: BigUint = cnts.iter().map(|c| c.parse::<BigUint>().unwrap_or_default()).sum();
+
+        if !self.take(tenant, cost.to_f64().unwrap_or(f64::INFINITY)) {
+            self.rejected.fetch_add(1, Relaxed);
+            return Err(GenError::RateLimited);
+        }
+
+        match gen() {
+            Ok(key) => {
+                self.served.fetch_add(1, Relaxed);
+                self.chars.fetch_add(key.len() as u64, Relaxed);
+                Ok(key)
+            }
+            Err(e) => {
+                self.failed.fetch_add(1, Relaxed);
+                Err(e)
+            }
+        }
+    }
+
+    /// Return the counters so far
+    #[inline]
+    pub fn metrics(&self) -> ServiceMetrics {
+        ServiceMetrics {
+            served:   self.served.load(Relaxed),
+            rejected: self.rejected.load(Relaxed),
+            failed:   self.failed.load(Relaxed),
+            chars:    self.chars.load(Relaxed),
+        }
+    }
+}
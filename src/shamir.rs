@@ -0,0 +1,126 @@
+//! Shamir's secret sharing of generated keys, over GF(256).
+use crate::{
+    RandKey, GenError, ToRandKey,
+    utils::*,
+};
+
+
+
+
+/// One share of a key, `k` of them recover it
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Share {
+    /// Where the polynomials are evaluated, never 0
+    pub x: u8,
+    /// The values of the polynomials, one per byte of the key
+    pub y: Vec<u8>,
+}
+
+
+#[inline]
+fn _GF_MUL(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+
+    while b != 0 {
+        if b & 1 != 0 {
+            p ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+
+    p
+}
+
+
+#[inline]
+fn _GF_INV(a: u8) -> u8 {
+    // a^254 is the inverse of a in GF(256)
+    let mut r = 1;
+    for _ in 0..254 {
+        r = _GF_MUL(r, a);
+    }
+    r
+}
+
+
+impl RandKey {
+    /// Split the key into `n` shares, any `k` of them recover it by [`recover_shares`].
+    ///
+    /// Fewer than `k` shares tell nothing about the key.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, shamir::recover_shares};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join()?;
+    ///
+    /// let shares = r_p.split_shares(3, 5)?;
+    /// assert_eq!(recover_shares(&shares[1..4])?.key(), r_p.key());
+    /// assert_eq!(recover_shares(&[shares[4].clone(), shares[0].clone(), shares[2].clone()])?.key(), r_p.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn split_shares(&self, k: u8, n: u8) -> Result<Vec<Share>, GenError> {
+        if k == 0 || k > n {
+            return Err(GenError::InvalidShares);
+        }
+
+        let key = self.key.borrow();
+        let mut rng = thread_rng();
+        let mut shares: Vec<Share> = (1..=n).map(|x| Share { x, y: Vec::with_capacity(key.len()) }).collect();
+
+        for secret in key.bytes() {
+            let coeffs: Vec<u8> = std::iter::once(secret).chain((1..k).map(|_| rng.gen())).collect();
+
+            for share in shares.iter_mut() {
+                // Horner's method
+                let y = coeffs.iter().rev().fold(0, |acc, c| _GF_MUL(acc, share.x) ^ c);
+                share.y.push(y);
+            }
+        }
+
+        Ok(shares)
+    }
+}
+
+
+/// Recover the key from its shares, given at least as many as required when splitting.
+///
+/// Too few shares recover a wrong key, which is mostly rejected for not being ASCII.
+#[inline]
+pub fn recover_shares(shares: &[Share]) -> Result<RandKey, GenError> {
+    let len = shares.first().map_or(0, |s| s.y.len());
+
+    let mut xs: Vec<u8> = shares.iter().map(|s| s.x).collect();
+    xs.sort_unstable();
+    xs.dedup();
+
+    if shares.is_empty() || xs.len() != shares.len() || xs[0] == 0 || shares.iter().any(|s| s.y.len() != len) {
+        return Err(GenError::InvalidShares);
+    }
+
+    // Lagrange interpolation at 0
+    let basis: Vec<u8> = shares.iter()
+                               .map(|i| {
+                                   shares.iter().filter(|j| j.x != i.x).fold(1, |acc, j| {
+                                       _GF_MUL(acc, _GF_MUL(j.x, _GF_INV(j.x ^ i.x)))
+                                   })
+                               })
+                               .collect();
+
+    let bytes: Vec<u8> = (0..len).map(|b| {
+                                      shares.iter().zip(basis.iter()).fold(0, |acc, (s, l)| acc ^ _GF_MUL(s.y[b], *l))
+                                  })
+                                  .collect();
+
+    String::from_utf8(bytes).map_err(|_| GenError::InvalidChar)?.to_randkey()
+}
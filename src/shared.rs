@@ -0,0 +1,181 @@
+use {
+    parking_lot::RwLock,
+    std::sync::Arc,
+    crate::{
+        RandKey, GenError, GenStats, RandKeyConfig, Strategy, SeedStrategy, Rule, Classifier, PoolCheck,
+        ASCIIExcludeCtrl::{self, *},
+        checksum::Checksum,
+        data_report::_CHECK_POOLS,
+        prelude::AsBiguint,
+        strength::_CHECK_ENTROPY,
+        utils::*,
+    },
+};
+
+
+
+
+/// The settings of the `RandKey` the generator was made from, all of them but the key
+#[derive(Debug)]
+struct Settings {
+    cnts:        [BigUint; 3],
+    unit:        BigUint,
+    data:        Vec<Vec<String>>,
+    checksum:    Option<Checksum>,
+    seed:        Option<u64>,
+    strategy:    Strategy,
+    seeding:     SeedStrategy,
+    rules:       Vec<Rule>,
+    min_entropy: f64,
+    pool_check:  PoolCheck,
+    classifier:  Arc<dyn Classifier>,
+}
+
+
+/// A `RandKey` generator shared across threads
+///
+/// Cloning it is cheap, the clones share the same settings, all the ones of the `RandKey` it's made from.
+/// Generating only takes a read lock, so the threads generate in parallel.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, SharedRandKey};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let shared = SharedRandKey::from(RandKey::new("10", "2", "3")?);
+///
+/// let handles: Vec<_> = (0..4).map(|_| {
+///     let shared = shared.clone();
+///     std::thread::spawn(move || shared.generate())
+/// }).collect();
+///
+/// for h in handles {
+///     assert_eq!(h.join().unwrap()?.len(), 15);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedRandKey {
+    inner: Arc<RwLock<Settings>>,
+}
+
+
+impl From<RandKey> for SharedRandKey {
+    #[inline]
+    fn from(r_p: RandKey) -> Self { SharedRandKey::from(&r_p) }
+}
+
+
+impl From<&RandKey> for SharedRandKey {
+    #[inline]
+    fn from(r_p: &RandKey) -> Self {
+        SharedRandKey {
+            inner: Arc::new(RwLock::new(Settings {
+                cnts:        [r_p.ltr_cnt.clone(), r_p.sbl_cnt.clone(), r_p.num_cnt.clone()],
+                unit:        r_p.UNIT.borrow().clone(),
+                data:        r_p.DATA.clone(),
+                checksum:    r_p.CHECKSUM,
+                seed:        r_p.SEED,
+                strategy:    r_p.STRATEGY,
+                seeding:     r_p.SEEDING,
+                rules:       r_p.RULES.clone(),
+                min_entropy: r_p.MIN_ENTROPY,
+                pool_check:  r_p.POOL_CHECK,
+                classifier:  Arc::clone(&r_p.CLASSIFIER),
+            })),
+        }
+    }
+}
+
+
+impl SharedRandKey {
+    /// Generate a key, like [`RandKey::join`] does
+    #[inline]
+    pub fn generate(&self) -> Result<String, GenError> { self.generate_with_stats().map(|(key, _)| key) }
+
+    /// Generate a key, and report how long each phase took
+    #[inline]
+    pub fn generate_with_stats(&self) -> Result<(String, GenStats), GenError> {
+        let s = self.inner.read();
+        let cnts = [&s.cnts[0], &s.cnts[1], &s.cnts[2]];
+
+        _CHECK_ENTROPY(cnts, &s.data, s.checksum, s.min_entropy)?;
+        let warnings = _CHECK_POOLS(cnts, &s.data, s.pool_check)?;
+
+        let arrangement = _Arrangement {
+            seed:        s.seed,
+            strategy:    s.strategy,
+            seeding:     s.seeding,
+            rules:       &s.rules,
+            check_digit: s.checksum.is_some(),
+        };
+        let (key, mut stats) = _GENERATE(cnts, &s.unit, &s.data, s.checksum, arrangement)?;
+        stats.warnings = warnings;
+        drop(s);
+
+        #[cfg(feature = "audit-log")]
+        crate::audit_log::_RECORD(|| self.config(), &key)?;
+
+        Ok((key, stats))
+    }
+
+    /// Change the count of letters, symbols or numbers, for all the clones
+    #[inline]
+    pub fn set_cnt(&self, kind: ASCIIExcludeCtrl, val: impl AsRef<str>) -> Result<(), GenError> {
+        let val = val.as_biguint()?;
+
+        let idx = match kind {
+            Alphabetic => 0,
+            Punctuation => 1,
+            Digit => 2,
+        };
+        self.inner.write().cnts[idx] = val;
+
+        Ok(())
+    }
+
+    /// Set the `UNIT` for all the clones
+    #[inline]
+    pub fn set_unit(&self, val: impl AsRef<str>) -> Result<(), GenError> {
+        let val = val.as_biguint()?;
+
+        if val.is_zero() {
+            Err(GenError::InvalidUnit)
+        } else {
+            self.inner.write().unit = val;
+            Ok(())
+        }
+    }
+
+    /// Replace the data for all the clones, like [`RandKey::replace_data`], classified by the classifier of the `RandKey`
+    #[inline]
+    pub fn replace_data(&self, val: &[impl AsRef<str>]) -> Result<(), GenError> {
+        let mut s = self.inner.write();
+        if !_CHECK_ITEMS(val, &*s.classifier) {
+            return Err(GenError::InvalidChar);
+        }
+
+        let data = _GROUP_WITH(val, &*s.classifier);
+        _CHECK_DATA([&s.cnts[0], &s.cnts[1], &s.cnts[2]], &data)?;
+        s.data = data;
+
+        Ok(())
+    }
+
+    /// Return the current settings
+    #[inline]
+    pub fn config(&self) -> RandKeyConfig {
+        let s = self.inner.read();
+
+        RandKeyConfig {
+            ltr_cnt:  s.cnts[0].to_string(),
+            sbl_cnt:  s.cnts[1].to_string(),
+            num_cnt:  s.cnts[2].to_string(),
+            unit:     s.unit.to_string(),
+            data:     s.data.clone(),
+            checksum: s.checksum,
+        }
+    }
+}
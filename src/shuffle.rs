@@ -0,0 +1,280 @@
+use {
+    std::collections::HashMap,
+    rand::{thread_rng, Rng, seq::SliceRandom},
+    crate::{RandKey, GenError, Strategy, ASCIIExcludeCtrl::*, strategy::_DISTINCT},
+};
+
+
+
+
+/// How many times a key is shuffled or generated again before giving up
+pub(crate) const _MAX_RETRIES: usize = 1000;
+
+
+/// How much a new key has to differ from the previous one, position by position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Difference {
+    /// In at least this many positions
+    AtLeast(usize),
+    /// In every position, a derangement
+    Everywhere,
+}
+
+
+impl Difference {
+    /// Return `true` if `new` differs enough from `old`, the positions only one of them has differ
+    #[inline]
+    pub(crate) fn holds(self, old: &[char], new: &[char]) -> bool {
+        let differing = old.iter().zip(new.iter()).filter(|(o, n)| o != n).count() + old.len().max(new.len())
+                        - old.len().min(new.len());
+
+        match self {
+            Difference::AtLeast(n) => differing >= n,
+            Difference::Everywhere => differing == old.len().max(new.len()),
+        }
+    }
+}
+
+
+/// Rearrange `old` so that no character stays in a position it had,
+/// `None` if a character fills more than half of the positions
+#[inline]
+pub(crate) fn _DERANGE(old: &[char], rng: &mut impl Rng) -> Option<Vec<char>> {
+    let mut groups: HashMap<char, Vec<usize>> = HashMap::new();
+    for (i, c) in old.iter().enumerate() {
+        groups.entry(*c).or_default().push(i);
+    }
+
+    let shift = groups.values().map(Vec::len).max().unwrap_or(0);
+    if shift * 2 > old.len() {
+        return None;
+    }
+
+    // The positions of a character are next to each other in `order`,
+    // so moving every character `shift` places forward takes them all out of their group.
+    let mut groups: Vec<Vec<usize>> = groups.into_values().collect();
+    groups.shuffle(rng);
+    let order: Vec<usize> = groups.into_iter()
+                                  .flat_map(|mut g| {
+                                      g.shuffle(rng);
+                                      g
+                                  })
+                                  .collect();
+
+    let mut new = vec![' '; old.len()];
+    for (j, i) in order.iter().enumerate() {
+        new[*i] = old[order[(j + shift) % order.len()]];
+    }
+
+    Some(new)
+}
+
+
+impl RandKey {
+    /// Return the seed of the final shuffle of [`join`](RandKey::join), `None` if it's random
+    #[inline]
+    pub fn seed(&self) -> Option<u64> { self.SEED }
+
+    /// Drive the final shuffle of [`join`](RandKey::join) by a random number generator seeded with `seed`,
+    /// or by `thread_rng` with `None`.
+    ///
+    /// With a seed, the same characters are always put in the same order,
+    /// the characters themselves are picked by the generators of [`set_seed_strategy`](RandKey::set_seed_strategy).
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // The data leaves a single choice for each kind, only the order is random
+    /// let mut r_p = RandKey::new("8", "0", "8")?;
+    /// r_p.replace_data(&["a", "-", "1"])?;
+    /// r_p.set_seed(Some(42));
+    /// assert_eq!(r_p.seed(), Some(42));
+    ///
+    /// let other = r_p.clone();
+    /// r_p.join()?;
+    /// other.join()?;
+    /// assert_eq!(r_p.key(), other.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_seed(&mut self, seed: Option<u64>) { self.SEED = seed; }
+
+    /// Shuffle the characters of the current key, without generating new ones
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::ToRandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "abcdef123-".to_randkey()?;
+    /// r_p.shuffle_key();
+    ///
+    /// let mut chars: Vec<char> = r_p.key().chars().collect();
+    /// chars.sort();
+    /// assert_eq!(chars.into_iter().collect::<String>(), "-123abcdef");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn shuffle_key(&self) {
+        let mut chars: Vec<char> = self.key.borrow().chars().collect();
+        chars.shuffle(&mut thread_rng());
+
+        *self.key.borrow_mut() = chars.into_iter().collect::<String>().into();
+        self.record_metadata();
+    }
+}
+
+
+impl RandKey {
+    /// Shuffle the characters of the current key like [`shuffle_key`](RandKey::shuffle_key),
+    /// until the key differs from the previous one as much as `diff` asks for.
+    ///
+    /// Return `GenError::AttemptsExhausted` if it can't, like a derangement of `aab`, the key is left unchanged.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{ToRandKey, Difference};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "aaaabbbb1234".to_randkey()?;
+    /// let old: Vec<char> = r_p.key().chars().collect();
+    ///
+    /// r_p.shuffle_key_differing(Difference::Everywhere)?;
+    /// assert!(r_p.key().chars().zip(old.iter()).all(|(new, old)| new != *old));
+    ///
+    /// r_p.shuffle_key_differing(Difference::AtLeast(6))?;
+    /// assert!("aab".to_randkey()?.shuffle_key_differing(Difference::Everywhere).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn shuffle_key_differing(&self, diff: Difference) -> Result<(), GenError> {
+        let old: Vec<char> = self.key.borrow().chars().collect();
+        let mut rng = thread_rng();
+
+        let mut new = None;
+
+        if let Difference::AtLeast(n) = diff {
+            let tries = if n <= old.len() { _MAX_RETRIES } else { 0 };
+
+            for _ in 0..tries {
+                let mut shuffled = old.clone();
+                shuffled.shuffle(&mut rng);
+
+                if diff.holds(&old, &shuffled) {
+                    new = Some(shuffled);
+                    break;
+                }
+            }
+        }
+
+        // A derangement differs in every position
+        let new = new.or_else(|| _DERANGE(&old, &mut rng).filter(|new| diff.holds(&old, new)))
+                     .ok_or(GenError::AttemptsExhausted)?;
+
+        *self.key.borrow_mut() = new.into_iter().collect::<String>().into();
+        self.record_metadata();
+
+        Ok(())
+    }
+
+    /// Generate a new key like [`join`](RandKey::join), until it differs from the previous one as much as `diff` asks for.
+    ///
+    /// Return `GenError::AttemptsExhausted` if none of the attempts does, the key is left as the last attempt.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, Difference};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join()?;
+    /// let old: Vec<char> = r_p.key().chars().collect();
+    ///
+    /// r_p.join_differing(Difference::Everywhere)?;
+    /// assert!(r_p.key().chars().zip(old.iter()).all(|(new, old)| new != *old));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_differing(&self, diff: Difference) -> Result<(), GenError> {
+        let old: Vec<char> = self.key.borrow().chars().collect();
+
+        self.join_filtered(|key| diff.holds(&old, &key.chars().collect::<Vec<_>>()), _MAX_RETRIES)
+    }
+}
+
+
+impl RandKey {
+    /// Replace each character of the key by a random different one of the same kind from the data,
+    /// keeping the positions and the counts, for a variant of the key as strong as it.
+    ///
+    /// The kinds are the ones of [`class_map`](RandKey::class_map). The check digit isn't replaced
+    /// but computed again for the new characters, and with `Strategy::Unique` no new character is there twice.
+    /// Return `GenError::MissChar` if the data has no other character for one of them, the key is left unchanged.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ToRandKey};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join()?;
+    /// let old = r_p.key();
+    ///
+    /// r_p.rotate_classes()?;
+    /// assert!(r_p.key().chars().zip(old.chars()).all(|(new, old)| new != old));
+    /// assert_eq!(r_p.class_map(), old.to_randkey()?.class_map());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn rotate_classes(&self) -> Result<(), GenError> {
+        let mut rng = thread_rng();
+        let key: Vec<char> = self.key.borrow().chars().collect();
+        let classes = self.class_map();
+        let body = if self.CHECKSUM.is_some() { key.len().saturating_sub(1) } else { key.len() };
+
+        let mut picked = vec![""; body];
+        if self.STRATEGY == Strategy::Unique {
+            // All the characters of a kind at once, drawn again until none is the one it replaces
+            for class in [Alphabetic, Punctuation, Digit] {
+                let at: Vec<usize> = (0..body).filter(|i| classes[*i] == class).collect();
+
+                let distinct = (0.._MAX_RETRIES).find_map(|_| {
+                                                    let distinct = _DISTINCT(self.data(class), at.len(), &mut rng).ok()?;
+                                                    distinct.iter().zip(at.iter()).all(|(s, i)| !s.starts_with(key[*i])).then_some(distinct)
+                                                })
+                                                .ok_or(GenError::MissChar)?;
+
+                for (s, i) in distinct.into_iter().zip(at) {
+                    picked[i] = s;
+                }
+            }
+        } else {
+            for (i, p) in picked.iter_mut().enumerate() {
+                let others: Vec<&String> = self.data(classes[i]).iter().filter(|s| !s.starts_with(key[i])).collect();
+                *p = others.choose(&mut rng).ok_or(GenError::MissChar)?;
+            }
+        }
+
+        let mut rotated = picked.concat();
+        if let Some(checksum) = self.CHECKSUM {
+            rotated.push(checksum.digit(&rotated)?);
+        }
+
+        *self.key.borrow_mut() = rotated.into();
+        self.record_metadata();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{RandKey, GenError};
+
+
+
+
+/// The data of a `RandKey` at some point, to go back to with [`restore_data`](RandKey::restore_data)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSnapshot {
+    data: Vec<Vec<String>>,
+}
+
+
+impl DataSnapshot {
+    /// Return the letters, the symbols and the numbers of the snapshot
+    #[inline]
+    pub fn data(&self) -> &[Vec<String>] { &self.data }
+}
+
+
+impl RandKey {
+    /// Return a copy of the data, without the rest of this `RandKey`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "2", "3")?;
+    /// let snapshot = r_p.snapshot_data();
+    ///
+    /// r_p.del_item(&["0", "1", "2"])?;
+    /// r_p.replace_data(&["a", "-", "9"])?;
+    /// assert_eq!(r_p.data(Digit), vec!["9"]);
+    ///
+    /// // Undo
+    /// r_p.restore_data(snapshot)?;
+    /// assert_eq!(r_p.data(Digit).len(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn snapshot_data(&self) -> DataSnapshot { DataSnapshot { data: self.DATA.clone() } }
+
+    /// Put back the data of `snapshot`
+    ///
+    /// Return `GenError::MissChar` like [`replace_data`](RandKey::replace_data),
+    /// and leave the data as it is, if the snapshot misses a kind of characters the counts need now.
+    #[inline]
+    pub fn restore_data(&mut self, snapshot: DataSnapshot) -> Result<(), GenError> {
+        let previous = std::mem::replace(&mut self.DATA, snapshot.data);
+
+        self.check_data().inspect_err(|_| self.DATA = previous)
+    }
+}
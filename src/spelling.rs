@@ -0,0 +1,197 @@
+//! Keys spelled out in words, to read them over the phone.
+//!
+//! The words come from a [`SpellingAlphabet`], a table of a character to its word:
+//! the built-in ICAO and German ones, or any other like the alphabet of a company.
+use {
+    std::{collections::BTreeMap, iter::FromIterator, str::FromStr},
+    crate::{RandKey, GenError},
+};
+
+
+
+
+/// The ICAO radiotelephony alphabet, also known as the NATO one, with the English names of the digits and the symbols
+const _ICAO: &[(char, &str)] = &[
+    ('a', "Alfa"), ('b', "Bravo"), ('c', "Charlie"), ('d', "Delta"), ('e', "Echo"), ('f', "Foxtrot"),
+    ('g', "Golf"), ('h', "Hotel"), ('i', "India"), ('j', "Juliett"), ('k', "Kilo"), ('l', "Lima"),
+    ('m', "Mike"), ('n', "November"), ('o', "Oscar"), ('p', "Papa"), ('q', "Quebec"), ('r', "Romeo"),
+    ('s', "Sierra"), ('t', "Tango"), ('u', "Uniform"), ('v', "Victor"), ('w', "Whiskey"), ('x', "X-ray"),
+    ('y', "Yankee"), ('z', "Zulu"),
+    ('0', "Zero"), ('1', "One"), ('2', "Two"), ('3', "Three"), ('4', "Four"),
+    ('5', "Five"), ('6', "Six"), ('7', "Seven"), ('8', "Eight"), ('9', "Nine"),
+    ('!', "Exclamation"), ('"', "Quote"), ('#', "Hash"), ('$', "Dollar"), ('%', "Percent"), ('&', "Ampersand"),
+    ('\'', "Apostrophe"), ('(', "Open-paren"), (')', "Close-paren"), ('*', "Asterisk"), ('+', "Plus"), (',', "Comma"),
+    ('-', "Dash"), ('.', "Period"), ('/', "Slash"), (':', "Colon"), (';', "Semicolon"), ('<', "Less-than"),
+    ('=', "Equals"), ('>', "Greater-than"), ('?', "Question"), ('@', "At"), ('[', "Open-bracket"), ('\\', "Backslash"),
+    (']', "Close-bracket"), ('^', "Caret"), ('_', "Underscore"), ('`', "Backtick"), ('{', "Open-brace"), ('|', "Pipe"),
+    ('}', "Close-brace"), ('~', "Tilde"),
+];
+
+
+/// The German spelling alphabet of DIN 5009:2022, with the German names of the digits and the symbols
+const _GERMAN: &[(char, &str)] = &[
+    ('a', "Aachen"), ('b', "Berlin"), ('c', "Chemnitz"), ('d', "Düsseldorf"), ('e', "Essen"), ('f', "Frankfurt"),
+    ('g', "Goslar"), ('h', "Hamburg"), ('i', "Ingelheim"), ('j', "Jena"), ('k', "Köln"), ('l', "Leipzig"),
+    ('m', "München"), ('n', "Nürnberg"), ('o', "Offenbach"), ('p', "Potsdam"), ('q', "Quickborn"), ('r', "Rostock"),
+    ('s', "Salzwedel"), ('t', "Tübingen"), ('u', "Unna"), ('v', "Völklingen"), ('w', "Wuppertal"), ('x', "Xanten"),
+    ('y', "Ypsilon"), ('z', "Zwickau"),
+    ('ä', "Umlaut-Aachen"), ('ö', "Umlaut-Offenbach"), ('ü', "Umlaut-Unna"), ('ß', "Eszett"),
+    ('0', "Null"), ('1', "Eins"), ('2', "Zwei"), ('3', "Drei"), ('4', "Vier"),
+    ('5', "Fünf"), ('6', "Sechs"), ('7', "Sieben"), ('8', "Acht"), ('9', "Neun"),
+    ('!', "Ausrufezeichen"), ('"', "Anführungszeichen"), ('#', "Raute"), ('$', "Dollar"), ('%', "Prozent"),
+    ('&', "Und-Zeichen"), ('\'', "Apostroph"), ('(', "Klammer-auf"), (')', "Klammer-zu"), ('*', "Stern"),
+    ('+', "Plus"), (',', "Komma"), ('-', "Bindestrich"), ('.', "Punkt"), ('/', "Schrägstrich"),
+    (':', "Doppelpunkt"), (';', "Semikolon"), ('<', "Kleiner-als"), ('=', "Gleich"), ('>', "Größer-als"),
+    ('?', "Fragezeichen"), ('@', "At"), ('[', "Eckige-Klammer-auf"), ('\\', "Backslash"), (']', "Eckige-Klammer-zu"),
+    ('^', "Zirkumflex"), ('_', "Unterstrich"), ('`', "Gravis"), ('{', "Geschweifte-Klammer-auf"), ('|', "Senkrechter-Strich"),
+    ('}', "Geschweifte-Klammer-zu"), ('~', "Tilde"),
+];
+
+
+/// Return the letter a table looks `c` up by, the lowercase one
+#[inline]
+fn _TABLE_KEY(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+
+/// A table of the words to spell the characters in
+///
+/// The letters are looked up regardless of their case, and their case is the one of the word:
+/// `a` is spelled `alfa` and `A` is spelled `ALFA`. The other characters are spelled as in the table.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::spelling::SpellingAlphabet;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let icao = SpellingAlphabet::icao();
+/// assert_eq!(icao.spell("aB3-")?, vec!["alfa", "BRAVO", "Three", "Dash"]);
+/// assert_eq!(SpellingAlphabet::german().spell("Kö")?, vec!["KÖLN", "umlaut-offenbach"]);
+///
+/// // The alphabet of a company, from a file of `<character> <word>` lines
+/// let acme: SpellingAlphabet = "# Acme\na Anvil\nb Bomb\n1 Uno".parse()?;
+/// assert_eq!(acme.spell("Ab1")?, vec!["ANVIL", "bomb", "Uno"]);
+/// assert!(acme.spell("c").is_err());
+///
+/// // Or the ICAO one with another word
+/// let custom = SpellingAlphabet::icao().with('x', "Xylophone");
+/// assert_eq!(custom.spell("x")?, vec!["xylophone"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpellingAlphabet {
+    words: BTreeMap<char, String>,
+}
+
+
+impl SpellingAlphabet {
+    /// Return an alphabet without words
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Return the ICAO radiotelephony alphabet, also known as the NATO one,
+    /// with the digits and the ASCII symbols in English
+    #[inline]
+    pub fn icao() -> Self { _ICAO.iter().map(|(c, w)| (*c, *w)).collect() }
+
+    /// Return the German spelling alphabet of DIN 5009:2022, with the umlauts,
+    /// and the digits and the ASCII symbols in German
+    #[inline]
+    pub fn german() -> Self { _GERMAN.iter().map(|(c, w)| (*c, *w)).collect() }
+
+    /// Spell `c` as `word`, instead of its word so far if it has one
+    #[inline]
+    pub fn with(mut self, c: char, word: impl Into<String>) -> Self {
+        self.words.insert(_TABLE_KEY(c), word.into());
+        self
+    }
+
+    /// Return the word of `c`, in the case of `c` if it's a letter
+    #[inline]
+    pub fn word(&self, c: char) -> Option<String> {
+        let word = self.words.get(&_TABLE_KEY(c))?;
+
+        Some(if c.is_uppercase() {
+            word.to_uppercase()
+        } else if c.is_lowercase() {
+            word.to_lowercase()
+        } else {
+            word.clone()
+        })
+    }
+
+    /// Return the words of the characters of `key`
+    ///
+    /// Return `GenError::InvalidChar` if the alphabet has no word for one of them.
+    #[inline]
+    pub fn spell(&self, key: &str) -> Result<Vec<String>, GenError> {
+        key.chars().map(|c| self.word(c).ok_or(GenError::InvalidChar)).collect()
+    }
+}
+
+
+impl<W: Into<String>> FromIterator<(char, W)> for SpellingAlphabet {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (char, W)>>(iter: I) -> Self {
+        iter.into_iter().fold(SpellingAlphabet::new(), |alphabet, (c, w)| alphabet.with(c, w))
+    }
+}
+
+
+impl FromStr for SpellingAlphabet {
+    type Err = GenError;
+
+    /// Parse an alphabet of `<character> <word>` lines, skipping the empty ones and the comments starting with `#`
+    ///
+    /// The word may have spaces. Spell `#` itself with [`with`](SpellingAlphabet::with). Return `GenError::InvalidAlphabet` if a line isn't a character and a word.
+    #[inline]
+    fn from_str(table: &str) -> Result<Self, GenError> {
+        let mut alphabet = SpellingAlphabet::new();
+
+        for (i, line) in table.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut chars = line.chars();
+            let c = chars.next().unwrap();
+            let word = chars.as_str();
+            if !word.starts_with(char::is_whitespace) || word.trim().is_empty() {
+                return Err(GenError::InvalidAlphabet(format!("line {} isn't a character and a word", i + 1)));
+            }
+
+            alphabet = alphabet.with(c, word.trim());
+        }
+
+        Ok(alphabet)
+    }
+}
+
+
+impl RandKey {
+    /// Return the words of the characters of the current key, in `alphabet`
+    ///
+    /// Return `GenError::InvalidChar` if the alphabet has no word for one of them.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{ToRandKey, spelling::SpellingAlphabet};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "Hi5!".to_randkey()?;
+    /// assert_eq!(r_p.spell_out(&SpellingAlphabet::icao())?.join(" "), "HOTEL india Five Exclamation");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn spell_out(&self, alphabet: &SpellingAlphabet) -> Result<Vec<String>, GenError> { alphabet.spell(&self.key.borrow()) }
+}
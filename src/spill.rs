@@ -0,0 +1,145 @@
+use {
+    memmap2::MmapMut,
+    crate::{
+        RandKey, GenError, Strategy,
+        prelude::AsBiguint,
+        parallel::parallel_available,
+        utils::*,
+    },
+    std::{fs::OpenOptions, io::Write, path::Path},
+};
+
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+
+
+
+impl RandKey {
+    /// Return the length from which [`join_to_file`](RandKey::join_to_file) generates the key on disk
+    #[inline]
+    pub fn spill_threshold(&self) -> String { self.SPILL.borrow().to_string() }
+
+    /// Set the length from which [`join_to_file`](RandKey::join_to_file) generates the key on disk
+    #[inline]
+    pub fn set_spill_threshold(&self, val: impl AsRef<str>) -> Result<(), GenError> {
+        *self.SPILL.borrow_mut() = val.as_biguint()?;
+        Ok(())
+    }
+
+    /// Generate the password and write it to the file at `path`
+    ///
+    /// Keys shorter than the spill threshold are generated by [`join`](RandKey::join) and kept in the `key` field.
+    /// The bigger ones are generated and shuffled right in the memory-mapped file, with the seeds and the check digit,
+    /// so the RAM they take is managed by the OS page cache. The `key` field is emptied then,
+    /// and the metadata are the ones of the key in the file.
+    ///
+    /// The file is created readable by its owner only on Unix.
+    /// Return `GenError::InconsistentField` if a key on disk would need [rules](RandKey::add_rule),
+    /// `Strategy::Unique` or `Strategy::Coverage`, and `GenError::InvalidChar` if the data isn't all ASCII.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let path = std::env::temp_dir().join("rand_key_spill_doc");
+    /// let r_p = RandKey::new("100000", "200", "300")?;
+    /// r_p.set_spill_threshold("1000")?;
+    /// r_p.join_to_file(&path)?;
+    /// assert_eq!(std::fs::read(&path)?.len(), 100500);
+    /// assert!(r_p.is_empty());
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_to_file(&self, path: impl AsRef<Path>) -> Result<(), GenError> {
+        self.check_data()?;
+        self.check_entropy()?;
+        self.check_pools()?;
+
+        let mut file = OpenOptions::new();
+        file.read(true).write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        file.mode(0o600);
+
+        if &self.ltr_cnt + &self.sbl_cnt + &self.num_cnt < *self.SPILL.borrow() {
+            self.join()?;
+            file.open(path)?.write_all(self.key.borrow().as_bytes())?;
+            return Ok(());
+        }
+
+        // The regions are filled in place, there's no buffer to keep characters apart, cover the data or place them
+        if !self.RULES.is_empty() || matches!(self.STRATEGY, Strategy::Unique | Strategy::Coverage) {
+            return Err(GenError::InconsistentField);
+        }
+        // A byte per character
+        if !self.DATA.iter().flatten().all(|c| c.is_ascii()) {
+            return Err(GenError::InvalidChar);
+        }
+
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let counts = _COUNTS([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM)?;
+        let body = counts.iter().try_fold(0, |acc: usize, c| acc.checked_add(*c)).ok_or(GenError::AllocationFailed)?;
+        let total = body.checked_add(self.CHECKSUM.map_or(0, |_| 1)).ok_or(GenError::AllocationFailed)?;
+        let (_, unit) = self.STRATEGY.resolve(body, self.UNIT.borrow().to_usize().unwrap_or(usize::MAX).max(1), false);
+        let seeding = self.SEEDING;
+
+        let path = path.as_ref();
+        let file = file.open(path)?;
+        file.set_len(total as u64)?;
+
+        // The file isn't shared with anyone else while it's being generated.
+        let mut map = unsafe { MmapMut::map_mut(&file)? };
+
+        let (ltr, rest) = map[..body].split_at_mut(counts[0]);
+        let (sbl, num) = rest.split_at_mut(counts[1]);
+
+        for (kind, (region, data)) in vec![ltr, sbl, num].into_iter().zip(self.DATA.iter()).enumerate() {
+            let pool: Vec<u8> = data.iter().map(|c| c.as_bytes()[0]).collect();
+
+            // The units are seeded like the ones of `join`
+            let pick = |(i, chunk): (usize, &mut [u8])| {
+                let mut rng = seeding.unit_rng(kind, i);
+                chunk.iter_mut().for_each(|b| *b = pool[rng.gen_range(0, pool.len())]);
+            };
+
+            if parallel_available() {
+                region.par_chunks_mut(unit).enumerate().for_each(pick);
+            } else {
+                region.chunks_mut(unit).enumerate().for_each(pick);
+            }
+        }
+
+        _SHUFFLE(&mut map[..body], self.SEED);
+
+        if let Some(checksum) = self.CHECKSUM {
+            let digit = checksum.digit(std::str::from_utf8(&map[..body]).expect("ASCII characters are valid UTF-8"))?;
+            map[body] = digit as u8;
+        }
+        map.flush()?;
+
+        #[cfg(any(feature = "metrics", feature = "audit-log"))]
+        let key = std::str::from_utf8(&map).expect("ASCII characters are valid UTF-8");
+
+        #[cfg(feature = "metrics")]
+        crate::telemetry::_RECORD(Ok(key), start.elapsed());
+
+        // The key isn't issued if it can't be recorded
+        #[cfg(feature = "audit-log")]
+        if let Err(e) = crate::audit_log::_RECORD(|| self.config(), key) {
+            drop(map);
+            std::fs::remove_file(path)?;
+            return Err(e);
+        }
+
+        *self.key.borrow_mut() = Default::default();
+        self.record_metadata();
+
+        Ok(())
+    }
+}
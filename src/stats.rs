@@ -0,0 +1,130 @@
+use {
+    crate::{RandKey, GenError, PoolWarning, parallel::_THREADS, utils::_GENERATE},
+    std::{
+        time::{Duration, Instant},
+        fmt::{self, Display, Formatter},
+    },
+};
+
+
+
+
+/// Timings of one generation, returned by [`RandKey::join_with_stats`](crate::RandKey::join_with_stats)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GenStats {
+    /// Time of the whole generation
+    pub wall:     Duration,
+    /// Time spent picking the characters from the data
+    pub sampling: Duration,
+    /// Time spent shuffling the picked characters
+    pub shuffle:  Duration,
+    /// Time spent collecting the shuffled characters into the key
+    pub collect:  Duration,
+    /// How many units the counts were divided into
+    pub units:    usize,
+    /// Length of the generated key
+    pub chars:    usize,
+    /// The counts which greatly exceed the size of their pool
+    pub warnings: Vec<PoolWarning>,
+    /// Whether the units were picked one after the other, because rayon can't run its threads here,
+    /// see [`parallel_available`](crate::parallel_available)
+    pub sequential: bool,
+}
+
+
+impl GenStats {
+    /// Return how many characters were generated per second
+    #[inline]
+    pub fn chars_per_sec(&self) -> f64 {
+        let secs = self.wall.as_secs_f64();
+        if secs == 0.0 { 0.0 } else { self.chars as f64 / secs }
+    }
+}
+
+
+impl Display for GenStats {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+               "{} chars in {} units{}, {:?} (sampling {:?}, shuffle {:?}, collect {:?}), {:.0} chars/sec",
+               self.chars,
+               self.units,
+               if self.sequential { " (sequential)" } else { "" },
+               self.wall,
+               self.sampling,
+               self.shuffle,
+               self.collect,
+               self.chars_per_sec())
+    }
+}
+
+
+/// What [`RandKey::throughput_probe`] measured
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThroughputReport {
+    /// How many keys were generated
+    pub rounds:  usize,
+    /// Characters generated in total
+    pub chars:   usize,
+    /// Time actually spent
+    pub elapsed: Duration,
+    /// Threads of the rayon pool
+    pub threads: usize,
+}
+
+
+impl ThroughputReport {
+    /// Return how many characters were generated per second
+    #[inline]
+    pub fn chars_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 { 0.0 } else { self.chars as f64 / secs }
+    }
+
+    /// Return how many characters each thread generated per second
+    #[inline]
+    pub fn chars_per_sec_per_thread(&self) -> f64 { self.chars_per_sec() / self.threads.max(1) as f64 }
+}
+
+
+impl RandKey {
+    /// Generate keys for `duration` and report the throughput, the `key` field is left untouched.
+    ///
+    /// At least one key is generated, so it may take longer than `duration` for huge keys.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10000", "0", "0")?;
+    /// let report = r_p.throughput_probe(Duration::from_millis(50))?;
+    /// assert!(report.rounds >= 1);
+    /// assert_eq!(report.chars, report.rounds * 10000);
+    /// println!("{:.0} chars/sec per thread", report.chars_per_sec_per_thread());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn throughput_probe(&self, duration: Duration) -> Result<ThroughputReport, GenError> {
+        let start = Instant::now();
+        let mut report = ThroughputReport { threads: _THREADS(), ..Default::default() };
+
+        while report.rounds == 0 || start.elapsed() < duration {
+            let (key, _) = _GENERATE(
+                [&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt],
+                &self.UNIT.borrow(),
+                &self.DATA,
+                self.CHECKSUM,
+                self.arrangement(),
+            )?;
+            report.rounds += 1;
+            report.chars += key.len();
+        }
+        report.elapsed = start.elapsed();
+
+        Ok(report)
+    }
+}
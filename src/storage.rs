@@ -0,0 +1,193 @@
+//! Where the saved settings are kept, the files of a directory by default,
+//! or a database of the deployment behind its own [`Storage`].
+use {
+    std::{
+        collections::BTreeMap,
+        fmt::Debug,
+        fs,
+        io::{self, ErrorKind},
+        path::PathBuf,
+        sync::{Arc, RwLock},
+    },
+    crate::GenError,
+};
+
+
+
+
+/// Named documents, read and written whole
+///
+/// The names are checked by the users of the storage, they are never empty nor contain a path separator.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{GenError, storage::Storage};
+/// use std::{collections::HashMap, sync::Mutex};
+///
+/// // A table of a database, say
+/// #[derive(Debug, Default)]
+/// struct Table(Mutex<HashMap<String, String>>);
+///
+/// impl Storage for Table {
+///     fn read(&self, name: &str) -> Result<String, GenError> {
+///         self.0.lock().unwrap().get(name).cloned().ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound).into())
+///     }
+///     fn write(&self, name: &str, contents: &str) -> Result<(), GenError> {
+///         self.0.lock().unwrap().insert(name.into(), contents.into());
+///         Ok(())
+///     }
+///     fn list(&self) -> Result<Vec<String>, GenError> {
+///         let mut names: Vec<String> = self.0.lock().unwrap().keys().cloned().collect();
+///         names.sort();
+///         Ok(names)
+///     }
+///     fn remove(&self, name: &str) -> Result<(), GenError> {
+///         self.0.lock().unwrap().remove(name);
+///         Ok(())
+///     }
+/// }
+///
+/// let table = Table::default();
+/// table.write("a", "{}").unwrap();
+/// assert_eq!(table.list().unwrap(), vec!["a"]);
+/// ```
+pub trait Storage: Debug + Send + Sync {
+    /// Return the document saved as `name`, `GenError::Io` of the kind `NotFound` if there is none
+    fn read(&self, name: &str) -> Result<String, GenError>;
+
+    /// Save `contents` as `name`, replacing the old document
+    fn write(&self, name: &str, contents: &str) -> Result<(), GenError>;
+
+    /// Return the names of the saved documents, in alphabetical order
+    fn list(&self) -> Result<Vec<String>, GenError>;
+
+    /// Remove the document saved as `name`
+    fn remove(&self, name: &str) -> Result<(), GenError>;
+}
+
+
+impl<S: Storage + ?Sized> Storage for Arc<S> {
+    #[inline]
+    fn read(&self, name: &str) -> Result<String, GenError> { (**self).read(name) }
+
+    #[inline]
+    fn write(&self, name: &str, contents: &str) -> Result<(), GenError> { (**self).write(name, contents) }
+
+    #[inline]
+    fn list(&self) -> Result<Vec<String>, GenError> { (**self).list() }
+
+    #[inline]
+    fn remove(&self, name: &str) -> Result<(), GenError> { (**self).remove(name) }
+}
+
+
+/// The files `<name>.<extension>` of a directory, created on the first write
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileStorage {
+    dir:       PathBuf,
+    extension: String,
+}
+
+
+impl FileStorage {
+    /// Return the storage of the files `<name>.json` in `dir`
+    #[inline]
+    pub fn new(dir: impl Into<PathBuf>) -> Self { Self::with_extension(dir, "json") }
+
+    /// Return the storage of the files `<name>.<extension>` in `dir`
+    #[inline]
+    pub fn with_extension(dir: impl Into<PathBuf>, extension: &str) -> Self {
+        FileStorage { dir: dir.into(), extension: extension.into() }
+    }
+
+    /// Return the directory of the files
+    #[inline]
+    pub fn dir(&self) -> &PathBuf { &self.dir }
+
+    #[inline]
+    fn path(&self, name: &str) -> PathBuf { self.dir.join(format!("{}.{}", name, self.extension)) }
+}
+
+
+impl Storage for FileStorage {
+    #[inline]
+    fn read(&self, name: &str) -> Result<String, GenError> { Ok(fs::read_to_string(self.path(name))?) }
+
+    #[inline]
+    fn write(&self, name: &str, contents: &str) -> Result<(), GenError> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(name), contents)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn list(&self) -> Result<Vec<String>, GenError> {
+        if !self.dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut names = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| *e == *self.extension) {
+                if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+
+        Ok(names)
+    }
+
+    #[inline]
+    fn remove(&self, name: &str) -> Result<(), GenError> {
+        fs::remove_file(self.path(name))?;
+        Ok(())
+    }
+}
+
+
+/// Documents in memory, for the tests and the processes which don't keep them,
+/// the clones share the documents
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    documents: Arc<RwLock<BTreeMap<String, String>>>,
+}
+
+
+impl MemoryStorage {
+    /// Return a storage without documents
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+}
+
+
+impl Storage for MemoryStorage {
+    #[inline]
+    fn read(&self, name: &str) -> Result<String, GenError> {
+        let documents = self.documents.read().unwrap_or_else(|e| e.into_inner());
+        documents.get(name).cloned().ok_or_else(|| io::Error::new(ErrorKind::NotFound, name.to_string()).into())
+    }
+
+    #[inline]
+    fn write(&self, name: &str, contents: &str) -> Result<(), GenError> {
+        self.documents.write().unwrap_or_else(|e| e.into_inner()).insert(name.into(), contents.into());
+        Ok(())
+    }
+
+    #[inline]
+    fn list(&self) -> Result<Vec<String>, GenError> {
+        Ok(self.documents.read().unwrap_or_else(|e| e.into_inner()).keys().cloned().collect())
+    }
+
+    #[inline]
+    fn remove(&self, name: &str) -> Result<(), GenError> {
+        match self.documents.write().unwrap_or_else(|e| e.into_inner()).remove(name) {
+            Some(_) => Ok(()),
+            None => Err(io::Error::new(ErrorKind::NotFound, name.to_string()).into()),
+        }
+    }
+}
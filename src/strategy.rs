@@ -0,0 +1,327 @@
+use {
+    std::{sync::OnceLock, time::Instant, hint::black_box},
+    rand::{Rng, thread_rng, rngs::StdRng, SeedableRng, seq::SliceRandom},
+    rayon::prelude::*,
+    crate::{RandKey, GenError, SeedStrategy, parallel::_THREADS, utils::{_TRY_BUF, _SHORT_KEY, _Arrangement}},
+};
+
+
+
+
+/// How [`join`](RandKey::join) arranges the characters of a key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Pick the characters of each kind in parallel, by units, then shuffle them all.
+    /// The default, and the fastest with many threads.
+    Shuffle,
+    /// Pick the kind of each character in turn, with the odds of what remains of each kind,
+    /// in a single pass without units nor shuffle.
+    /// It's single-threaded, but needs no memory besides the key.
+    Streaming,
+    /// Pick the characters of each kind without replacement, then shuffle them all,
+    /// so no character is in the key twice.
+    /// Every count has to be at most the size of its pool, and there can't be a check digit.
+    Unique,
+    /// Pick every character of a pool once if its count allows it, the rest of the count at random,
+    /// then shuffle them all, so test data exercises the whole data. There can't be a check digit.
+    Coverage,
+    /// Pick one of the others for the length of the key, measured against the speed of this machine once:
+    /// the short keys by a single unit, the long ones by units spread over the threads of rayon,
+    /// and by streaming if there's a single thread.
+    /// Until it's measured, the keys generated on the threads of rayon take a fixed length instead.
+    /// The unit set by [`set_unit`](RandKey::set_unit) is ignored,
+    /// and a seeded key can change with the machine since the strategy can.
+    Auto,
+}
+
+
+impl Default for Strategy {
+    #[inline]
+    fn default() -> Self { Strategy::Shuffle }
+}
+
+
+/// The shortest keys the `Auto` strategy picks in parallel, measured on the first use
+static _PARALLEL_FROM: OnceLock<usize> = OnceLock::new();
+
+
+/// The length the `Auto` strategy picks in parallel from on the threads of rayon, until it's measured elsewhere
+const _UNCALIBRATED_FROM: usize = 1 << 16;
+
+
+/// Return the length from which picking in parallel beats picking in a single unit
+///
+/// It's the length picked sequentially in four times what it takes rayon to run a task on every thread,
+/// within `[4096, 4194304]`. It's measured outside of the lock of `_PARALLEL_FROM`, since a thread of rayon
+/// blocked on it could be the one the measure waits for, and not on the threads of rayon at all, which take
+/// `_UNCALIBRATED_FROM` then.
+#[inline]
+pub(crate) fn _CALIBRATE() -> usize {
+    if let Some(from) = _PARALLEL_FROM.get() {
+        return *from;
+    }
+    if rayon::current_thread_index().is_some() {
+        return _UNCALIBRATED_FROM;
+    }
+
+    const PROBE: usize = 1 << 14;
+
+    let pool: Vec<u8> = (b'a'..=b'z').collect();
+    let mut rng = thread_rng();
+    let start = Instant::now();
+    let picked: Vec<u8> = (0..PROBE).map(|_| pool[rng.gen_range(0, pool.len())]).collect();
+    black_box(picked);
+    let per_char = start.elapsed().as_nanos().max(1) as f64 / PROBE as f64;
+
+    let overhead = (0..5).map(|_| {
+                             let start = Instant::now();
+                             (0..rayon::current_num_threads()).into_par_iter().for_each(|i| { black_box(i); });
+                             start.elapsed().as_nanos()
+                         })
+                         .min()
+                         .unwrap_or(0) as f64;
+
+    // The threads measuring at once keep the first measure, so `Auto` resolves the same from then on
+    let _ = _PARALLEL_FROM.set(((4.0 * overhead / per_char) as usize).clamp(1 << 12, 1 << 22));
+    *_PARALLEL_FROM.get().expect("set right above")
+}
+
+
+impl Strategy {
+    /// Return the strategy and the unit of a key of `total` characters, the ones of `Auto` picked for it
+    #[inline]
+    pub(crate) fn resolve(self, total: usize, unit: usize, rules: bool) -> (Strategy, usize) {
+        if self != Strategy::Auto {
+            return (self, unit);
+        }
+
+        let threads = _THREADS();
+        if total <= _SHORT_KEY {
+            (Strategy::Shuffle, unit)
+        } else if threads == 1 && !rules {
+            (Strategy::Streaming, unit)
+        } else if threads == 1 || total < _CALIBRATE() {
+            (Strategy::Shuffle, total)
+        } else {
+            // A few units per thread, so the threads finishing first take the rest
+            (Strategy::Shuffle, total.div_ceil(threads * 4).max(_CALIBRATE() / 4))
+        }
+    }
+}
+
+
+/// Return a buffer of `counts[i]` characters picked from `pools[i]` in a random order, with room for `extra` more
+///
+/// The kinds are picked by the seeded generator if there is a seed, so the same kinds are always put in the same order.
+/// The characters are picked by a single generator, the one of the first unit of letters.
+#[inline]
+pub(crate) fn _STREAM<T: Copy>(counts:  [usize; 3],
+                               pools:   &[Vec<T>],
+                               extra:   usize,
+                               seed:    Option<u64>,
+                               seeding: SeedStrategy) -> Result<Vec<T>, GenError> {
+    let mut remaining = counts;
+    let mut total: usize = counts.iter().sum();
+    let mut buf = _TRY_BUF(total + extra)?;
+
+    let mut rng = seeding.unit_rng(0, 0);
+    let mut seeded = seed.map(StdRng::seed_from_u64);
+
+    while total > 0 {
+        let mut n = match &mut seeded {
+            Some(seeded) => seeded.gen_range(0, total),
+            None => rng.gen_range(0, total),
+        };
+
+        let kind = remaining.iter().position(|r| {
+                                       if n < *r {
+                                           true
+                                       } else {
+                                           n -= r;
+                                           false
+                                       }
+                                   })
+                                   .unwrap();
+
+        let pool = &pools[kind];
+        buf.push(pool[rng.gen_range(0, pool.len())]);
+        remaining[kind] -= 1;
+        total -= 1;
+    }
+
+    Ok(buf)
+}
+
+
+/// Return `count` distinct characters picked from `pool` by `rng`
+///
+/// Return `GenError::PoolTooSmall` if `count` exceeds the distinct characters of `pool`.
+#[inline]
+pub(crate) fn _DISTINCT<'a, T: Ord>(pool: &'a [T], count: usize, rng: &mut impl Rng) -> Result<Vec<&'a T>, GenError> {
+    let mut distinct: Vec<&T> = pool.iter().collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+
+    if count > distinct.len() {
+        return Err(GenError::PoolTooSmall(format!("{} unique characters from a pool of {}", count, distinct.len())));
+    }
+
+    Ok(distinct.choose_multiple(rng, count).copied().collect())
+}
+
+
+/// Return a buffer of `counts[i]` distinct characters picked from `pools[i]`, with room for `extra` more
+///
+/// Return `GenError::PoolTooSmall` if a count exceeds the distinct characters of its pool.
+#[inline]
+pub(crate) fn _UNIQUE<T: Copy + Ord>(counts: [usize; 3], pools: &[Vec<T>], extra: usize, seeding: SeedStrategy) -> Result<Vec<T>, GenError> {
+    let mut buf = _TRY_BUF(counts.iter().sum::<usize>() + extra)?;
+
+    for (kind, (count, pool)) in counts.iter().zip(pools).enumerate() {
+        buf.extend(_DISTINCT(pool, *count, &mut seeding.unit_rng(kind, 0))?);
+    }
+
+    Ok(buf)
+}
+
+
+/// Return a buffer of `counts[i]` characters picked from `pools[i]`, with room for `extra` more,
+/// which has every distinct character of a pool if its count is at least the size of the pool
+#[inline]
+pub(crate) fn _COVER<T: Copy + Ord>(counts: [usize; 3], pools: &[Vec<T>], extra: usize, seeding: SeedStrategy) -> Result<Vec<T>, GenError> {
+    let mut buf = _TRY_BUF(counts.iter().sum::<usize>() + extra)?;
+
+    for (kind, (count, pool)) in counts.iter().zip(pools).enumerate() {
+        let mut distinct = pool.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+
+        let covered = if *count >= distinct.len() { distinct.len() } else { 0 };
+        buf.extend_from_slice(&distinct[..covered]);
+
+        let mut rng = seeding.unit_rng(kind, 0);
+        buf.extend((covered..*count).map(|_| pool[rng.gen_range(0, pool.len())]));
+    }
+
+    Ok(buf)
+}
+
+
+impl RandKey {
+    /// Return how the characters of a key are picked and arranged
+    #[inline]
+    pub(crate) fn arrangement(&self) -> _Arrangement<'_> {
+        _Arrangement {
+            seed:        self.SEED,
+            strategy:    self.STRATEGY,
+            seeding:     self.SEEDING,
+            rules:       &self.RULES,
+            check_digit: self.CHECKSUM.is_some(),
+        }
+    }
+
+    /// Return how the characters of a key are arranged
+    #[inline]
+    pub fn strategy(&self) -> Strategy { self.STRATEGY }
+
+    /// Set how the characters of a key are arranged
+    ///
+    /// `Shuffle`, `Streaming` and `Auto` generate the same keys with the same odds,
+    /// `Unique` only the keys without a repeated character, and `Coverage` the keys with the whole data.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, Strategy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("1000000", "10", "10")?;
+    /// r_p.set_strategy(Strategy::Streaming);
+    ///
+    /// let stats = r_p.join_with_stats()?;
+    /// assert_eq!(stats.units, 1);
+    /// assert_eq!(r_p.len(), "1000020");
+    ///
+    /// // No unit to tune
+    /// r_p.set_strategy(Strategy::Auto);
+    /// r_p.join()?;
+    /// assert_eq!(r_p.len(), "1000020");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_strategy(&mut self, strategy: Strategy) { self.STRATEGY = strategy; }
+
+    /// Set whether a key has every character once at most, for the provisioning formats which require it,
+    /// like this `RandKey` was set to `Strategy::Unique`, or back to the default one
+    ///
+    /// Return `GenError::InconsistentField` if there is a check digit.
+    /// The generations return `GenError::PoolTooSmall` once a count exceeds the size of its pool,
+    /// except [`resume`](RandKey::resume) which can't keep the characters apart and returns `GenError::InconsistentField`.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, GenError, ASCIIExcludeCtrl::Digit};
+    /// use std::collections::HashSet;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("20", "5", "10")?;
+    /// r_p.set_unique(true)?;
+    ///
+    /// let key = r_p.generate()?;
+    /// assert_eq!(key.chars().collect::<HashSet<_>>().len(), 35);
+    ///
+    /// r_p.set_cnt(Digit, "11");
+    /// assert!(matches!(r_p.join(), Err(GenError::PoolTooSmall(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_unique(&mut self, unique: bool) -> Result<(), GenError> {
+        if unique {
+            if self.CHECKSUM.is_some() {
+                return Err(GenError::InconsistentField);
+            }
+            self.STRATEGY = Strategy::Unique;
+        } else if self.STRATEGY == Strategy::Unique {
+            self.STRATEGY = Strategy::default();
+        }
+
+        Ok(())
+    }
+
+    /// Set whether a key has every character of the data at least once, for the kinds whose count allows it,
+    /// like this `RandKey` was set to `Strategy::Coverage`, or back to the default one
+    ///
+    /// The characters of the data are put in the key before the rest is picked,
+    /// so it costs the same as the default strategy however big the data is.
+    ///
+    /// The generations return `GenError::InconsistentField` if there is a check digit, which takes a digit out of the count,
+    /// and so do [`join_ordered`](RandKey::join_ordered) and [`resume`](RandKey::resume), which don't cover the data.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "40", "10")?;
+    /// r_p.set_coverage(true);
+    ///
+    /// let key = r_p.generate()?;
+    /// // All 32 symbols and 10 digits, the letters are too few
+    /// assert!((b'!'..=b'~').filter(u8::is_ascii_punctuation).all(|c| key.contains(c as char)));
+    /// assert!((b'0'..=b'9').all(|c| key.contains(c as char)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_coverage(&mut self, coverage: bool) {
+        if coverage {
+            self.STRATEGY = Strategy::Coverage;
+        } else if self.STRATEGY == Strategy::Coverage {
+            self.STRATEGY = Strategy::default();
+        }
+    }
+}
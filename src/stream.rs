@@ -0,0 +1,149 @@
+use {
+    std::{cell::Ref, sync::Arc},
+    crate::{
+        RandKey, GenError, Strategy,
+        seeding::_UnitRng,
+        utils::*,
+    },
+};
+
+
+
+
+/// An iterator yielding a random key character by character, returned by [`RandKey::stream`]
+#[derive(Clone, Debug)]
+pub struct KeyStream {
+    left: [BigUint; 3],
+    data: Vec<Vec<char>>,
+    rng:  _UnitRng,
+}
+
+
+impl Iterator for KeyStream {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let total: BigUint = self.left.iter().sum();
+
+        if total.is_zero() {
+            return None;
+        }
+
+        // Pick the kind with the probability of its share in what's left,
+        // so every arrangement of the kinds is as likely as the shuffle in `join` makes it.
+        let mut pick = self.rng.gen_biguint_below(&total);
+        let kind = self.left
+                       .iter()
+                       .position(|n| if pick < *n { true } else { pick -= n; false })
+                       .unwrap();
+
+        self.left[kind] -= BigUint::one();
+        let pool = &self.data[kind];
+
+        Some(pool[self.rng.gen_range(0, pool.len())])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let total: BigUint = self.left.iter().sum();
+        match total.to_usize() {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+
+/// An iterator over the key in chunks of `n` characters, returned by [`RandKey::chunks`]
+///
+/// The chunks are copied out, because the key lives in a `RefCell` and can't be lent beyond the iterator.
+#[derive(Debug)]
+pub struct KeyChunks<'a> {
+    key: Ref<'a, Arc<String>>,
+    pos: usize,
+    n:   usize,
+}
+
+
+impl Iterator for KeyChunks<'_> {
+    type Item = String;
+
+    #[inline]
+    fn next(&mut self) -> Option<String> {
+        if self.pos >= self.key.len() {
+            return None;
+        }
+
+        let rest = &self.key[self.pos..];
+        let end = rest.char_indices().nth(self.n).map_or(rest.len(), |(i, _)| i);
+        self.pos += end;
+
+        Some(rest[..end].to_string())
+    }
+}
+
+
+impl RandKey {
+    /// Return an iterator yielding a random key with the counts of `RandKey`, without storing it anywhere.
+    ///
+    /// The key isn't recorded in the audit log, it's never whole in this crate.
+    /// The characters are picked like the ones of a single unit of [`join`](RandKey::join), seeded by the [`SeedStrategy`](crate::SeedStrategy).
+    /// Return `GenError::InconsistentField` if there are [rules](RandKey::add_rule), or with `Strategy::Unique` or `Strategy::Coverage`,
+    /// which a key picked character by character can't follow.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// let key: String = r_p.stream()?.collect();
+    /// assert_eq!(key.len(), 15);
+    /// assert_eq!(key.chars().filter(char::is_ascii_digit).count(), 3);
+    /// assert!(r_p.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn stream(&self) -> Result<KeyStream, GenError> {
+        self.check_data()?;
+        self.check_entropy()?;
+        self.check_pools()?;
+
+        if !self.RULES.is_empty() || matches!(self.STRATEGY, Strategy::Unique | Strategy::Coverage) {
+            return Err(GenError::InconsistentField);
+        }
+
+        Ok(KeyStream {
+            left: [self.ltr_cnt.clone(), self.sbl_cnt.clone(), self.num_cnt.clone()],
+            data: self.DATA.iter().map(|v| v.iter().map(_CHAR_FROM_STR).collect()).collect(),
+            rng:  self.SEEDING.unit_rng(0, 0),
+        })
+    }
+
+    /// Return an iterator over the key in chunks of `n` characters, the last one may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, or if the key is mutably borrowed (by `join` or `set_key`) while iterating.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ToRandKey};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = "abcdefghij".to_randkey()?;
+    /// let lines: Vec<String> = r_p.chunks(4).collect();
+    /// assert_eq!(lines, ["abcd", "efgh", "ij"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn chunks(&self, n: usize) -> KeyChunks<'_> {
+        assert_ne!(n, 0, "chunk size must be non-zero");
+        KeyChunks { key: self.key.borrow(), pos: 0, n }
+    }
+}
@@ -0,0 +1,340 @@
+use {
+    std::{
+        cmp::Ordering,
+        str::FromStr,
+        fmt::{self, Display, Formatter},
+    },
+    crate::{
+        RandKey, GenError,
+        checksum::Checksum,
+        utils::{BigUint, Zero, One, ToPrimitive, _CNT, _DEFAULT_DATA, _LOG2, _LOG2_FACTORIAL},
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// Entropy that fills the whole strength bar
+pub(crate) const _FULL_BAR_BITS: f64 = 100.0;
+
+
+/// Rough verdict of a key's strength, based on its entropy
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Strength {
+    /// Less than 28 bits
+    VeryWeak,
+    /// 28 to 35 bits
+    Weak,
+    /// 36 to 59 bits
+    Reasonable,
+    /// 60 to 127 bits
+    Strong,
+    /// 128 bits or more
+    VeryStrong,
+}
+
+
+impl FromStr for Strength {
+    type Err = GenError;
+
+    /// Parse the lowercase, dashed name of a verdict like `"very-weak"`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::strength::Strength;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!("very-strong".parse::<Strength>()?, Strength::VeryStrong);
+    /// assert!("mighty".parse::<Strength>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, GenError> {
+        match s {
+            "very-weak" => Ok(Strength::VeryWeak),
+            "weak" => Ok(Strength::Weak),
+            "reasonable" => Ok(Strength::Reasonable),
+            "strong" => Ok(Strength::Strong),
+            "very-strong" => Ok(Strength::VeryStrong),
+            _ => Err(GenError::UnknownStrength(s.into())),
+        }
+    }
+}
+
+
+/// Strength of the keys a `RandKey` generates
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StrengthReport {
+    /// Entropy of a generated key, in bits
+    pub bits:    f64,
+    /// How many kinds of characters the key is made of
+    pub classes: usize,
+}
+
+
+impl StrengthReport {
+    /// Estimate the strength of an existing key
+    ///
+    /// Every character is worth the bits of the kinds of characters the key contains,
+    /// except the ones repeating or continuing (like `abc` or `321`) the previous character,
+    /// which are worth one bit only.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::StrengthReport;
+    ///
+    /// let weak = StrengthReport::of("aaaaaaaa");
+    /// let strong = StrengthReport::of("q7#Lm2!x");
+    /// assert_eq!(weak.classes, 1);
+    /// assert_eq!(strong.classes, 3);
+    /// assert!(strong.bits > weak.bits);
+    /// ```
+    #[inline]
+    pub fn of(key: &str) -> Self {
+        let data = _DEFAULT_DATA();
+        let chars: Vec<char> = key.chars().collect();
+
+        let present = [
+            chars.iter().any(|c| c.is_ascii_alphabetic()),
+            chars.iter().any(|c| c.is_ascii_punctuation()),
+            chars.iter().any(|c| c.is_ascii_digit()),
+        ];
+        let pool: usize = present.iter().zip(data.iter()).filter(|(p, _)| **p).map(|(_, d)| d.len()).sum();
+        let per_char = if pool > 1 { (pool as f64).log2() } else { 0.0 };
+
+        let bits = chars.iter()
+                        .enumerate()
+                        .fold(0.0, |acc, (i, c)| {
+                            let predictable = i > 0 && (*c as i64 - chars[i - 1] as i64).abs() <= 1;
+                            acc + if predictable { per_char.min(1.0) } else { per_char }
+                        });
+
+        StrengthReport { bits, classes: present.iter().filter(|p| **p).count() }
+    }
+
+    /// Return the verdict for the entropy of this report
+    #[inline]
+    pub fn strength(&self) -> Strength {
+        match self.bits {
+            b if b.is_nan() || b < 28.0 => Strength::VeryWeak,
+            b if b < 36.0 => Strength::Weak,
+            b if b < 60.0 => Strength::Reasonable,
+            b if b < 128.0 => Strength::Strong,
+            _ => Strength::VeryStrong,
+        }
+    }
+
+    /// Render the entropy as a bar of `width` cells, 100 bits or more is a full bar.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::StrengthReport;
+    ///
+    /// let report = StrengthReport { bits: 72.0, classes: 3 };
+    /// assert_eq!(report.render_bar(10), "[#######---] 72 bits");
+    /// ```
+    #[inline]
+    pub fn render_bar(&self, width: usize) -> String {
+        let ratio = (self.bits / _FULL_BAR_BITS).clamp(0.0, 1.0);
+        let filled = ((ratio * width as f64) as usize).min(width);
+
+        format!("[{}{}] {:.0} bits", "#".repeat(filled), "-".repeat(width - filled), self.bits)
+    }
+}
+
+
+/// Return how many letters, symbols and numbers `key` has
+///
+/// Return `GenError::InvalidChar` if it has other characters.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::strength::class_counts;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(class_counts("ab123_c53")?, [3, 1, 5]);
+/// assert!(class_counts("a b").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn class_counts(key: &str) -> Result<[usize; 3], GenError> {
+    let (l, s, n) = _CNT(key)?;
+
+    Ok([l.to_usize().unwrap(), s.to_usize().unwrap(), n.to_usize().unwrap()])
+}
+
+
+/// The default scorer of [`RandKey::join_best_of`], the estimated entropy of `key` in bits
+#[inline]
+pub fn score(key: &str) -> f64 { StrengthReport::of(key).bits }
+
+
+impl Display for StrengthReport {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { write!(f, "{:.0} bits ({:?})", self.bits, self.strength()) }
+}
+
+
+/// Return the strength of the keys of the counts `cnts` picked from `data`
+#[inline]
+pub(crate) fn _STRENGTH(cnts: [&BigUint; 3], data: &[Vec<String>], checksum: Option<Checksum>) -> StrengthReport {
+    // The check digit adds no entropy
+    let num_cnt = match checksum {
+        Some(_) if !cnts[2].is_zero() => cnts[2] - BigUint::one(),
+        _ => cnts[2].clone(),
+    };
+
+    let counts = [(cnts[0], data[0].len()), (cnts[1], data[1].len()), (&num_cnt, data[2].len())];
+
+    let total = counts.iter().map(|(cnt, _)| *cnt).sum();
+
+    // The arrangements of the kinds, `log2(total! / (ltr! sbl! num!))`
+    let mut arrangements = _LOG2_FACTORIAL(&total);
+    let mut bits = 0.0;
+    let mut classes = 0;
+
+    for (cnt, pool) in counts.iter() {
+        if cnt.is_zero() || *pool == 0 {
+            continue;
+        }
+        classes += 1;
+        arrangements -= _LOG2_FACTORIAL(cnt);
+        // A pool of one character adds no bits, and no infinity times zero
+        if *pool > 1 {
+            bits += cnt.to_f64().unwrap_or(f64::INFINITY) * (*pool as f64).log2();
+        }
+    }
+
+    // The factorials too large for a f64 are infinities, the arrangements are then `Σ cnt * log2(total / cnt)` by Stirling's
+    if !arrangements.is_finite() {
+        arrangements = counts.iter()
+                             .filter(|(cnt, pool)| !cnt.is_zero() && *pool != 0)
+                             .map(|(cnt, _)| (cnt.to_f64().unwrap_or(f64::INFINITY), _LOG2(&total) - _LOG2(cnt)))
+                             .filter(|(_, ratio)| *ratio > 0.0)
+                             .map(|(cnt, ratio)| cnt * ratio)
+                             .sum();
+    }
+
+    StrengthReport { bits: bits + arrangements, classes }
+}
+
+
+/// Check the entropy of the keys of the counts `cnts` picked from `data` isn't below `min`
+#[inline]
+pub(crate) fn _CHECK_ENTROPY(cnts: [&BigUint; 3], data: &[Vec<String>], checksum: Option<Checksum>, min: f64) -> Result<(), GenError> {
+    if min <= 0.0 {
+        return Ok(());
+    }
+
+    let bits = _STRENGTH(cnts, data, checksum).bits;
+    // Should the entropy be NaN, the floor isn't met; an infinite entropy is above any floor
+    if bits.is_nan() || bits < min {
+        return Err(GenError::BelowEntropyFloor(bits, min));
+    }
+
+    Ok(())
+}
+
+
+impl RandKey {
+    /// Return the strength of the keys this `RandKey` generates
+    ///
+    /// The entropy takes both the characters picked from the data and their positions into account.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// let report = r_p.strength();
+    /// assert_eq!(report.classes, 3);
+    /// assert!(report.bits > 90.0);
+    /// println!("{}", report.render_bar(20));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn strength(&self) -> StrengthReport { _STRENGTH([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM) }
+
+    /// Return the entropy in bits below which the keys aren't generated, 0 by default
+    #[inline]
+    pub fn min_entropy(&self) -> f64 { self.MIN_ENTROPY }
+
+    /// Refuse to generate keys with less than `bits` of entropy, as estimated by [`strength`](RandKey::strength)
+    ///
+    /// The floor is checked when a key is generated, so shrinking the counts or the data
+    /// below it makes [`join`](RandKey::join) fail instead of generating weaker keys.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, GenError};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("12", "2", "2")?;
+    /// r_p.set_min_entropy(80.0);
+    /// r_p.join()?;
+    ///
+    /// r_p.del_item(&["A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M"])?;
+    /// r_p.del_item(&["N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z"])?;
+    /// r_p.del_item(&["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m"])?;
+    /// assert!(matches!(r_p.join(), Err(GenError::BelowEntropyFloor(..))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_min_entropy(&mut self, bits: f64) { self.MIN_ENTROPY = bits; }
+
+    /// Check the entropy of the keys isn't below the floor
+    #[inline]
+    pub(crate) fn check_entropy(&self) -> Result<(), GenError> {
+        _CHECK_ENTROPY([&self.ltr_cnt, &self.sbl_cnt, &self.num_cnt], &self.DATA, self.CHECKSUM, self.MIN_ENTROPY)
+    }
+
+    /// Compare the strength of keys generated by `self` and `other`
+    ///
+    /// Return `Greater` if `self` has at least the bits and the kinds of characters in use of `other`,
+    /// and more of one of them, `Equal` if both are the same. When one has more bits and the other more kinds,
+    /// the bits decide: the kinds are already counted in them. The bits are ordered totally, so the
+    /// verdict of the entropy of `Greater` is never lower than the one of `other`.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::RandKey;
+    /// use std::cmp::Ordering;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let old = RandKey::new("8", "0", "2")?;
+    /// let new = RandKey::new("12", "2", "3")?;
+    /// assert_eq!(new.compare_strength(&old), Ordering::Greater);
+    /// assert_eq!(old.compare_strength(&new), Ordering::Less);
+    /// assert_eq!(new.compare_strength(&new.clone()), Ordering::Equal);
+    ///
+    /// // More bits with fewer kinds of characters
+    /// assert_eq!(RandKey::new("30", "0", "0")?.compare_strength(&new), Ordering::Greater);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn compare_strength(&self, other: &RandKey) -> Ordering {
+        let (lhs, rhs) = (self.strength(), other.strength());
+
+        lhs.bits
+           .total_cmp(&rhs.bits)
+           .then(lhs.classes.cmp(&rhs.classes))
+    }
+}
@@ -0,0 +1,82 @@
+//! The metrics of the feature `metrics`, recorded with the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! Install any recorder, like `metrics-exporter-prometheus`, to export them.
+//! Every generation of a key is recorded, by [`RandKey`](crate::RandKey), [`SharedRandKey`](crate::SharedRandKey)
+//! and the services built on them.
+use {
+    std::time::Duration,
+    metrics::{counter, histogram},
+    crate::GenError,
+};
+
+
+
+
+/// Counter of the keys generated
+pub const KEYS_GENERATED: &str = "rand_key_keys_generated_total";
+
+
+/// Counter of the characters generated
+pub const CHARS_GENERATED: &str = "rand_key_chars_generated_total";
+
+
+/// Histogram of the time a generation takes, in seconds
+pub const GENERATION_SECONDS: &str = "rand_key_generation_seconds";
+
+
+/// Counter of the failed generations, labeled by the `kind` of `GenError`, like `MissChar`
+pub const FAILURES: &str = "rand_key_failures_total";
+
+
+/// Return the name of the variant of `e`
+#[inline]
+#[rustfmt::skip]
+pub(crate) fn _KIND(e: &GenError) -> &'static str {
+    use GenError::*;
+
+    match e {
+        MissChar              => "MissChar",
+        DelNonExistValue      => "DelNonExistValue",
+        InvalidUnit           => "InvalidUnit",
+        InvalidChar           => "InvalidChar",
+        InvalidNumber         => "InvalidNumber",
+        InconsistentField     => "InconsistentField",
+        AttemptsExhausted     => "AttemptsExhausted",
+        InvalidShares         => "InvalidShares",
+        NoCheckDigit          => "NoCheckDigit",
+        InvalidProfile(_)     => "InvalidProfile",
+        RateLimited           => "RateLimited",
+        WorkerUnavailable(_)  => "WorkerUnavailable",
+        UnknownPreset(_)      => "UnknownPreset",
+        BatchTooLarge(_)      => "BatchTooLarge",
+        OutOfData             => "OutOfData",
+        UnknownStrength(_)    => "UnknownStrength",
+        InvalidPassphrase     => "InvalidPassphrase",
+        Gpg(_)                => "Gpg",
+        InvalidCharsets(_)    => "InvalidCharsets",
+        AllocationFailed      => "AllocationFailed",
+        WeakKey(_)            => "WeakKey",
+        InvalidArmor(_)       => "InvalidArmor",
+        PoolTooSmall(_)       => "PoolTooSmall",
+        UnsatisfiableRules(_) => "UnsatisfiableRules",
+        BelowEntropyFloor(..) => "BelowEntropyFloor",
+        InvalidHeader(_)      => "InvalidHeader",
+        InvalidSignature(_)   => "InvalidSignature",
+        InvalidAlphabet(_)    => "InvalidAlphabet",
+        Io(_)                 => "Io",
+    }
+}
+
+
+/// Record a generation which took `elapsed`
+#[inline]
+pub(crate) fn _RECORD(result: Result<&str, &GenError>, elapsed: Duration) {
+    match result {
+        Ok(key)               => {
+            counter!(KEYS_GENERATED).increment(1);
+            counter!(CHARS_GENERATED).increment(key.chars().count() as u64);
+            histogram!(GENERATION_SECONDS).record(elapsed.as_secs_f64());
+        }
+        Err(e)                => counter!(FAILURES, "kind" => _KIND(e)).increment(1),
+    }
+}
@@ -0,0 +1,183 @@
+//! Fake credential records to seed the systems under test, a username, a key and a creation date each.
+use {
+    std::{
+        collections::HashSet,
+        io::Write,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+    rand::{thread_rng, Rng, seq::SliceRandom},
+    crate::{RandKey, GenError, export::_CSV_FIELD, passphrase::Language, shuffle::_MAX_RETRIES},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// How far back the records are created by default, a year
+pub const DEFAULT_SPAN: Duration = Duration::from_secs(365 * 24 * 3600);
+
+
+/// A fake user and its key
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CredentialRecord {
+    /// Two words and a number, like `amber.falcon42`
+    pub username: String,
+    pub key:      String,
+    /// When the user was created, in seconds since the Unix epoch
+    pub created:  u64,
+}
+
+
+impl CredentialRecord {
+    /// Return the creation date in ISO 8601, like `2024-03-09`
+    #[inline]
+    pub fn created_date(&self) -> String {
+        // The civil date of a day count, by Howard Hinnant's algorithm
+        let days = (self.created / 86400) as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let doe = days.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+
+/// A generator of records, with the keys of a profile and the usernames of a wordlist
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, testdata::{self, RecordGenerator}};
+/// use std::collections::HashSet;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let records = RecordGenerator::new(RandKey::new("10", "2", "4")?).generate(100)?;
+/// assert_eq!(records.len(), 100);
+/// assert_eq!(records.iter().map(|r| &r.username).collect::<HashSet<_>>().len(), 100);
+/// assert!(records.iter().all(|r| r.key.len() == 16 && r.created_date().len() == 10));
+///
+/// let mut csv = Vec::new();
+/// testdata::to_csv(&records, &mut csv)?;
+/// assert!(String::from_utf8(csv)?.starts_with("username,key,created\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RecordGenerator {
+    profile: RandKey,
+    words:   Vec<String>,
+    since:   SystemTime,
+    until:   SystemTime,
+}
+
+
+impl RecordGenerator {
+    /// Return a generator of the keys of `profile`, the usernames of the English wordlist,
+    /// created within [`DEFAULT_SPAN`] before now
+    #[inline]
+    pub fn new(profile: RandKey) -> Self {
+        let until = SystemTime::now();
+
+        RecordGenerator {
+            profile,
+            words: Language::English.wordlist().into_iter().map(String::from).collect(),
+            since: until.checked_sub(DEFAULT_SPAN).unwrap_or(UNIX_EPOCH),
+            until,
+        }
+    }
+
+    /// Make the usernames of `words`
+    #[inline]
+    pub fn wordlist(mut self, words: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.words = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Create the records between `since` and `until`
+    #[inline]
+    pub fn created_between(mut self, since: SystemTime, until: SystemTime) -> Self {
+        self.since = since;
+        self.until = until.max(since);
+        self
+    }
+
+    /// Generate `n` records, with distinct usernames
+    ///
+    /// Return `GenError::MissChar` if the wordlist is empty,
+    /// and `GenError::AttemptsExhausted` if it has too few words for `n` distinct usernames.
+    #[inline]
+    pub fn generate(&self, n: usize) -> Result<Vec<CredentialRecord>, GenError> {
+        if self.words.is_empty() {
+            return Err(GenError::MissChar);
+        }
+
+        let mut rng = thread_rng();
+        let secs = |t: SystemTime| t.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let (since, until) = (secs(self.since), secs(self.until));
+
+        let mut usernames = HashSet::with_capacity(n);
+        for _ in 0..n.saturating_mul(_MAX_RETRIES) {
+            if usernames.len() == n {
+                break;
+            }
+            let first = self.words.choose(&mut rng).expect("the wordlist isn't empty");
+            let second = self.words.choose(&mut rng).expect("the wordlist isn't empty");
+            usernames.insert(format!("{}.{}{}", first, second, rng.gen_range(0, 100)));
+        }
+        if usernames.len() < n {
+            return Err(GenError::AttemptsExhausted);
+        }
+
+        usernames.into_iter()
+                 .map(|username| {
+                     Ok(CredentialRecord { username, key: self.profile.generate()?, created: rng.gen_range(since, until + 1) })
+                 })
+                 .collect()
+    }
+}
+
+
+/// Write the records as CSV with a `username,key,created` header, the dates in ISO 8601
+#[inline]
+pub fn to_csv(records: &[CredentialRecord], mut writer: impl Write) -> Result<(), GenError> {
+    writeln!(writer, "username,key,created")?;
+
+    for r in records {
+        writeln!(writer, "{},{},{}", _CSV_FIELD(&r.username), _CSV_FIELD(&r.key), r.created_date())?;
+    }
+
+    Ok(())
+}
+
+
+/// Write the records as a JSON array
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::testdata::{self, CredentialRecord};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let records = vec![CredentialRecord { username: "amber.falcon42".into(), key: "s3cr3t!".into(), created: 1709942400 }];
+/// assert_eq!(records[0].created_date(), "2024-03-09");
+///
+/// let mut json = Vec::new();
+/// testdata::to_json(&records, &mut json)?;
+/// assert_eq!(String::from_utf8(json)?, r#"[{"username":"amber.falcon42","key":"s3cr3t!","created":1709942400}]"#);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "json")]
+#[inline]
+pub fn to_json(records: &[CredentialRecord], writer: impl Write) -> Result<(), GenError> {
+    serde_json::to_writer(writer, records).map_err(|e| GenError::Io(e.into()))
+}
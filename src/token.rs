@@ -0,0 +1,193 @@
+//! Tokens of a fixed grammar, like license keys, API tokens or serials.
+//!
+//! A [`TokenSpec`] is a sequence of parts added together:
+//! literals, random characters of a charset, and check digits.
+use {
+    std::ops::Add,
+    rand::{thread_rng, seq::SliceRandom},
+    crate::{GenError, checksum::Checksum},
+};
+
+
+
+
+/// The letters and digits [`alnum`] picks from
+pub const ALNUM: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+
+/// A part of a [`TokenSpec`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Part {
+    /// Written as is
+    Literal(String),
+    /// `len` characters picked from `chars`
+    Random { chars: Vec<char>, len: usize },
+    /// The check digit of the random characters before it
+    CheckDigit(Checksum),
+}
+
+
+/// The grammar of a token
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::token::{literal, alnum, check_digit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let spec = literal("acct_") + alnum(24) + check_digit();
+/// assert_eq!(spec.len(), 30);
+/// assert!(spec.entropy_bits() > 142.0);
+///
+/// let token = spec.generate()?;
+/// assert!(token.starts_with("acct_"));
+/// assert!(spec.verify(&token));
+/// assert!(!spec.verify(&token[1..]));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenSpec {
+    /// The parts, in order
+    pub parts: Vec<Part>,
+}
+
+
+/// Return a spec of the literal `s`
+#[inline]
+pub fn literal(s: impl Into<String>) -> TokenSpec { TokenSpec { parts: vec![Part::Literal(s.into())] } }
+
+
+/// Return a spec of `len` characters picked from `chars`
+#[inline]
+pub fn charset(chars: &str, len: usize) -> TokenSpec {
+    TokenSpec { parts: vec![Part::Random { chars: chars.chars().collect(), len }] }
+}
+
+
+/// Return a spec of `len` letters and digits
+#[inline]
+pub fn alnum(len: usize) -> TokenSpec { charset(ALNUM, len) }
+
+
+/// Return a spec of `len` digits
+#[inline]
+pub fn digits(len: usize) -> TokenSpec { charset("0123456789", len) }
+
+
+/// Return a spec of `len` lowercase hexadecimal digits
+#[inline]
+pub fn hex(len: usize) -> TokenSpec { charset("0123456789abcdef", len) }
+
+
+/// Return a spec of the Luhn check digit of the random characters before it
+#[inline]
+pub fn check_digit() -> TokenSpec { check_digit_with(Checksum::Luhn) }
+
+
+/// Return a spec of the check digit of the random characters before it, by `algo`
+#[inline]
+pub fn check_digit_with(algo: Checksum) -> TokenSpec { TokenSpec { parts: vec![Part::CheckDigit(algo)] } }
+
+
+impl Add for TokenSpec {
+    type Output = TokenSpec;
+
+    #[inline]
+    fn add(mut self, rhs: TokenSpec) -> TokenSpec {
+        self.parts.extend(rhs.parts);
+        self
+    }
+}
+
+
+impl TokenSpec {
+    /// Return how many characters a token has
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parts.iter()
+                  .map(|p| match p {
+                      Part::Literal(s) => s.chars().count(),
+                      Part::Random { len, .. } => *len,
+                      Part::CheckDigit(_) => 1,
+                  })
+                  .sum()
+    }
+
+    /// Check whether a token is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Return the entropy of a token in bits, the literals and the check digits add none
+    #[inline]
+    pub fn entropy_bits(&self) -> f64 {
+        self.parts.iter().fold(0.0, |acc, p| match p {
+            Part::Random { chars, len } if chars.len() > 1 => acc + *len as f64 * (chars.len() as f64).log2(),
+            _ => acc,
+        })
+    }
+
+    /// Generate a token
+    ///
+    /// Return `GenError::MissChar` if a charset is empty,
+    /// and `GenError::InvalidChar` if a check digit follows characters other than ASCII letters and digits.
+    #[inline]
+    pub fn generate(&self) -> Result<String, GenError> {
+        let mut rng = thread_rng();
+        let mut token = String::with_capacity(self.len());
+        let mut random = String::new();
+
+        for p in &self.parts {
+            match p {
+                Part::Literal(s) => token.push_str(s),
+                Part::Random { chars, len } => {
+                    for _ in 0..*len {
+                        let c = *chars.choose(&mut rng).ok_or(GenError::MissChar)?;
+                        token.push(c);
+                        random.push(c);
+                    }
+                }
+                Part::CheckDigit(algo) => {
+                    let d = algo.digit(&random)?;
+                    token.push(d);
+                    random.push(d);
+                }
+            }
+        }
+
+        Ok(token)
+    }
+
+    /// Check whether `token` conforms to the spec, with valid check digits
+    #[inline]
+    pub fn verify(&self, token: &str) -> bool {
+        let mut rest = token.chars();
+        let mut random = String::new();
+
+        for p in &self.parts {
+            match p {
+                Part::Literal(s) => {
+                    if !s.chars().all(|c| rest.next() == Some(c)) {
+                        return false;
+                    }
+                }
+                Part::Random { chars, len } => {
+                    for _ in 0..*len {
+                        match rest.next() {
+                            Some(c) if chars.contains(&c) => random.push(c),
+                            _ => return false,
+                        }
+                    }
+                }
+                Part::CheckDigit(algo) => {
+                    match (rest.next(), algo.digit(&random)) {
+                        (Some(c), Ok(d)) if c == d => random.push(c),
+                        _ => return false,
+                    }
+                }
+            }
+        }
+
+        rest.next().is_none()
+    }
+}
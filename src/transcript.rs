@@ -0,0 +1,153 @@
+//! Verifiable generations for raffles and audited random selections.
+//!
+//! The hash of a secret seed is published before the generation, the [`Commitment`].
+//! The key is then generated from the seed, and the [`Transcript`] reveals the seed with the settings,
+//! so anyone can check with [`verify_transcript`] that the seed is the committed one and that it generates the key.
+use {
+    rand::{thread_rng, RngCore},
+    sha2::{Sha256, Digest},
+    crate::{RandKey, GenError, RandKeyConfig, SeedStrategy},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// A secret seed and the nonce which keeps its hash from being reversed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commitment {
+    seed:  u64,
+    nonce: [u8; 16],
+}
+
+
+/// What a committed generation used and generated, to publish once the key is used
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Transcript {
+    /// The hash published before the generation, in hex
+    pub commitment: String,
+    /// The revealed seed
+    pub seed:       u64,
+    /// The revealed nonce, in hex
+    pub nonce:      String,
+    /// The settings the key was generated with
+    pub config:     RandKeyConfig,
+    pub key:        String,
+}
+
+
+/// Return `bytes` in lowercase hex
+#[inline]
+pub(crate) fn _HEX(bytes: &[u8]) -> String { bytes.iter().map(|b| format!("{:02x}", b)).collect() }
+
+
+/// Return the hex SHA-256 of the nonce and the seed in little-endian
+#[inline]
+pub(crate) fn _COMMIT(seed: u64, nonce: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(nonce);
+    hasher.update(seed.to_le_bytes());
+
+    _HEX(&hasher.finalize())
+}
+
+
+impl Commitment {
+    /// Return a commitment to a random seed
+    #[inline]
+    pub fn new() -> Self {
+        let mut nonce = [0; 16];
+        thread_rng().fill_bytes(&mut nonce);
+
+        Commitment { seed: thread_rng().next_u64(), nonce }
+    }
+
+    /// Return the hash to publish before the generation, the hex SHA-256 of the nonce and the seed
+    #[inline]
+    pub fn hash(&self) -> String { _COMMIT(self.seed, &self.nonce) }
+}
+
+
+impl Default for Commitment {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+
+/// Return the `RandKey` with the settings of `config` which generates from `seed`
+#[inline]
+pub(crate) fn _SEEDED(config: &RandKeyConfig, seed: u64) -> Result<RandKey, GenError> {
+    let mut r_p = RandKey::from_config(config)?;
+    r_p.set_seed(Some(seed));
+    r_p.set_seed_strategy(SeedStrategy::Derived(seed));
+
+    Ok(r_p)
+}
+
+
+/// Check whether the seed of `transcript` is the committed one, and generates its key with its settings
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{RandKey, transcript::{Commitment, verify_transcript}};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let commitment = Commitment::new();
+/// println!("Published before the raffle: {}", commitment.hash());
+///
+/// let r_p = RandKey::new("0", "0", "6")?;
+/// let transcript = r_p.join_committed(&commitment)?;
+/// assert_eq!(transcript.commitment, commitment.hash());
+/// assert_eq!(transcript.key, r_p.key());
+/// assert!(verify_transcript(&transcript));
+///
+/// let mut forged = transcript.clone();
+/// forged.seed += 1;
+/// assert!(!verify_transcript(&forged));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn verify_transcript(transcript: &Transcript) -> bool {
+    let nonce: Option<Vec<u8>> = (0..transcript.nonce.len()).step_by(2)
+                                                            .map(|i| transcript.nonce.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+                                                            .collect();
+
+    match nonce {
+        Some(nonce) if _COMMIT(transcript.seed, &nonce) == transcript.commitment => {
+            _SEEDED(&transcript.config, transcript.seed).and_then(|r_p| r_p.generate()).is_ok_and(|key| key == transcript.key)
+        }
+        _ => false,
+    }
+}
+
+
+impl RandKey {
+    /// Generate the key from the seed of `commitment`, and return the transcript which proves it
+    ///
+    /// The key is generated with the settings of [`config`](RandKey::config) only,
+    /// so the strategy, the rules and the seeds of this `RandKey` aren't involved.
+    #[inline]
+    pub fn join_committed(&self, commitment: &Commitment) -> Result<Transcript, GenError> {
+        let config = self.config();
+        let r_p = _SEEDED(&config, commitment.seed)?;
+        let key = r_p.generate()?;
+
+        *self.key.borrow_mut() = key.clone().into();
+        // Recorded by the seeded `RandKey`, whose generator picked the characters
+        r_p.record_metadata();
+        *self.METADATA.borrow_mut() = r_p.metadata();
+
+        Ok(Transcript {
+            commitment: commitment.hash(),
+            seed: commitment.seed,
+            nonce: _HEX(&commitment.nonce),
+            config,
+            key,
+        })
+    }
+}
@@ -0,0 +1,204 @@
+//! Unicode ranges of characters in the data, classified by their general category.
+//!
+//! Only the letters, punctuation, symbols and decimal digits are added,
+//! not the marks, separators and unassigned code points of a range.
+use {
+    std::ops::RangeInclusive,
+    unicode_general_category::{get_general_category, GeneralCategory::*},
+    crate::{RandKey, ASCIIExcludeCtrl, Classifier, DefaultClassifier},
+};
+
+
+
+
+/// Named Unicode blocks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnicodeBlock {
+    /// Greek and Coptic, U+0370 to U+03FF
+    Greek,
+    /// Cyrillic, U+0400 to U+04FF
+    Cyrillic,
+}
+
+
+impl UnicodeBlock {
+    /// Return the code points of the block
+    #[inline]
+    pub fn range(self) -> RangeInclusive<char> {
+        match self {
+            UnicodeBlock::Greek => '\u{370}'..='\u{3ff}',
+            UnicodeBlock::Cyrillic => '\u{400}'..='\u{4ff}',
+        }
+    }
+}
+
+
+/// The numeral systems the numbers of the data can be in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumeralSystem {
+    /// `0` to `9`, the default
+    Latin,
+    /// `٠` to `٩`, U+0660 to U+0669
+    ArabicIndic,
+    /// `۰` to `۹` of Persian and Urdu, U+06F0 to U+06F9
+    ExtendedArabicIndic,
+    /// `०` to `९`, U+0966 to U+096F
+    Devanagari,
+    /// `০` to `৯`, U+09E6 to U+09EF
+    Bengali,
+}
+
+
+impl NumeralSystem {
+    /// Return the digits of the system, from zero to nine
+    #[inline]
+    pub fn digits(self) -> RangeInclusive<char> {
+        match self {
+            NumeralSystem::Latin => '0'..='9',
+            NumeralSystem::ArabicIndic => '\u{660}'..='\u{669}',
+            NumeralSystem::ExtendedArabicIndic => '\u{6f0}'..='\u{6f9}',
+            NumeralSystem::Devanagari => '\u{966}'..='\u{96f}',
+            NumeralSystem::Bengali => '\u{9e6}'..='\u{9ef}',
+        }
+    }
+
+    /// Return the numeral system of a locale like `ar-EG` or `fa_IR`, `Latin` for the ones it's unknown
+    ///
+    /// The Arabic of the Maghreb writes Latin digits, the other Arabic locales the Arabic-Indic ones.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::unicode::NumeralSystem;
+    ///
+    /// assert_eq!(NumeralSystem::for_locale("ar-EG"), NumeralSystem::ArabicIndic);
+    /// assert_eq!(NumeralSystem::for_locale("ar_MA"), NumeralSystem::Latin);
+    /// assert_eq!(NumeralSystem::for_locale("hi"), NumeralSystem::Devanagari);
+    /// assert_eq!(NumeralSystem::for_locale("en-US"), NumeralSystem::Latin);
+    /// ```
+    #[inline]
+    pub fn for_locale(locale: &str) -> Self {
+        let mut subtags = locale.split(&['-', '_'][..]).map(str::to_ascii_lowercase);
+        let language = subtags.next().unwrap_or_default();
+        let region = subtags.next().unwrap_or_default();
+
+        match (language.as_str(), region.as_str()) {
+            ("ar", "ma") | ("ar", "dz") | ("ar", "tn") | ("ar", "ly") => NumeralSystem::Latin,
+            ("ar", _) => NumeralSystem::ArabicIndic,
+            ("fa", _) | ("ur", _) | ("ps", _) => NumeralSystem::ExtendedArabicIndic,
+            ("hi", _) | ("mr", _) | ("ne", _) => NumeralSystem::Devanagari,
+            ("bn", _) | ("as", _) => NumeralSystem::Bengali,
+            _ => NumeralSystem::Latin,
+        }
+    }
+}
+
+
+/// Return the kind of `c`, `None` if it never goes in the data
+#[inline]
+pub(crate) fn _CLASSIFY(c: char) -> Option<ASCIIExcludeCtrl> {
+    match get_general_category(c) {
+        UppercaseLetter | LowercaseLetter | TitlecaseLetter | OtherLetter => Some(ASCIIExcludeCtrl::Alphabetic),
+        ConnectorPunctuation | DashPunctuation | OpenPunctuation | ClosePunctuation | InitialPunctuation
+        | FinalPunctuation | OtherPunctuation | MathSymbol | CurrencySymbol | ModifierSymbol | OtherSymbol => {
+            Some(ASCIIExcludeCtrl::Punctuation)
+        }
+        DecimalNumber => Some(ASCIIExcludeCtrl::Digit),
+        _ => None,
+    }
+}
+
+
+/// The classes of ASCII, and the general categories of Unicode for the other characters
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnicodeClassifier;
+
+
+impl Classifier for UnicodeClassifier {
+    #[inline]
+    fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl> { if c.is_ascii() { DefaultClassifier.classify(c) } else { _CLASSIFY(c) } }
+}
+
+
+impl RandKey {
+    /// Add the letters, symbols and numbers of `range` to the data, return how many were new
+    ///
+    /// The keys with other characters than ASCII are loaded back with a [`UnicodeClassifier`],
+    /// like by [`to_randkey_with`](crate::ToRandKey::to_randkey_with), but can't have a check digit.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("10", "0", "0")?;
+    /// r_p.clear(Alphabetic);
+    /// assert_eq!(r_p.add_unicode_range('α'..='ω'), 25);
+    ///
+    /// r_p.join()?;
+    /// assert!(r_p.key().chars().all(|c| ('α'..='ω').contains(&c)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn add_unicode_range(&mut self, range: RangeInclusive<char>) -> usize {
+        let mut added = 0;
+
+        for c in range {
+            let i = match _CLASSIFY(c) {
+                Some(ASCIIExcludeCtrl::Alphabetic) => 0,
+                Some(ASCIIExcludeCtrl::Punctuation) => 1,
+                Some(ASCIIExcludeCtrl::Digit) => 2,
+                None => continue,
+            };
+
+            let s = c.to_string();
+            if !self.DATA[i].contains(&s) {
+                self.DATA[i].push(s);
+                added += 1;
+            }
+        }
+
+        added
+    }
+
+    /// Add the letters, symbols and numbers of `block` to the data, return how many were new
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, unicode::UnicodeBlock, ASCIIExcludeCtrl::*};
+    ///
+    /// let mut r_p = RandKey::default();
+    /// r_p.add_unicode_block(UnicodeBlock::Cyrillic);
+    /// assert!(r_p.data(Alphabetic).contains(&"Ж".to_string()));
+    /// assert!(r_p.data(Punctuation).contains(&"҂".to_string()));
+    /// ```
+    #[inline]
+    pub fn add_unicode_block(&mut self, block: UnicodeBlock) -> usize { self.add_unicode_range(block.range()) }
+
+    /// Replace the numbers of the data by the digits of `system`, so the keys exercise the parsing of other numerals
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, ToRandKey, unicode::{NumeralSystem, UnicodeClassifier}, ASCIIExcludeCtrl::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut r_p = RandKey::new("6", "0", "4")?;
+    /// r_p.set_numerals(NumeralSystem::Devanagari);
+    ///
+    /// let key = r_p.generate()?;
+    /// assert_eq!(key.chars().filter(|c| ('०'..='९').contains(c)).count(), 4);
+    /// assert_eq!(key.to_randkey_with(UnicodeClassifier)?.get_cnt(Digit), "4");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_numerals(&mut self, system: NumeralSystem) { self.DATA[2] = system.digits().map(String::from).collect(); }
+
+    /// Replace the numbers of the data by the digits `locale` writes, see [`NumeralSystem::for_locale`]
+    #[inline]
+    pub fn set_numerals_for_locale(&mut self, locale: &str) { self.set_numerals(NumeralSystem::for_locale(locale)); }
+}
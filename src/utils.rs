@@ -1,14 +1,24 @@
 pub use {
     rand::prelude::*,
     rayon::prelude::*,
-    num_bigint::{BigUint, ToBigUint},
+    num_bigint::{BigUint, ToBigUint, RandBigInt},
     num_traits::{Zero, One, ToPrimitive},
 };
 
 
 use {
-    crate::error::GenError,
-    std::{str::FromStr,sync::{Arc, atomic::{Ordering::*, AtomicUsize},},},
+    crate::{
+        error::GenError,
+        stats::GenStats,
+        checksum::Checksum,
+        strategy::{Strategy, _STREAM, _UNIQUE, _COVER},
+        seeding::SeedStrategy,
+        rules::{Rule, _PLACE},
+        classify::{Classifier, DefaultClassifier},
+        parallel::parallel_available,
+        ASCIIExcludeCtrl::*,
+    },
+    std::{str::FromStr, time::Instant, sync::{Arc, atomic::{Ordering::*, AtomicUsize},},},
 };
 
 
@@ -25,14 +35,13 @@ pub(crate) fn _DEFAULT_DATA() -> Vec<Vec<String>> {
     let mut symbols = Vec::new();
     let mut numbers = Vec::new();
 
-    let _ = (33..127).into_iter()
-                     .for_each(|x| {
-                         let ch = x as u8 as char;
+    (33..127).for_each(|x| {
+                  let ch = x as u8 as char;
 
-                         if ch.is_ascii_alphabetic()  { letters.push(ch.into()) }
-                         if ch.is_ascii_punctuation() { symbols.push(ch.into()) }
-                         if ch.is_ascii_digit()       { numbers.push(ch.into()) }
-                     });
+                  if ch.is_ascii_alphabetic()  { letters.push(ch.into()) }
+                  if ch.is_ascii_punctuation() { symbols.push(ch.into()) }
+                  if ch.is_ascii_digit()       { numbers.push(ch.into()) }
+              });
 
     vec![letters, symbols, numbers]
 
@@ -41,34 +50,41 @@ pub(crate) fn _DEFAULT_DATA() -> Vec<Vec<String>> {
 
 /// Count the fields of `RandKey` in a string
 ///
-/// The `_CNT("ab123_c53")` returns `(3, 5, 1)`
+/// The `_CNT("ab123_c53")` returns `(3, 1, 5)`
+#[inline]
+pub(crate) fn _CNT(content: impl AsRef<str>) -> Result<(BigUint, BigUint, BigUint), GenError> { _CNT_WITH(content, &DefaultClassifier) }
+
+
+/// Count the fields of `RandKey` in a string, the characters classified by `classifier`
 #[inline]
 #[rustfmt::skip]
-pub(crate) fn _CNT(content: impl AsRef<str>) -> Result<(BigUint, BigUint, BigUint), GenError> {
+pub(crate) fn _CNT_WITH(content: impl AsRef<str>, classifier: &dyn Classifier) -> Result<(BigUint, BigUint, BigUint), GenError> {
 
     let l = Arc::new(AtomicUsize::new(0));
     let s = Arc::new(AtomicUsize::new(0));
     let n = Arc::new(AtomicUsize::new(0));
 
-    content.as_ref()
-           .chars()
-           .collect::<Vec<_>>()
-           .par_iter()
-           .for_each(|x| {
-               if x.is_ascii() {
-
-                   if x.is_ascii_alphabetic()  {  l.clone().fetch_add(1, SeqCst); }
-                   if x.is_ascii_punctuation() {  s.clone().fetch_add(1, SeqCst); }
-                   if x.is_ascii_digit()       {  n.clone().fetch_add(1, SeqCst); }
+    let count = |x: &char| {
+        match classifier.classify(*x) {
+            Some(Alphabetic)  => { l.clone().fetch_add(1, SeqCst); }
+            Some(Punctuation) => { s.clone().fetch_add(1, SeqCst); }
+            Some(Digit)       => { n.clone().fetch_add(1, SeqCst); }
+            None => {}
+        }
+    };
 
-               }
-           });
+    let chars = content.as_ref().chars().collect::<Vec<_>>();
+    if parallel_available() {
+        chars.par_iter().for_each(count);
+    } else {
+        chars.iter().for_each(count);
+    }
 
     let l = l.load(SeqCst).to_biguint().unwrap();
     let s = s.load(SeqCst).to_biguint().unwrap();
     let n = n.load(SeqCst).to_biguint().unwrap();
 
-    if &l+&s+&n != content.as_ref().len().to_biguint().unwrap() {
+    if &l+&s+&n != content.as_ref().chars().count().to_biguint().unwrap() {
         Err(GenError::InvalidChar)
     } else {
         Ok((l, s, n))
@@ -77,90 +93,374 @@ pub(crate) fn _CNT(content: impl AsRef<str>) -> Result<(BigUint, BigUint, BigUin
 }
 
 
-/// Generate n random numbers, each one is up to `length`
+/// Return an empty buffer with room for `len` elements, `GenError::AllocationFailed` if it can't be allocated
 #[inline]
-pub(crate) fn _RAND_IDX(cnt: &BigUint, length: usize) -> Vec<usize> {
-    let mut n = cnt.to_biguint().unwrap();
-    let mut idxs = Vec::with_capacity(n.to_usize().unwrap());
+pub(crate) fn _TRY_BUF<T>(len: usize) -> Result<Vec<T>, GenError> {
+    let mut buf = Vec::new();
+    buf.try_reserve_exact(len).map_err(|_| GenError::AllocationFailed)?;
+
+    Ok(buf)
+}
+
+
+/// Return a buffer of `counts[i]` characters picked from `pools[i]` in a row, with room for `extra` more,
+/// and how many units of at most `unit` characters they were picked in parallel by
+#[inline]
+pub(crate) fn _SAMPLE<T: Copy + Default + Send + Sync>(counts: [usize; 3],
+                                                       pools:  &[Vec<T>],
+                                                       unit:    usize,
+                                                       extra:   usize,
+                                                       seeding: SeedStrategy) -> Result<(Vec<T>, usize), GenError> {
+    let total = counts.iter().sum();
+
+    let mut buf = _TRY_BUF(total + extra)?;
+    buf.resize(total, T::default());
+
+    let mut units = 0;
+    let mut rest = &mut buf[..];
+    for (kind, (count, pool)) in counts.iter().zip(pools).enumerate() {
+        let (head, tail) = rest.split_at_mut(*count);
+
+        let pick = |(i, chunk): (usize, &mut [T])| {
+            let mut rng = seeding.unit_rng(kind, i);
+            chunk.iter_mut().for_each(|c| *c = pool[rng.gen_range(0, pool.len())]);
+        };
+
+        // Each unit has its own generator, so the order doesn't change the characters
+        if parallel_available() {
+            head.par_chunks_mut(unit).enumerate().for_each(pick);
+        } else {
+            head.chunks_mut(unit).enumerate().for_each(pick);
+        }
 
-    while !n.is_zero() {
-        idxs.push(thread_rng().gen_range(0, length));
-        n -= BigUint::one();
+        units += count.div_ceil(unit);
+        rest = tail;
     }
 
-    idxs
+    Ok((buf, units))
+}
+
 
+/// Check whether the elements in the sequence are all single ascii characters
+#[inline]
+pub(crate) fn _CHECK_ASCII(v: &[impl AsRef<str>]) -> bool {
+    v.iter()
+     .all(|s| {
+            let mut chars = s.as_ref().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c.is_ascii() && !c.is_ascii_control(),
+                _ => false,
+            }
+        })
 }
 
 
-/// Resolve large numbers into smaller numbers
+/// Check whether the elements in the sequence are all single characters `classifier` accepts
 #[inline]
-pub(crate) fn _DIV_UNIT(unit: &BigUint, n: &mut BigUint) -> Vec<BigUint> {
-    let UNIT = unit.to_biguint().unwrap();
+pub(crate) fn _CHECK_ITEMS(v: &[impl AsRef<str>], classifier: &dyn Classifier) -> bool {
+    v.iter()
+     .all(|s| {
+            let mut chars = s.as_ref().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => classifier.classify(c).is_some(),
+                _ => false,
+            }
+        })
+}
 
-    let mut ret = Vec::with_capacity((n.clone() / &UNIT + BigUint::one()).to_usize().unwrap());
 
-    loop {
-        if n.clone() < UNIT {
-            ret.push(n.to_biguint().unwrap());
-            break;
-        } else {
-            *n -= UNIT.clone();
-            ret.push(UNIT.clone());
+#[inline]
+pub(crate) fn _GROUP(v: &[impl AsRef<str>]) -> Vec<Vec<String>> { _GROUP_WITH(v, &DefaultClassifier) }
+
+
+/// Group the characters in letters, symbols and numbers by `classifier` in their order, leave out the ones it rejects
+#[inline]
+pub(crate) fn _GROUP_WITH(v: &[impl AsRef<str>], classifier: &dyn Classifier) -> Vec<Vec<String>> {
+    let mut grouped = vec![Vec::new(), Vec::new(), Vec::new()];
+
+    for c in v.iter().map(_CHAR_FROM_STR) {
+        match classifier.classify(c) {
+            Some(Alphabetic) => grouped[0].push(c.into()),
+            Some(Punctuation) => grouped[1].push(c.into()),
+            Some(Digit) => grouped[2].push(c.into()),
+            None => {}
         }
     }
 
-    ret
+    grouped
+}
+
+
+#[inline]
+pub(crate) fn _CHAR_FROM_STR(s: impl AsRef<str>) -> char { char::from_str(s.as_ref()).unwrap() }
+
 
+
+/// Compute `log2(n)` of a positive `n`, finite even when `n` doesn't fit in a f64
+#[inline]
+pub(crate) fn _LOG2(n: &BigUint) -> f64 {
+    // The bits below the 64 highest ones don't show in a f64
+    let shift = n.bits().saturating_sub(64);
+    (n >> shift).to_f64().unwrap().log2() + shift as f64
 }
 
 
-/// Check whether the elements in the sequence are all ascii values
+/// Compute `log2(n!)`, exact for small numbers and by Stirling's approximation for the large ones.
+///
+/// Saturate to `f64::INFINITY` when it doesn't fit in a f64, it's never NaN.
 #[inline]
-pub(crate) fn _CHECK_ASCII(v: &[impl AsRef<str>]) -> bool {
-    v.iter()
-     .find(|c| {
-            let c = _CHAR_FROM_STR(c);
-            !c.is_ascii() || c.is_ascii_control()
-        })
-     .is_none()
+pub(crate) fn _LOG2_FACTORIAL(n: &BigUint) -> f64 {
+    let log2 = _LOG2(n.max(&BigUint::one()));
+    let n = n.to_f64().unwrap_or(f64::INFINITY);
+
+    if n < 1024.0 {
+        (2..=n as usize).fold(0.0, |acc, i| acc + (i as f64).log2())
+    } else {
+        // Factored, so the two big terms never make an infinity minus an infinity
+        n * (log2 - std::f64::consts::LOG2_E) + 0.5 * (2.0 * std::f64::consts::PI).log2() + 0.5 * log2
+    }
+}
+
+
+/// Fingerprint the data in FNV-1a, in hex
+#[inline]
+pub(crate) fn _FINGERPRINT(data: &[Vec<String>]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for byte in data.iter().flat_map(|v| v.iter().flat_map(|s| s.bytes()).chain(std::iter::once(0xff))) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    format!("{:016x}", hash)
+}
+
+
+/// Check whether every kind of characters to generate has data to pick from
+#[inline]
+#[allow(non_snake_case)]
+pub(crate) fn _CHECK_DATA(cnts: [&BigUint; 3], data: &[Vec<String>]) -> Result<(), GenError> {
+    let L = cnts[0].is_zero();
+    let S = cnts[1].is_zero();
+    let N = cnts[2].is_zero();
+
+    let dl = data[0].is_empty();
+    let ds = data[1].is_empty();
+    let dn = data[2].is_empty();
+
+    let dl_L = !L && dl;
+    let ds_S = !S && ds;
+    let dn_N = !N && dn;
+
+    if !(dl_L || ds_S || dn_N) {
+        Ok(())
+    } else {
+        Err(GenError::MissChar)
+    }
 }
 
 
+/// The longest keys generated on the stack
+pub(crate) const _SHORT_KEY: usize = 128;
+
+
+/// Overwrite `buf` with zeros, so the characters of a scratch buffer aren't left in the memory once it's freed
 #[inline]
-pub(crate) fn _GROUP(v: &[impl AsRef<str>]) -> Vec<Vec<String>> {
+pub(crate) fn _WIPE<T: Copy + Default>(buf: &mut [T]) {
+    for c in buf.iter_mut() {
+        // SAFETY: `c` is a valid and aligned reference. Volatile, so the writes before the free aren't optimized away
+        unsafe { std::ptr::write_volatile(c, T::default()) };
+    }
+    std::sync::atomic::compiler_fence(SeqCst);
+}
 
-    use parking_lot::Mutex;
 
-    let v: Vec<String> = v.iter().map(|x| x.as_ref().to_string()).collect();
+/// Shuffle `v` in place by Fisher–Yates, with the seeded generator if there is a seed
+#[inline]
+pub(crate) fn _SHUFFLE<T>(v: &mut [T], seed: Option<u64>) {
+    match seed {
+        Some(seed) => v.shuffle(&mut StdRng::seed_from_u64(seed)),
+        None => v.shuffle(&mut thread_rng()),
+    }
+}
 
-    let ltr = Mutex::new(Vec::<String>::new());
-    let sbl = Mutex::new(Vec::<String>::new());
-    let num = Mutex::new(Vec::<String>::new());
 
-    v.par_iter().for_each(|c| {
-        let mut temp;
-        let c = _CHAR_FROM_STR(c);
+/// Return the counts of letters, symbols and numbers to pick from `data`, the check digit aside
+#[inline]
+pub(crate) fn _COUNTS(cnts: [&BigUint; 3], data: &[Vec<String>], checksum: Option<Checksum>) -> Result<[usize; 3], GenError> {
+    _CHECK_DATA(cnts, data)?;
 
-        if c.is_ascii_alphabetic() {
-            temp = ltr.lock();
-            temp.push(c.clone().into());
+    let mut counts = [0; 3];
+    for (count, cnt) in counts.iter_mut().zip(cnts.iter()) {
+        *count = cnt.to_usize().ok_or(GenError::AllocationFailed)?;
+    }
+
+    // The check digit is one of the digits
+    if checksum.is_some() {
+        if counts[2] == 0 {
+            return Err(GenError::NoCheckDigit);
         }
-        if c.is_ascii_punctuation() {
-            temp = sbl.lock();
-            temp.push(c.clone().into());
+        if counts[1] != 0 {
+            return Err(GenError::InvalidChar);
         }
-        if c.is_ascii_digit() {
-            temp = num.lock();
-            temp.push(c.clone().into());
+        counts[2] -= 1;
+    }
+
+    Ok(counts)
+}
+
+
+/// How the characters of a key are picked and arranged
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct _Arrangement<'a> {
+    pub(crate) seed:     Option<u64>,
+    pub(crate) strategy: Strategy,
+    pub(crate) seeding:  SeedStrategy,
+    pub(crate) rules:    &'a [Rule],
+    /// Whether a check digit is pushed after the characters
+    pub(crate) check_digit: bool,
+}
+
+
+/// Return the characters picked and arranged by `arrangement`, how many units they were picked by,
+/// and when the picking and the shuffle ended
+#[inline]
+pub(crate) fn _ARRANGE<T: Copy + Ord + Default + Send + Sync>(counts:      [usize; 3],
+                                                              pools:       &[Vec<T>],
+                                                              unit:        usize,
+                                                              extra:       usize,
+                                                              arrangement: _Arrangement) -> Result<(Vec<T>, usize, Instant, Instant), GenError> {
+    let _Arrangement { seed, strategy, seeding, rules, check_digit } = arrangement;
+
+    let (mut buf, units) = match strategy {
+        // `Auto` is resolved into another strategy before
+        Strategy::Shuffle | Strategy::Auto => _SAMPLE(counts, pools, unit, extra, seeding)?,
+        Strategy::Unique => (_UNIQUE(counts, pools, extra, seeding)?, 1),
+        Strategy::Coverage => (_COVER(counts, pools, extra, seeding)?, 1),
+        Strategy::Streaming if rules.is_empty() => {
+            let buf = _STREAM(counts, pools, extra, seed, seeding)?;
+            let sampled = Instant::now();
+
+            return Ok((buf, 1, sampled, sampled));
         }
-    });
+        // The kinds are picked on the fly, there's no placement to follow the rules
+        Strategy::Streaming => return Err(GenError::InconsistentField),
+    };
 
-    vec![ltr.into_inner(), sbl.into_inner(), num.into_inner()]
+    let sampled = Instant::now();
+    if rules.is_empty() {
+        _SHUFFLE(&mut buf, seed);
+    } else {
+        buf = _PLACE(buf, counts, rules, check_digit, seed)?;
+    }
 
+    Ok((buf, units, sampled, Instant::now()))
 }
 
 
+/// Generate a key with the counts of letters, symbols and numbers picked from `data`
 #[inline]
-pub(crate) fn _CHAR_FROM_STR(s: impl AsRef<str>) -> char { char::from_str(s.as_ref()).unwrap() }
+pub(crate) fn _GENERATE(cnts:        [&BigUint; 3],
+                        unit:        &BigUint,
+                        data:        &[Vec<String>],
+                        checksum:    Option<Checksum>,
+                        arrangement: _Arrangement) -> Result<(String, GenStats), GenError> {
+    #[cfg(feature = "metrics")]
+    let start = Instant::now();
+
+    let result = _GENERATE_KEY(cnts, unit, data, checksum, arrangement);
+
+    #[cfg(feature = "metrics")]
+    crate::telemetry::_RECORD(result.as_ref().map(|(key, _)| key.as_str()), start.elapsed());
 
+    result
+}
+
+
+#[inline]
+#[rustfmt::skip]
+pub(crate) fn _GENERATE_KEY(cnts:        [&BigUint; 3],
+                            unit:        &BigUint,
+                            data:        &[Vec<String>],
+                            checksum:    Option<Checksum>,
+                            arrangement: _Arrangement) -> Result<(String, GenStats), GenError> {
+
+    let start = Instant::now();
+    let counts = _COUNTS(cnts, data, checksum)?;
+
+    // The check digit could repeat a digit, and takes one out of the digits to cover
+    if matches!(arrangement.strategy, Strategy::Unique | Strategy::Coverage) && checksum.is_some() {
+        return Err(GenError::InconsistentField);
+    }
+
+    let unit = unit.to_usize().unwrap_or(usize::MAX).max(1);
+    let extra = checksum.map_or(0, |_| 1);
+    let ascii = data.iter().flatten().all(|s| s.is_ascii());
+
+    // The counts come from the users of servers, so a key too long fails instead of aborting
+    let total = counts.iter().try_fold(extra, |acc: usize, c| acc.checked_add(*c)).ok_or(GenError::AllocationFailed)? - extra;
+
+    let (strategy, unit) = arrangement.strategy.resolve(total, unit, !arrangement.rules.is_empty());
+    let arrangement = _Arrangement { strategy, check_digit: checksum.is_some(), ..arrangement };
+
+    let (mut key, units, sampled, shuffled) = if ascii && arrangement.strategy == Strategy::Shuffle && arrangement.rules.is_empty() && total <= _SHORT_KEY {
+        // Short keys are picked and shuffled on the stack, the key is the only allocation
+        let mut buf = [0; _SHORT_KEY];
+        let mut len = 0;
+
+        for (kind, (count, pool)) in counts.iter().zip(data).enumerate() {
+            for (i, chunk) in buf[len..len + count].chunks_mut(unit).enumerate() {
+                let mut rng = arrangement.seeding.unit_rng(kind, i);
+                chunk.iter_mut().for_each(|c| *c = pool[rng.gen_range(0, pool.len())].as_bytes()[0]);
+            }
+            len += count;
+        }
+        let sampled = Instant::now();
+        _SHUFFLE(&mut buf[..len], arrangement.seed);
+        let shuffled = Instant::now();
+
+        let mut key = String::with_capacity(len + extra);
+        key.push_str(std::str::from_utf8(&buf[..len]).expect("ASCII characters are valid UTF-8"));
+        _WIPE(&mut buf[..len]);
+
+        (key, 1, sampled, shuffled)
+    } else if ascii {
+        let pools: Vec<Vec<u8>> = data.iter().map(|v| v.iter().map(|s| s.as_bytes()[0]).collect()).collect();
+
+        let (buf, units, sampled, shuffled) = _ARRANGE(counts, &pools, unit, extra, arrangement)?;
+
+        (String::from_utf8(buf).expect("ASCII characters are valid UTF-8"), units, sampled, shuffled)
+    } else {
+        // The characters of Unicode ranges are shuffled whole
+        let pools: Vec<Vec<char>> = data.iter().map(|v| v.iter().map(_CHAR_FROM_STR).collect()).collect();
+        let (mut buf, units, sampled, shuffled) = _ARRANGE(counts, &pools, unit, 0, arrangement)?;
+
+        let mut key = String::new();
+        key.try_reserve_exact(buf.iter().map(|c| c.len_utf8()).sum::<usize>() + extra)
+           .map_err(|_| GenError::AllocationFailed)?;
+        key.extend(buf.iter());
+        _WIPE(&mut buf);
+
+        (key, units, sampled, shuffled)
+    };
+
+    if let Some(checksum) = checksum {
+        key.push(checksum.digit(&key)?);
+    }
+
+    let collected = Instant::now();
+
+    let stats = GenStats {
+        wall:     collected - start,
+        sampling: sampled - start,
+        shuffle:  shuffled - sampled,
+        collect:  collected - shuffled,
+        units,
+        chars:    key.len(),
+        warnings: Vec::new(),
+        sequential: units > 1 && !parallel_available(),
+    };
+
+    Ok((key, stats))
+
+}
@@ -0,0 +1,127 @@
+//! Checks of the wordlists loaded into the [`Passphrase`](crate::passphrase::Passphrase) generator.
+use {
+    std::{
+        collections::BTreeSet,
+        fmt::{self, Display, Formatter},
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+
+
+/// The words nobody wants to find in a generated passphrase
+pub const OFFENSIVE: &[&str] = &[
+    "anal", "arse", "ass", "bastard", "bitch", "bollocks", "boob", "cock", "crap", "cunt", "damn", "dick", "dildo", "dyke",
+    "fag", "fuck", "hell", "homo", "jizz", "kike", "nazi", "nigger", "penis", "piss", "porn", "prick", "pussy", "rape",
+    "retard", "shit", "slut", "spic", "tits", "twat", "vagina", "wank", "whore",
+];
+
+
+/// What [`validate`] found in a wordlist
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Report {
+    /// How many words the list has
+    pub total:        usize,
+    /// How many of them are distinct
+    pub unique:       usize,
+    /// The words listed more than once
+    pub duplicates:   Vec<String>,
+    /// The pairs of a word and a longer one it starts, like `("sun", "sunny")`
+    pub prefixes:     Vec<(String, String)>,
+    /// The words of [`OFFENSIVE`], in any case
+    pub offensive:    Vec<String>,
+    /// The words with other characters than ASCII
+    pub non_ascii:    Vec<String>,
+    /// The entropy of a word in bits, counting the distinct words only
+    pub bits_per_word: f64,
+    /// The entropy of a word in bits when the words aren't separated,
+    /// counting only the distinct words which start no other one
+    pub prefix_free_bits: f64,
+}
+
+
+impl Report {
+    /// Check whether the list has no duplicate, prefix, offensive word nor other character than ASCII
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        self.duplicates.is_empty() && self.prefixes.is_empty() && self.offensive.is_empty() && self.non_ascii.is_empty()
+    }
+}
+
+
+#[inline]
+fn _BITS(n: usize) -> f64 { if n == 0 { 0.0 } else { (n as f64).log2() } }
+
+
+/// Check `words` for duplicates, prefixes, offensive words and other characters than ASCII,
+/// and return their real entropy
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::{wordlist, passphrase::Language};
+///
+/// let report = wordlist::validate(&["sun", "sunny", "moon", "moon", "café"]);
+/// assert_eq!(report.total, 5);
+/// assert_eq!(report.unique, 4);
+/// assert_eq!(report.duplicates, vec!["moon"]);
+/// assert_eq!(report.prefixes, vec![("sun".to_string(), "sunny".to_string())]);
+/// assert_eq!(report.non_ascii, vec!["café"]);
+/// assert_eq!(report.bits_per_word, 2.0);
+/// assert!(!report.is_clean());
+///
+/// assert!(wordlist::validate(&Language::English.wordlist()).offensive.is_empty());
+/// ```
+#[inline]
+pub fn validate(words: &[impl AsRef<str>]) -> Report {
+    let mut unique = BTreeSet::new();
+    let mut duplicates = BTreeSet::new();
+
+    for w in words {
+        let w = w.as_ref();
+        if !unique.insert(w) {
+            duplicates.insert(w);
+        }
+    }
+
+    // The words starting with a word follow it in order
+    let sorted: Vec<&str> = unique.iter().copied().collect();
+    let mut prefixes = vec![];
+    for (i, w) in sorted.iter().enumerate() {
+        prefixes.extend(sorted[i + 1..].iter()
+                                        .take_while(|longer| longer.starts_with(w))
+                                        .map(|longer| (w.to_string(), longer.to_string())));
+    }
+    let prefix_free = sorted.iter().filter(|w| !prefixes.iter().any(|(p, _)| p == *w)).count();
+
+    let offensive = sorted.iter().filter(|w| OFFENSIVE.iter().any(|o| o.eq_ignore_ascii_case(w))).map(|w| w.to_string()).collect();
+    let non_ascii = sorted.iter().filter(|w| !w.is_ascii()).map(|w| w.to_string()).collect();
+
+    Report {
+        total: words.len(),
+        unique: unique.len(),
+        duplicates: duplicates.into_iter().map(String::from).collect(),
+        prefixes,
+        offensive,
+        non_ascii,
+        bits_per_word: _BITS(unique.len()),
+        prefix_free_bits: _BITS(prefix_free),
+    }
+}
+
+
+impl Display for Report {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Words:      {} ({} distinct)", self.total, self.unique)?;
+        writeln!(f, "Entropy:    {:.2} bits per word, {:.2} without separator", self.bits_per_word, self.prefix_free_bits)?;
+        writeln!(f, "Duplicates: {}", self.duplicates.len())?;
+        writeln!(f, "Prefixes:   {}", self.prefixes.len())?;
+        writeln!(f, "Offensive:  {}", self.offensive.len())?;
+        write!(f, "Non-ASCII:  {}", self.non_ascii.len())
+    }
+}
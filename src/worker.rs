@@ -0,0 +1,71 @@
+use {
+    rayon::{ThreadPool, ThreadPoolBuilder},
+    crate::{RandKey, GenError},
+    std::sync::{Arc, mpsc::{channel, Receiver}},
+};
+
+
+
+
+/// A handle of the thread pool started by [`spawn_worker`], cloning it shares the pool
+#[derive(Clone, Debug)]
+pub struct Worker {
+    pool: Arc<ThreadPool>,
+}
+
+
+/// Start a thread pool of `threads` threads generating the keys, `0` lets rayon choose.
+///
+/// The pool stops when the last handle is dropped.
+/// # Example
+///
+/// Basic usage:
+/// ```
+/// use rand_key::spawn_worker;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let worker = spawn_worker(2)?;
+/// let reply = worker.request("10", "2", "3");
+/// // Do something else, then wait for the key, or poll it with `try_recv`
+/// assert_eq!(reply.recv()??.len(), 15);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn spawn_worker(threads: usize) -> Result<Worker, GenError> {
+    let pool = ThreadPoolBuilder::new()
+                   .num_threads(threads)
+                   .thread_name(|i| format!("rand_key-worker-{}", i))
+                   .build()
+                   .map_err(|e| GenError::WorkerUnavailable(e.to_string()))?;
+
+    Ok(Worker { pool: Arc::new(pool) })
+}
+
+
+impl Worker {
+    /// Ask for a key of the given counts, which is sent to the returned channel once generated
+    #[inline]
+    pub fn request(&self, ltr_cnt: impl AsRef<str>, sbl_cnt: impl AsRef<str>, num_cnt: impl AsRef<str>)
+                   -> Receiver<Result<String, GenError>> {
+        let cnts = [ltr_cnt.as_ref().to_string(), sbl_cnt.as_ref().to_string(), num_cnt.as_ref().to_string()];
+        self.request_with(move || RandKey::new(&cnts[0], &cnts[1], &cnts[2]))
+    }
+
+    /// Ask for a key of the `RandKey` returned by `make`, which runs on the pool as well
+    #[inline]
+    pub fn request_with<F>(&self, make: F) -> Receiver<Result<String, GenError>>
+    where
+        F: FnOnce() -> Result<RandKey, GenError> + Send + 'static,
+    {
+        let (tx, rx) = channel();
+
+        self.pool.spawn(move || {
+            let key = make().and_then(|r_p| r_p.join().map(|_| r_p.key()));
+            // The requester may have stopped waiting
+            let _ = tx.send(key);
+        });
+
+        rx
+    }
+}
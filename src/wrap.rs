@@ -0,0 +1,111 @@
+use {
+    std::io::{self, Write},
+    crate::{RandKey, GenError},
+};
+
+
+
+
+/// A writer wrapping what it writes at `width` characters per line, maybe numbering the lines like `cat -n`
+///
+/// Call [`finish`](WrappedWriter::finish) to end the last line.
+#[derive(Debug)]
+pub struct WrappedWriter<W: Write> {
+    inner:        W,
+    width:        usize,
+    line_numbers: bool,
+    column:       usize,
+    line:         usize,
+}
+
+
+impl<W: Write> WrappedWriter<W> {
+    /// Return a writer wrapping at `width` characters, or never with `0`
+    #[inline]
+    pub fn new(inner: W, width: usize) -> Self { WrappedWriter { inner, width, line_numbers: false, column: 0, line: 0 } }
+
+    /// Number the lines or not
+    #[inline]
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// End the last line if it isn't, and return the inner writer
+    #[inline]
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.column > 0 {
+            self.inner.write_all(b"\n")?;
+        }
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+
+impl<W: Write> Write for WrappedWriter<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut start = 0;
+
+        for (i, b) in buf.iter().enumerate() {
+            // Only the first byte of a UTF-8 character starts a new one
+            if b & 0xC0 == 0x80 {
+                continue;
+            }
+
+            if self.width > 0 && self.column == self.width {
+                self.inner.write_all(&buf[start..i])?;
+                self.inner.write_all(b"\n")?;
+                start = i;
+                self.column = 0;
+            }
+            if self.column == 0 && self.line_numbers {
+                self.inner.write_all(&buf[start..i])?;
+                self.line += 1;
+                write!(self.inner, "{:>6}\t", self.line)?;
+                start = i;
+            }
+            self.column += 1;
+        }
+        self.inner.write_all(&buf[start..])?;
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
+}
+
+
+impl RandKey {
+    /// Generate the password like [`join`](RandKey::join), and write it to `writer`
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, WrappedWriter};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("100", "0", "0")?;
+    ///
+    /// let mut wrapped = WrappedWriter::new(Vec::new(), 64).line_numbers(true);
+    /// r_p.join_to_writer(&mut wrapped)?;
+    /// let out = String::from_utf8(wrapped.finish()?)?;
+    ///
+    /// let lines: Vec<&str> = out.lines().collect();
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(lines[0], format!("     1\t{}", &r_p.key()[..64]));
+    /// assert_eq!(lines[1], format!("     2\t{}", &r_p.key()[64..]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn join_to_writer(&self, mut writer: impl Write) -> Result<(), GenError> {
+        self.join()?;
+        writer.write_all(self.key.borrow().as_bytes())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+//! n-of-n splitting of generated keys, all the parts are needed to recover the key.
+use crate::{
+    RandKey, GenError, ToRandKey,
+    utils::*,
+};
+
+
+
+
+impl RandKey {
+    /// Split the key into `n` random parts of the same length, whose XOR is the key.
+    /// # Example
+    ///
+    /// Basic usage:
+    /// ```
+    /// use rand_key::{RandKey, xor::recover_xor};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let r_p = RandKey::new("10", "2", "3")?;
+    /// r_p.join()?;
+    ///
+    /// let parts = r_p.split_xor(2)?;
+    /// assert_eq!(recover_xor(&parts)?.key(), r_p.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn split_xor(&self, n: usize) -> Result<Vec<Vec<u8>>, GenError> {
+        if n == 0 {
+            return Err(GenError::InvalidShares);
+        }
+
+        let mut last = self.key.borrow().as_bytes().to_vec();
+        let mut rng = thread_rng();
+
+        let mut parts: Vec<Vec<u8>> = (1..n).map(|_| (0..last.len()).map(|_| rng.gen()).collect()).collect();
+
+        parts.iter().for_each(|part| last.iter_mut().zip(part).for_each(|(b, p)| *b ^= p));
+        parts.push(last);
+
+        Ok(parts)
+    }
+}
+
+
+/// Recover the key from all of its parts
+#[inline]
+pub fn recover_xor(parts: &[Vec<u8>]) -> Result<RandKey, GenError> {
+    let len = parts.first().map(Vec::len).ok_or(GenError::InvalidShares)?;
+
+    if parts.iter().any(|p| p.len() != len) {
+        return Err(GenError::InvalidShares);
+    }
+
+    let bytes = parts.iter().fold(vec![0; len], |mut acc, part| {
+        acc.iter_mut().zip(part).for_each(|(b, p)| *b ^= p);
+        acc
+    });
+
+    String::from_utf8(bytes).map_err(|_| GenError::InvalidChar)?.to_randkey()
+}
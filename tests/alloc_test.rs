@@ -0,0 +1,17 @@
+#![cfg(feature = "alloc-track")]
+
+
+use rand_key::{RandKey, alloc_track::TrackingAllocator};
+
+
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
+
+#[test]
+fn test_peak() {
+    let r_p = RandKey::new("100000", "0", "0").unwrap();
+    let peak = r_p.join_with_peak_alloc().unwrap();
+    // The key at least, shuffled in place and moved into the `RandKey` without a copy
+    assert!((100000..200000).contains(&peak), "{}", peak);
+}
@@ -0,0 +1,41 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(all(test, feature = "audit-log", feature = "pool"))]
+mod Audit {
+
+    use rand_key::{RandKey, SharedRandKey, KeyPool, ASCIIExcludeCtrl::*, audit_log::{self, AuditLog}};
+
+    // A single test, the log is global to the process
+    #[test]
+    fn test_every_path() {
+        let path = std::env::temp_dir().join(format!("rand_key_audit_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        audit_log::enable(AuditLog::open(&path, b"secret").unwrap());
+
+        let r_p = RandKey::new("6", "0", "4").unwrap();
+        let pool = KeyPool::new(r_p.clone(), 2).unwrap();
+
+        let mut keys = audit_log::as_requester("bob", || {
+            let shared = SharedRandKey::from(&r_p).generate().unwrap();
+            r_p.join_ordered(&[Alphabetic, Digit]).unwrap();
+            let ordered = r_p.key();
+            let child = r_p.derive_child(0).unwrap();
+            vec![shared, ordered, child]
+        });
+        keys.extend(audit_log::as_requester("carol", || vec![pool.take().unwrap().to_string()]));
+        audit_log::disable();
+
+        let log = AuditLog::open(&path, b"secret").unwrap();
+        let events = audit_log::read_events(&path).unwrap();
+        assert_eq!(events.len(), keys.len());
+        for (event, key) in events.iter().zip(keys.iter()) {
+            assert_eq!(event.key, log.fingerprint(key));
+        }
+        assert!(events[..3].iter().all(|e| e.requester == "bob"));
+        // The pooled key is recorded once it's taken, not once it's generated
+        assert_eq!(events[3].requester, "carol");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
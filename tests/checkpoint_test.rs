@@ -0,0 +1,27 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(all(test, unix))]
+mod Checkpoint {
+
+    use rand_key::RandKey;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn test_private_file() {
+        let path = std::env::temp_dir().join(format!("rand_key_private_checkpoint_{}", std::process::id()));
+        let r_p = RandKey::new("1000", "0", "0").unwrap();
+        r_p.set_unit("100").unwrap();
+
+        // The picked characters are a part of the key, nobody else reads them
+        let mut mode = None;
+        r_p.join_resumable(&path, |c| {
+               mode.get_or_insert(std::fs::metadata(&c.path)?.permissions().mode() & 0o777);
+               Ok(())
+           })
+           .unwrap();
+
+        assert_eq!(mode, Some(0o600));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,59 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Classify {
+
+    use rand_key::{RandKey, Classifier, DefaultClassifier, ToRandKey, ASCIIExcludeCtrl::{self, *}, checksum::Checksum};
+
+    // `_` is a letter, like in identifiers
+    #[derive(Debug)]
+    struct Identifier;
+
+    impl Classifier for Identifier {
+        fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl> {
+            if c == '_' { Some(Alphabetic) } else { DefaultClassifier.classify(c) }
+        }
+    }
+
+    #[test]
+    fn test_class_map() {
+        let r_p = "ab___".to_randkey_with(Identifier).unwrap();
+        assert_eq!(r_p.class_map(), vec![Alphabetic; 5]);
+    }
+
+    #[test]
+    fn test_rotate_classes() {
+        let r_p = "ab___".to_randkey_with(Identifier).unwrap();
+        r_p.rotate_classes().unwrap();
+        assert_eq!(r_p.class_map(), vec![Alphabetic; 5]);
+    }
+
+    #[test]
+    fn test_rotate_check_digit() {
+        let mut r_p = RandKey::new("6", "0", "4").unwrap();
+        r_p.set_checksum(Some(Checksum::Luhn));
+
+        for _ in 0..100 {
+            r_p.join().unwrap();
+            r_p.rotate_classes().unwrap();
+            assert!(Checksum::Luhn.verify(&r_p.key()), "{}", r_p.key());
+        }
+    }
+
+    #[test]
+    fn test_rotate_unique() {
+        let mut r_p = RandKey::new("20", "0", "10").unwrap();
+        r_p.set_unique(true).unwrap();
+
+        for _ in 0..100 {
+            r_p.join().unwrap();
+            let old = r_p.key();
+            r_p.rotate_classes().unwrap();
+
+            let key = r_p.key();
+            assert!(key.chars().zip(old.chars()).all(|(new, old)| new != old));
+            assert_eq!(key.chars().collect::<std::collections::HashSet<_>>().len(), 30, "{}", key);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Data {
+
+    use rand_key::{RandKey, ASCIIExcludeCtrl::*};
+
+    #[test]
+    fn test_add_twice() {
+        let mut r_p = RandKey::new("10", "2", "3").unwrap();
+        r_p.clear_all();
+        r_p.add_item(&["a", "b", "a"]).unwrap();
+        r_p.add_item(&["b", "c"]).unwrap();
+        assert_eq!(r_p.data(Alphabetic), vec!["a", "b", "c"]);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_same_low_byte() {
+        use rand_key::{ToRandKey, unicode::UnicodeClassifier};
+
+        // U+0161 and U+0261 both end in 0x61
+        let mut r_p = "abc".to_randkey_with(UnicodeClassifier).unwrap();
+        r_p.clear_all();
+        r_p.add_item(&["š", "\u{261}"]).unwrap();
+        assert_eq!(r_p.data(Alphabetic), vec!["š", "\u{261}"]);
+
+        r_p.del_item(&["\u{261}", "\u{261}"]).unwrap();
+        assert_eq!(r_p.data(Alphabetic), vec!["š"]);
+
+        let result = r_p.add_item_lenient(&["\u{261}", "š"]);
+        assert_eq!(result.taken, 1);
+        assert_eq!(result.skipped[0].index, 1);
+        assert_eq!(r_p.data(Alphabetic), vec!["š", "\u{261}"]);
+    }
+}
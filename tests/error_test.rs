@@ -26,4 +26,79 @@ mod InvalidInput {
         let r_p = RandKey::new("你好", "1", "C").unwrap();
         r_p.join().unwrap();
     }
+
+    #[test]
+    fn test_items() {
+        let mut r_p = RandKey::new("10", "2", "3").unwrap();
+        assert!(r_p.add_item(&["ab"]).is_err());
+        assert!(r_p.del_item(&[""]).is_err());
+        assert!(r_p.replace_data(&["a", "1", "-", "🦀"]).is_err());
+    }
+}
+
+
+#[cfg(test)]
+mod Filter {
+
+    use rand_key::{RandKey, GenError};
+
+    #[test]
+    fn test_exhausted() {
+        let r_p = RandKey::new("10", "2", "3").unwrap();
+        assert!(matches!(r_p.join_filtered(|_| false, 5), Err(GenError::AttemptsExhausted)));
+        assert!(matches!(r_p.join_filtered(|_| true, 0), Err(GenError::AttemptsExhausted)));
+    }
+
+    #[test]
+    fn test_passed() {
+        let r_p = RandKey::new("10", "2", "3").unwrap();
+        assert!(r_p.join_filtered(|key| key.len() == 15, 1).is_ok());
+    }
+}
+
+
+#[cfg(test)]
+mod Service {
+
+    use rand_key::{RandKey, GenError, KeyService, service::{BatchRequest, MAX_BATCH}};
+
+    #[test]
+    fn test_huge_batch() {
+        let service = KeyService::new(RandKey::default().into(), 100.0, 1000.0);
+
+        let req = BatchRequest { batch: Some(100_000_000_000), ..Default::default() };
+        assert!(matches!(service.generate_batch("alice", &req), Err(GenError::BatchTooLarge(MAX_BATCH))));
+
+        let req = BatchRequest { labels: Some(vec![String::new(); MAX_BATCH + 1]), ..Default::default() };
+        assert!(matches!(service.generate_batch("alice", &req), Err(GenError::BatchTooLarge(MAX_BATCH))));
+    }
+}
+
+
+#[cfg(test)]
+mod Alloc {
+
+    use rand_key::{RandKey, GenError, ASCIIExcludeCtrl::*};
+
+    #[test]
+    fn test_ordered_overflow() {
+        let r_p = RandKey::new(usize::MAX.to_string(), "0", "1").unwrap();
+        assert!(matches!(r_p.join_ordered(&[Alphabetic, Digit]), Err(GenError::AllocationFailed)));
+    }
+}
+
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod Compat {
+
+    use rand_key::compat::RandPwd;
+
+    #[test]
+    fn test_zero_unit() {
+        let mut r_p = RandPwd::new(10, 2, 3);
+        r_p.set_unit(0);
+        r_p.join();
+        assert_eq!(r_p.show().len(), 15);
+    }
 }
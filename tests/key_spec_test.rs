@@ -0,0 +1,31 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Spec {
+
+    use rand_key::{RandKey, KeySpec, Classifier, DefaultClassifier, ToRandKey, ASCIIExcludeCtrl::{self, *}};
+    use std::convert::TryFrom;
+
+    // `_` is a letter, like in identifiers
+    #[derive(Debug)]
+    struct Identifier;
+
+    impl Classifier for Identifier {
+        fn classify(&self, c: char) -> Option<ASCIIExcludeCtrl> {
+            if c == '_' { Some(Alphabetic) } else { DefaultClassifier.classify(c) }
+        }
+    }
+
+    #[test]
+    fn test_classifier() {
+        let r_p: RandKey = "snake_case1".to_randkey_with(Identifier).unwrap();
+        let spec = KeySpec::try_from(&r_p).unwrap();
+
+        assert_eq!(spec.count(Alphabetic), 10);
+        assert!(spec.matches("case_snake2"));
+        assert!(spec.matches(&r_p.generate().unwrap()));
+        // The ASCII classes would count it as a symbol
+        assert!(!KeySpec::new(10, 0, 1).matches("case_snake2"));
+    }
+}
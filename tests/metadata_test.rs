@@ -0,0 +1,44 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Metadata {
+
+    use rand_key::{RandKey, ToRandKey, SetRandKeyOp::*};
+
+    #[test]
+    fn test_key_from_outside() {
+        let mut r_p = RandKey::new("3", "0", "3").unwrap();
+        r_p.join().unwrap();
+        assert!(r_p.metadata().is_some());
+
+        r_p.set_key("abc123", Update).unwrap();
+        assert!(r_p.metadata().is_none());
+
+        r_p.join().unwrap();
+        r_p.extend("d4".chars());
+        assert!(r_p.metadata().is_none());
+
+        // A key which fails the check is left alone, and so is its metadata
+        r_p.join().unwrap();
+        assert!(r_p.set_key("abc", Check).is_err());
+        assert!(r_p.metadata().is_some());
+    }
+
+    #[test]
+    fn test_generated_key() {
+        let r_p = "abc123".to_randkey().unwrap();
+        r_p.shuffle_key();
+        assert!(r_p.metadata().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "transcript")]
+    fn test_committed_key() {
+        use rand_key::transcript::Commitment;
+
+        let r_p = RandKey::new("3", "0", "3").unwrap();
+        r_p.join_committed(&Commitment::new()).unwrap();
+        assert_eq!(r_p.metadata().unwrap().entropy_bits, r_p.strength().bits);
+    }
+}
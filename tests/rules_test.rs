@@ -0,0 +1,77 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Rules {
+
+    use rand_key::{RandKey, Rule, GenError, ASCIIExcludeCtrl::*, checksum::Checksum};
+
+    fn adjacent(key: &str, kind: fn(&char) -> bool) -> bool {
+        let key: Vec<char> = key.chars().collect();
+        key.windows(2).any(|w| kind(&w[0]) && kind(&w[1]))
+    }
+
+    #[test]
+    fn test_check_digit() {
+        let mut r_p = RandKey::new("3", "0", "4").unwrap();
+        r_p.set_checksum(Some(Checksum::Luhn));
+        r_p.add_rule(Rule::NoAdjacent(Digit));
+
+        for _ in 0..200 {
+            let key = r_p.generate().unwrap();
+            assert_eq!(key.len(), 7);
+            assert!(!adjacent(&key, char::is_ascii_digit), "{}", key);
+            assert!(Checksum::Luhn.verify(&key));
+        }
+
+        // Three letters can't keep four digits apart
+        r_p.set_cnt(Digit, "5");
+        assert!(matches!(r_p.join(), Err(GenError::UnsatisfiableRules(_))));
+    }
+
+    #[test]
+    fn test_resume() {
+        let path = std::env::temp_dir().join("rand_key_rules_resume");
+        let mut r_p = RandKey::new("40", "10", "5").unwrap();
+        r_p.add_rule(Rule::NoAdjacent(Punctuation));
+
+        for _ in 0..50 {
+            r_p.join_resumable(&path, |_| Ok(())).unwrap();
+            assert!(!adjacent(&r_p.key(), char::is_ascii_punctuation), "{}", r_p.key());
+        }
+    }
+
+    #[test]
+    fn test_ordered() {
+        let mut r_p = RandKey::new("6", "2", "0").unwrap();
+        r_p.add_rule(Rule::NoAdjacent(Punctuation));
+        assert!(matches!(r_p.join_ordered(&[Alphabetic, Punctuation]), Err(GenError::InconsistentField)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut r_p = RandKey::new("6", "2", "0").unwrap();
+        r_p.add_rule(Rule::NoAdjacent(Punctuation));
+        assert!(matches!(r_p.stream(), Err(GenError::InconsistentField)));
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn test_derive() {
+        let mut r_p = RandKey::new("40", "10", "5").unwrap();
+        r_p.add_rule(Rule::NoAdjacent(Punctuation));
+        r_p.join().unwrap();
+
+        for i in 0..50 {
+            let child = r_p.derive_child(i).unwrap();
+            assert!(!adjacent(&child, char::is_ascii_punctuation), "{}", child);
+            assert_eq!(child, r_p.derive_child(i).unwrap());
+        }
+
+        r_p.set_min_entropy(1000.0);
+        assert!(matches!(r_p.derive_child(0), Err(GenError::BelowEntropyFloor(..))));
+        r_p.set_min_entropy(0.0);
+        r_p.set_coverage(true);
+        assert!(matches!(r_p.derive_child(0), Err(GenError::InconsistentField)));
+    }
+}
@@ -0,0 +1,26 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Scan {
+
+    use rand_key::scan::{scan, FindingKind};
+
+    #[test]
+    fn test_long_digit_run() {
+        assert!(scan("a12345678901234567890b").iter().all(|f| f.kind != FindingKind::Year));
+        assert!(scan("1990 12345678901234567890").iter().any(|f| f.kind == FindingKind::Year));
+    }
+
+    #[test]
+    fn test_long_key() {
+        // Linear in the length, the repeated tokens and the words of a long key used to take minutes
+        let key: String = (0..32768u32).map(|i| (b'!' + (i.wrapping_mul(2654435761) >> 7) as u8 % 94) as char).collect();
+        scan(&key);
+
+        let key = "password".repeat(4096);
+        let findings = scan(&key);
+        assert!(findings.iter().any(|f| f.kind == FindingKind::RepeatedToken && f.len == key.len()));
+        assert_eq!(findings.iter().filter(|f| f.kind == FindingKind::Word("password".into())).count(), 4096);
+    }
+}
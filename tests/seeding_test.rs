@@ -0,0 +1,36 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Seeding {
+
+    use rand_key::{RandKey, SeedStrategy};
+
+    fn generate(seed: [u8; 32]) -> String {
+        let mut r_p = RandKey::new("40", "10", "10").unwrap();
+        r_p.set_unit("5").unwrap();
+        r_p.set_seed(Some(1));
+        r_p.set_seed_strategy(SeedStrategy::Derived256(seed));
+        r_p.generate().unwrap()
+    }
+
+    #[test]
+    fn test_derived_256() {
+        let mut seed = [7; 32];
+        let key = generate(seed);
+        assert_eq!(key, generate(seed));
+
+        // Every byte of the master seed counts, the last ones too
+        seed[31] ^= 1;
+        assert_ne!(key, generate(seed));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut r_p = RandKey::new("40", "10", "10").unwrap();
+        r_p.set_seed_strategy(SeedStrategy::Derived(9));
+
+        let key: String = r_p.stream().unwrap().collect();
+        assert_eq!(key, r_p.stream().unwrap().collect::<String>());
+    }
+}
@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Shared {
+
+    use rand_key::{RandKey, SharedRandKey, Rule, GenError, SeedStrategy, ASCIIExcludeCtrl::*};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_entropy_floor() {
+        let mut r_p = RandKey::new("2", "0", "0").unwrap();
+        r_p.set_min_entropy(80.0);
+        assert!(r_p.join().is_err());
+        assert!(matches!(SharedRandKey::from(r_p).generate(), Err(GenError::BelowEntropyFloor(..))));
+    }
+
+    #[test]
+    fn test_settings() {
+        let mut r_p = RandKey::new("20", "5", "5").unwrap();
+        r_p.add_rule(Rule::NeverFirst(Digit));
+        r_p.set_unique(true).unwrap();
+        let shared = SharedRandKey::from(&r_p);
+
+        for _ in 0..100 {
+            let key = shared.generate().unwrap();
+            assert!(!key.starts_with(|c: char| c.is_ascii_digit()), "{}", key);
+            assert_eq!(key.chars().collect::<HashSet<_>>().len(), 30);
+        }
+
+        // Seeded like the `RandKey`
+        r_p.set_unique(false).unwrap();
+        r_p.set_seed(Some(1));
+        r_p.set_seed_strategy(SeedStrategy::Derived(2));
+        assert_eq!(SharedRandKey::from(&r_p).generate().unwrap(), r_p.generate().unwrap());
+    }
+}
@@ -0,0 +1,52 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(all(test, feature = "spill"))]
+mod Spill {
+
+    use rand_key::{RandKey, Rule, GenError, SeedStrategy, ASCIIExcludeCtrl::*, checksum::Checksum};
+
+    fn path(name: &str) -> std::path::PathBuf { std::env::temp_dir().join(format!("rand_key_spill_{}_{}", name, std::process::id())) }
+
+    #[test]
+    fn test_settings() {
+        let path = path("settings");
+        let mut r_p = RandKey::new("2000", "0", "500").unwrap();
+        r_p.set_spill_threshold("100").unwrap();
+        r_p.set_checksum(Some(Checksum::Luhn));
+        r_p.set_seed(Some(3));
+        r_p.set_seed_strategy(SeedStrategy::Derived(5));
+
+        r_p.join_to_file(&path).unwrap();
+        let key = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(key.len(), 2500);
+        assert!(Checksum::Luhn.verify(&key));
+        assert!(r_p.metadata().is_some());
+
+        // Seeded, the same key again
+        r_p.join_to_file(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), key);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        r_p.add_rule(Rule::NeverFirst(Digit));
+        assert!(matches!(r_p.join_to_file(&path), Err(GenError::InconsistentField)));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_non_ascii() {
+        use rand_key::{ToRandKey, unicode::UnicodeClassifier};
+
+        let mut r_p = "αβγδεζηθ".to_randkey_with(UnicodeClassifier).unwrap();
+        r_p.replace_data(&["α", "β", "γ", "δ", "ε", "ζ", "η", "θ"]).unwrap();
+        r_p.set_cnt(Alphabetic, "500");
+        r_p.set_spill_threshold("100").unwrap();
+        assert!(matches!(r_p.join_to_file(path("greek")), Err(GenError::InvalidChar)));
+    }
+}
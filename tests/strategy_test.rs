@@ -0,0 +1,102 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Unique {
+
+    use rand_key::{RandKey, GenError, ASCIIExcludeCtrl::*};
+    use std::collections::HashSet;
+
+    fn distinct(key: &str) -> bool { key.chars().collect::<HashSet<_>>().len() == key.chars().count() }
+
+    #[test]
+    fn test_ordered() {
+        let mut r_p = RandKey::new("26", "0", "10").unwrap();
+        r_p.set_unique(true).unwrap();
+
+        for _ in 0..50 {
+            r_p.join_ordered(&[Alphabetic, Digit]).unwrap();
+            assert!(distinct(&r_p.key()), "{}", r_p.key());
+        }
+
+        r_p.set_cnt(Digit, "11");
+        assert!(matches!(r_p.join_ordered(&[Alphabetic, Digit]), Err(GenError::PoolTooSmall(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn test_derive() {
+        let mut r_p = RandKey::new("20", "10", "10").unwrap();
+        r_p.set_unique(true).unwrap();
+        r_p.join().unwrap();
+
+        for i in 0..50 {
+            let child = r_p.derive_child(i).unwrap();
+            assert_eq!(child.len(), 40);
+            assert!(distinct(&child), "{}", child);
+        }
+    }
+
+    #[test]
+    fn test_resume() {
+        let mut r_p = RandKey::new("10", "0", "5").unwrap();
+        r_p.set_unique(true).unwrap();
+
+        let path = std::env::temp_dir().join(format!("rand_key_unique_resume_{}", std::process::id()));
+        assert!(matches!(r_p.join_resumable(&path, |_| Ok(())), Err(GenError::InconsistentField)));
+        assert!(!path.exists());
+    }
+}
+
+
+#[cfg(test)]
+mod Auto {
+
+    use rand_key::{RandKey, Strategy};
+    use rayon::prelude::*;
+
+    // The first `Auto` keys generated on the threads of rayon used to measure the machine with them all blocked
+    #[test]
+    fn test_calibrate_in_rayon() {
+        let keys = rayon::current_num_threads() * 4;
+        let lens: Vec<usize> = (0..keys).into_par_iter()
+                                        .map(|_| {
+                                            let mut r_p = RandKey::new("100000", "0", "0").unwrap();
+                                            r_p.set_strategy(Strategy::Auto);
+                                            r_p.generate().unwrap().len()
+                                        })
+                                        .collect();
+        assert!(lens.iter().all(|n| *n == 100000));
+    }
+}
+
+
+#[cfg(test)]
+mod Coverage {
+
+    use rand_key::{RandKey, GenError, ASCIIExcludeCtrl::*, checksum::Checksum};
+
+    #[test]
+    fn test_check_digit() {
+        let mut r_p = RandKey::new("0", "0", "10").unwrap();
+        r_p.set_coverage(true);
+        r_p.join().unwrap();
+        assert!((b'0'..=b'9').all(|c| r_p.key().contains(c as char)));
+
+        // The check digit takes one of the ten digits, one would be missing
+        r_p.set_checksum(Some(Checksum::Luhn));
+        assert!(matches!(r_p.join(), Err(GenError::InconsistentField)));
+        assert!(matches!(r_p.plan(), Err(GenError::InconsistentField)));
+    }
+
+    #[test]
+    fn test_ordered_and_resume() {
+        let mut r_p = RandKey::new("26", "0", "10").unwrap();
+        r_p.set_coverage(true);
+        assert!(matches!(r_p.join_ordered(&[Alphabetic, Digit]), Err(GenError::InconsistentField)));
+
+        let path = std::env::temp_dir().join(format!("rand_key_coverage_resume_{}", std::process::id()));
+        assert!(matches!(r_p.join_resumable(&path, |_| Ok(())), Err(GenError::InconsistentField)));
+        assert!(!path.exists());
+    }
+}
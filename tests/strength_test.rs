@@ -0,0 +1,33 @@
+#![allow(non_snake_case)]
+
+
+#[cfg(test)]
+mod Strength {
+
+    use rand_key::{RandKey, GenError, strength::Strength};
+
+    // More than a f64 holds
+    fn huge() -> String { "9".repeat(400) }
+
+    #[test]
+    fn test_huge_counts() {
+        let mut r_p = RandKey::new(huge(), "2", huge()).unwrap();
+        let report = r_p.strength();
+        assert_eq!(report.bits, f64::INFINITY);
+        assert_eq!(report.strength(), Strength::VeryStrong);
+
+        // The floor is met, the key just can't be held
+        r_p.set_min_entropy(128.0);
+        assert!(matches!(r_p.join(), Err(GenError::AllocationFailed)));
+    }
+
+    #[test]
+    fn test_pool_of_one() {
+        // The letters can only be `a`, the bits are where the single digit goes
+        let mut r_p = RandKey::new(huge(), "0", "1").unwrap();
+        r_p.replace_data(&["a", "1"]).unwrap();
+        let bits = r_p.strength().bits;
+        assert!(bits.is_finite(), "{}", bits);
+        assert!((bits - 400.0 * 10f64.log2()).abs() < 1.0, "{}", bits);
+    }
+}